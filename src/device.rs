@@ -1,9 +1,20 @@
-use self::response::{firmware::{FirmwareInfo}, report::MachineInfo, util::{message::Message, echo::EchoMessage}, state::{gcode_state::GCodeState}, setting::DeviceSettings, error::StatusCodes};
+use std::collections::VecDeque;
+
+use crate::job::progress::{JobProgress, UploadProgress};
+
+use self::{event::{DeviceEvent, MAX_DEVICE_EVENTS}, util::{coordinate::{get_coordinate_system, CoordinateSystem}, io::AuxOutputStates, signals::SignalSet}};
+
+use self::response::{firmware::{FirmwareInfo, startup::{StartupLine, StartupLines}, welcome::WelcomeMessage}, report::MachineInfo, state::{odometer::Odometer, pendant::PendantControl, pid_log::PidLog, probe::ProbeResult, ngc_parameters::{NgcParameter, NgcParameters}, tool_table::{ToolTable, ToolTableEntry}, sdcard::{SdCardFile, SdCardListing}}, util::{message::Message, echo::EchoMessage}, state::{gcode_state::GCodeState, machine::state::MachineStateName}, setting::DeviceSettings, error::StatusCodes};
 
 pub mod response;
 pub mod command;
+pub mod event;
 pub mod util;
 
+/// Report in inches setting ($13)
+const REPORT_INCHES_SETTING: u32 = 13;
+
+const MM_PER_INCH: f32 = 25.4;
 
 /// The device object stores all gathered information from a grbl controller
 /// 
@@ -18,6 +29,48 @@ pub struct DeviceInfo {
     last_echo_message: Option<EchoMessage>,
     settings: DeviceSettings,
     status_codes: StatusCodes,
+    last_response_status: Option<Result<(), i32>>,
+    job_progress: Option<JobProgress>,
+    upload_progress: Option<UploadProgress>,
+    events: VecDeque<DeviceEvent>,
+    probe_result: Option<ProbeResult>,
+    ngc_parameters: NgcParameters,
+    tool_table: ToolTable,
+    sd_card_listing: SdCardListing,
+    startup_lines: StartupLines,
+    odometer: Option<Odometer>,
+    pid_log: Option<PidLog>,
+
+    /// Aux output states commanded by this host, the device does not report them
+    aux_outputs: AuxOutputStates,
+
+    /// Lines of a dumped sd card file, collected until the dump is acknowledged
+    sd_card_dump: Option<Vec<String>>,
+    sd_card_dump_active: bool,
+
+    /// Tool number requested by the device while in "Tool" state
+    requested_tool: Option<u32>,
+    tool_change_pending: bool,
+    parser_state_requested: bool,
+
+    /// Full status report and gcode state have to be requested after the pendant released control
+    resync_requested: bool,
+
+    /// Count of commands written by the device thread itself whose response is outstanding
+    internal_responses_pending: u32,
+
+    /// Count of received "ok" and "error:\<code\>" responses
+    response_count: u64,
+    error_response_count: u64,
+
+    /// Count of received status reports
+    report_count: u64,
+
+    /// Count of status reports with the result of an input wait ("In:")
+    input_wait_result_count: u64,
+
+    /// Count of received welcome messages
+    reset_count: u64,
 }
 
 
@@ -35,6 +88,30 @@ impl DeviceInfo {
             last_echo_message: None,
             settings: DeviceSettings::new(),
             status_codes: StatusCodes::new(),
+            last_response_status: None,
+            job_progress: None,
+            upload_progress: None,
+            events: VecDeque::new(),
+            probe_result: None,
+            ngc_parameters: NgcParameters::default(),
+            tool_table: ToolTable::default(),
+            sd_card_listing: SdCardListing::default(),
+            sd_card_dump: None,
+            sd_card_dump_active: false,
+            startup_lines: StartupLines::default(),
+            odometer: None,
+            pid_log: None,
+            aux_outputs: AuxOutputStates::default(),
+            requested_tool: None,
+            tool_change_pending: false,
+            parser_state_requested: false,
+            resync_requested: false,
+            internal_responses_pending: 0,
+            response_count: 0,
+            error_response_count: 0,
+            report_count: 0,
+            input_wait_result_count: 0,
+            reset_count: 0,
         })
     }
 
@@ -83,10 +160,49 @@ impl DeviceInfo {
         self.machine_info.as_ref()
     }
 
+    /// Get the machine state of the last status report
+    #[must_use]
+    pub fn machine_state_name(&self) -> Option<MachineStateName> {
+        self.machine_info.as_ref().map(|info| *info.machine_state().status())
+    }
+
     /// Set the device's machine info.
     /// 
-    /// Does only replace present values
-    pub fn update_machine_info(&mut self, machine_info: MachineInfo) {
+    /// Does only replace present values.
+    /// Positions are always stored in mm and in both frames (MPos and WPos) if the offset (WCO) is known.
+    pub fn update_machine_info(&mut self, mut machine_info: MachineInfo) {
+        self.report_count += 1;
+        if machine_info.input_wait_result_succeeded().is_some() {
+            self.input_wait_result_count += 1;
+        }
+        if self.is_inch_reporting() {
+            machine_info.scale_positions(MM_PER_INCH);
+        }
+        let cached_offset = self.machine_info.as_ref().and_then(|info| info.local_offset()).cloned();
+        machine_info.derive_positions(cached_offset.as_ref());
+
+        // grbl omits "A:" if all accessories are off, but always reports it together with "Ov:"
+        if machine_info.override_values().is_some() && machine_info.accessory_state().is_none() {
+            machine_info.set_accessory_state(Vec::new());
+        }
+
+        // "Pn:" is part of every report while any signal is asserted
+        if machine_info.machine_signals().is_none() {
+            machine_info.set_machine_signals(Vec::new());
+        }
+        let previous_signals = self.machine_info.as_ref().and_then(|info| info.signal_set()).unwrap_or_default();
+        self.put_signal_events(&machine_info.signal_set().unwrap_or_default(), &previous_signals);
+        let previous_state = self.machine_state_name();
+        self.update_tool_change(*machine_info.machine_state().status(), previous_state);
+        self.update_aux_outputs(*machine_info.machine_state().status());
+        if let (Some(thc_state), Some(previous_thc_state)) = (machine_info.thc_state(), self.machine_info.as_ref().and_then(|info| info.thc_state())) {
+            if thc_state.is_arc_lost(previous_thc_state) {
+                self.put_event(DeviceEvent::ArcLost);
+            }
+        }
+        let previous_pendant_control = self.machine_info.as_ref().and_then(|info| info.pendant_control()).copied();
+        self.update_pendant_control(machine_info.pendant_control().copied(), previous_pendant_control);
+
         // just set info as new info if no info was present
         // otherwhise check for each single value and replace it with the updated information
         if let None = self.machine_info {
@@ -101,13 +217,8 @@ impl DeviceInfo {
         old_machine_info.set_machine_state(machine_info.machine_state().clone());
 
         
-        if let Some(gp) = machine_info.global_position() {
-            old_machine_info.set_global_position(gp.clone());
-        }
-
-        if let Some(lp) = machine_info.local_position() {
-            old_machine_info.set_local_position(lp.clone());
-        }
+        // each report contains a position, a frame which could not be derived is outdated
+        old_machine_info.set_positions(machine_info.global_position().cloned(), machine_info.local_position().cloned());
 
         if let Some(lo) = machine_info.local_offset() {
             old_machine_info.set_local_offset(lo.clone());
@@ -133,10 +244,6 @@ impl DeviceInfo {
             old_machine_info.set_machine_coordinate_system(mcs.clone());
         }
 
-        if let Some(ms) = machine_info.machine_signals() {
-            old_machine_info.set_machine_signals(ms.clone());
-        }
-
         if let Some(ov) = machine_info.override_values() {
             old_machine_info.set_override_values(*ov);
         }
@@ -173,10 +280,30 @@ impl DeviceInfo {
             old_machine_info.set_arc_mode(*ac);
         }
 
+        if let Some(ct) = machine_info.current_tool() {
+            old_machine_info.set_current_tool(ct);
+        }
+
+        // grblHAL reports the progress only while streaming from the sd card
+        old_machine_info.set_sd_card_progress(machine_info.sd_card_progress().cloned());
+
+        if let Some(thc) = machine_info.thc_state() {
+            old_machine_info.set_thc_state(*thc);
+        }
+
+        // unknown fields are only valid for the report which contained them
+        old_machine_info.set_unknown_fields(machine_info.unknown_fields().clone());
+
         // set updated old machine info to new info
         self.machine_info = Some(old_machine_info);
     }
 
+    /// Indicates if positions are reported in inches ($13=1)
+    #[must_use]
+    pub fn is_inch_reporting(&self) -> bool {
+        self.settings.get_setting_f32(&REPORT_INCHES_SETTING).unwrap_or(0.0) as i32 == 1
+    }
+
     /// Get a reference to the device's gcode state.
     #[must_use]
     pub fn gcode_state(&self) -> Option<&GCodeState> {
@@ -184,10 +311,48 @@ impl DeviceInfo {
     }
 
     /// Set the device's gcode state.
+    ///
+    /// The active coordinate system of the machine info is updated as well
     pub fn set_gcode_state(&mut self, gcode_state: GCodeState) {
+        if self.tool_change_pending {
+            if let Some(tool) = gcode_state.tool() {
+                self.tool_change_pending = false;
+                self.requested_tool = Some(tool);
+                self.put_event(DeviceEvent::ToolChangeRequested(tool));
+            }
+        }
+        let coordinate_system = gcode_state.values().iter().find(|value| get_coordinate_system(value).is_ok()).cloned();
+        if let (Some(machine_info), Some(coordinate_system)) = (self.machine_info.as_mut(), coordinate_system) {
+            machine_info.set_machine_coordinate_system(coordinate_system);
+        }
         self.gcode_state = Some(gcode_state);
     }  
 
+    /// Returns the active work coordinate system from the last "WCS:" report field or gcode state
+    #[must_use]
+    pub fn active_coordinate_system(&self) -> Option<CoordinateSystem> {
+        if let Some(name) = self.machine_info.as_ref().and_then(|info| info.machine_coordinate_system()) {
+            return get_coordinate_system(name).ok();
+        }
+        self.gcode_state.as_ref()?.values().iter().find_map(|value| get_coordinate_system(value).ok())
+    }
+
+    /// Get a reference to the device's parameters of the last "$#" response.
+    #[must_use]
+    pub fn ngc_parameters(&self) -> &NgcParameters {
+        &self.ngc_parameters
+    }
+
+    /// Stores a parameter of the "$#" response
+    ///
+    /// The position is always stored in mm
+    pub fn put_ngc_parameter(&mut self, mut parameter: NgcParameter) {
+        if self.is_inch_reporting() {
+            parameter.scale_position(MM_PER_INCH);
+        }
+        self.ngc_parameters.put(parameter);
+    }
+
     /// Get a mutable reference to the device info's settings.
     #[must_use]
     pub fn settings_mut(&mut self) -> &mut DeviceSettings {
@@ -211,6 +376,383 @@ impl DeviceInfo {
     pub fn status_codes_mut(&mut self) -> &mut StatusCodes {
         &mut self.status_codes
     }
+
+    /// Get the device's last response status ("ok" or "error:\<code\>")
+    #[must_use]
+    pub fn last_response_status(&self) -> Option<&Result<(), i32>> {
+        self.last_response_status.as_ref()
+    }
+
+    /// Stores the response status and acknowledges the pending job line
+    ///
+    /// Responses of commands written by the device thread itself are neither counted nor acknowledge a job line
+    pub fn put_response_status(&mut self, status: Result<(), i32>) {
+        self.sd_card_dump_active = false;
+        if self.internal_responses_pending > 0 {
+            self.internal_responses_pending -= 1;
+            return;
+        }
+        self.response_count += 1;
+        if status.is_err() {
+            self.error_response_count += 1;
+        }
+        if let Some(job_progress) = self.job_progress.as_mut() {
+            job_progress.put_response_status(&status);
+        }
+        self.last_response_status = Some(status);
+    }
+
+    /// Get the count of received "ok" and "error:\<code\>" responses
+    #[must_use]
+    pub fn response_count(&self) -> u64 {
+        self.response_count
+    }
+
+    /// Get the count of received "error:\<code\>" responses
+    #[must_use]
+    pub fn error_response_count(&self) -> u64 {
+        self.error_response_count
+    }
+
+    /// Get the count of received status reports
+    #[must_use]
+    pub fn report_count(&self) -> u64 {
+        self.report_count
+    }
+
+    /// Get the count of status reports with an input wait result ("In:")
+    #[must_use]
+    pub fn input_wait_result_count(&self) -> u64 {
+        self.input_wait_result_count
+    }
+
+    /// Get the count of resets detected by welcome messages
+    #[must_use]
+    pub fn reset_count(&self) -> u64 {
+        self.reset_count
+    }
+
+    /// Stores the welcome message and handles the reset it announces
+    ///
+    /// The cached parser state, commanded aux outputs and pending tool changes are dropped,
+    /// a running job fails because the device discarded its queued lines
+    pub fn put_welcome_message(&mut self, welcome_message: WelcomeMessage) {
+        self.firmware_info.set_welcome_message(Some(welcome_message));
+        self.reset_count += 1;
+        self.gcode_state = None;
+        self.last_response_status = None;
+        self.sd_card_dump_active = false;
+        self.requested_tool = None;
+        self.tool_change_pending = false;
+        self.parser_state_requested = false;
+        self.internal_responses_pending = 0;
+        self.aux_outputs = AuxOutputStates::default();
+        if let Some(job_progress) = self.job_progress.as_mut() {
+            job_progress.fail("Device was reset");
+        }
+        self.put_event(DeviceEvent::Reset);
+    }
+
+    /// Get a reference to the device's last probe result.
+    #[must_use]
+    pub fn probe_result(&self) -> Option<&ProbeResult> {
+        self.probe_result.as_ref()
+    }
+
+    /// Set the device's last probe result.
+    ///
+    /// The position is always stored in mm
+    pub fn set_probe_result(&mut self, mut probe_result: ProbeResult) {
+        if self.is_inch_reporting() {
+            probe_result.scale_position(MM_PER_INCH);
+        }
+        self.probe_result = Some(probe_result);
+    }
+
+    /// Get a reference to the device's job progress.
+    #[must_use]
+    pub fn job_progress(&self) -> Option<&JobProgress> {
+        self.job_progress.as_ref()
+    }
+
+    /// Get a mutable reference to the device's job progress.
+    pub fn job_progress_mut(&mut self) -> Option<&mut JobProgress> {
+        self.job_progress.as_mut()
+    }
+
+    /// Set the device's job progress.
+    pub fn set_job_progress(&mut self, job_progress: JobProgress) {
+        self.job_progress = Some(job_progress);
+    }
+
+    /// Get a reference to the progress of the last sd card upload.
+    #[must_use]
+    pub fn upload_progress(&self) -> Option<&UploadProgress> {
+        self.upload_progress.as_ref()
+    }
+
+    /// Get a mutable reference to the progress of the last sd card upload.
+    pub fn upload_progress_mut(&mut self) -> Option<&mut UploadProgress> {
+        self.upload_progress.as_mut()
+    }
+
+    /// Set the progress of the sd card upload.
+    pub fn set_upload_progress(&mut self, upload_progress: UploadProgress) {
+        self.upload_progress = Some(upload_progress);
+    }
+
+    /// Get a reference to the device's events which are not taken yet.
+    #[must_use]
+    pub fn events(&self) -> &VecDeque<DeviceEvent> {
+        &self.events
+    }
+
+    /// Removes and returns all events in order of occurrence
+    pub fn take_events(&mut self) -> Vec<DeviceEvent> {
+        self.events.drain(..).collect()
+    }
+
+    /// Adds the event, drops the oldest event if the queue is full
+    pub fn put_event(&mut self, event: DeviceEvent) {
+        if self.events.len() >= MAX_DEVICE_EVENTS {
+            self.events.pop_front();
+        }
+        self.events.push_back(event);
+    }
+
+    /// Get a reference to the device's tool table of the last "$#" response.
+    #[must_use]
+    pub fn tool_table(&self) -> &ToolTable {
+        &self.tool_table
+    }
+
+    /// Stores a tool table entry of the "$#" response
+    ///
+    /// Offset and radius are always stored in mm
+    pub fn put_tool_table_entry(&mut self, mut entry: ToolTableEntry) {
+        if self.is_inch_reporting() {
+            entry.scale(MM_PER_INCH);
+        }
+        self.tool_table.put(entry);
+    }
+
+    /// Removes all tool table entries before a new tool table is requested
+    pub fn reset_tool_table(&mut self) {
+        self.tool_table = ToolTable::default();
+    }
+
+    /// Get a reference to the device's startup lines of the last "$N" response.
+    #[must_use]
+    pub fn startup_lines(&self) -> &StartupLines {
+        &self.startup_lines
+    }
+
+    /// Stores a startup line of the "$N" response
+    pub fn put_startup_line(&mut self, startup_line: StartupLine) {
+        self.startup_lines.put(startup_line);
+    }
+
+    /// Get a reference to the device's last odometer data ("$ODOMETERS").
+    #[must_use]
+    pub fn odometer(&self) -> Option<&Odometer> {
+        self.odometer.as_ref()
+    }
+
+    /// Set the device's odometer data.
+    pub fn set_odometer(&mut self, odometer: Odometer) {
+        self.odometer = Some(odometer);
+    }
+
+    /// Removes the odometer data before new data is requested
+    pub fn reset_odometer(&mut self) {
+        self.odometer = None;
+    }
+
+    /// Get a reference to the device's last spindle sync PID log ("$PIDL").
+    #[must_use]
+    pub fn pid_log(&self) -> Option<&PidLog> {
+        self.pid_log.as_ref()
+    }
+
+    /// Set the device's spindle sync PID log.
+    pub fn set_pid_log(&mut self, pid_log: PidLog) {
+        self.pid_log = Some(pid_log);
+    }
+
+    /// Removes the PID log before a new log is requested
+    pub fn reset_pid_log(&mut self) {
+        self.pid_log = None;
+    }
+
+    /// Get a reference to the aux output states commanded by this host.
+    #[must_use]
+    pub fn aux_outputs(&self) -> &AuxOutputStates {
+        &self.aux_outputs
+    }
+
+    /// Stores the applied state of an immediate digital output
+    pub fn put_digital_output(&mut self, port: u16, enabled: bool) {
+        self.aux_outputs.put_digital(port, enabled);
+    }
+
+    /// Stores the applied value of an immediate analog output
+    pub fn put_analog_output(&mut self, port: u16, value: f32) {
+        self.aux_outputs.put_analog(port, value);
+    }
+
+    /// Stores the state of a synchronized digital output which changes with the next motion
+    pub fn put_pending_digital_output(&mut self, port: u16, enabled: bool) {
+        self.aux_outputs.put_pending_digital(port, enabled);
+    }
+
+    /// Stores the value of a synchronized analog output which changes with the next motion
+    pub fn put_pending_analog_output(&mut self, port: u16, value: f32) {
+        self.aux_outputs.put_pending_analog(port, value);
+    }
+
+    /// Get a reference to the device's last sd card directory listing.
+    #[must_use]
+    pub fn sd_card_listing(&self) -> &SdCardListing {
+        &self.sd_card_listing
+    }
+
+    /// Removes all files of the sd card listing before a new listing is requested
+    pub fn reset_sd_card_listing(&mut self) {
+        self.sd_card_listing = SdCardListing::default();
+    }
+
+    /// Adds a file of the sd card listing
+    pub fn put_sd_card_file(&mut self, file: SdCardFile) {
+        self.sd_card_listing.put_file(file);
+    }
+
+    /// Get the lines of the last dumped sd card file
+    #[must_use]
+    pub fn sd_card_dump(&self) -> Option<&Vec<String>> {
+        self.sd_card_dump.as_ref()
+    }
+
+    /// Collects all following lines as sd card file content until the next response status
+    pub fn start_sd_card_dump(&mut self) {
+        self.sd_card_dump = Some(Vec::new());
+        self.sd_card_dump_active = true;
+    }
+
+    /// Indicates if lines are collected as sd card file content
+    #[must_use]
+    pub fn is_sd_card_dump_active(&self) -> bool {
+        self.sd_card_dump_active
+    }
+
+    /// Adds a line of the dumped sd card file
+    pub fn put_sd_card_dump_line(&mut self, line: &str) {
+        if let Some(lines) = self.sd_card_dump.as_mut() {
+            lines.push(line.to_string());
+        }
+    }
+
+    /// Get the tool number of the active manual tool change
+    #[must_use]
+    pub fn requested_tool(&self) -> Option<u32> {
+        self.requested_tool
+    }
+
+    /// Indicates once after entering the "Tool" state that the gcode state ("$G") has to be requested
+    ///
+    /// The requested tool number is only part of the gcode state
+    pub fn take_parser_state_request(&mut self) -> bool {
+        std::mem::take(&mut self.parser_state_requested)
+    }
+
+    /// Marks a command written by the device thread itself e.g. "$G" after entering the "Tool" state
+    pub fn put_internal_command(&mut self) {
+        self.internal_responses_pending += 1;
+    }
+
+    /// Indicates if the device thread may write its own command
+    ///
+    /// Responses arrive in order, the command must not be outstanding together with a job line
+    #[must_use]
+    pub fn is_ready_for_internal_command(&self) -> bool {
+        self.internal_responses_pending == 0 && !self.job_progress.as_ref().is_some_and(|progress| progress.is_awaiting_acknowledgement())
+    }
+
+    /// Indicates if the response of a command written by the device thread is outstanding
+    #[must_use]
+    pub fn is_internal_response_pending(&self) -> bool {
+        self.internal_responses_pending > 0
+    }
+
+    /// Indicates if the pendant has control of the machine ("MPG:1")
+    #[must_use]
+    pub fn is_pendant_control_taken(&self) -> bool {
+        self.machine_info.as_ref().and_then(|info| info.pendant_control()) == Some(&PendantControl::Taken)
+    }
+
+    /// Indicates once after the pendant released control that the full status report (0x87)
+    /// and the gcode state ("$G") have to be requested
+    ///
+    /// The pendant may have changed positions, modes and overrides while in control
+    pub fn take_resync_request(&mut self) -> bool {
+        std::mem::take(&mut self.resync_requested)
+    }
+
+    /// Tracks the pendant taking and releasing control, "MPG:" is only reported on change
+    ///
+    /// A running job fails because grblHAL ignores the host stream while the pendant is in control
+    fn update_pendant_control(&mut self, pendant_control: Option<PendantControl>, previous_pendant_control: Option<PendantControl>) {
+        let pendant_control = match pendant_control {
+            Some(pendant_control) if Some(pendant_control) != previous_pendant_control => pendant_control,
+            _ => return,
+        };
+        match pendant_control {
+            PendantControl::Taken => {
+                if let Some(job_progress) = self.job_progress.as_mut() {
+                    job_progress.fail("Pendant took control");
+                }
+                self.put_event(DeviceEvent::PendantControlTaken);
+            }
+            PendantControl::Released if previous_pendant_control.is_some() => {
+                self.resync_requested = true;
+                self.put_event(DeviceEvent::PendantControlReleased);
+            }
+            PendantControl::Released => (),
+        }
+    }
+
+    /// Tracks entering and leaving the "Tool" state
+    fn update_tool_change(&mut self, state: MachineStateName, previous_state: Option<MachineStateName>) {
+        let was_tool_change = previous_state == Some(MachineStateName::Tool);
+        if state == MachineStateName::Tool && !was_tool_change {
+            self.tool_change_pending = true;
+            self.parser_state_requested = true;
+        } else if state != MachineStateName::Tool && was_tool_change {
+            // program driven tool changes (M6) may complete before the tool number was read
+            self.requested_tool = None;
+            self.put_event(DeviceEvent::ToolChangeCompleted);
+            self.tool_change_pending = false;
+            self.parser_state_requested = false;
+        }
+    }
+
+    /// Pending synchronized outputs are applied once the motion queued after them is completed
+    fn update_aux_outputs(&mut self, state: MachineStateName) {
+        match state {
+            MachineStateName::Run => self.aux_outputs.put_motion(),
+            MachineStateName::Idle => self.aux_outputs.apply_pending(),
+            _ => (),
+        }
+    }
+
+    /// Adds an event for each signal edge between the reports
+    fn put_signal_events(&mut self, signals: &SignalSet, previous_signals: &SignalSet) {
+        for signal in signals.rising_edges(previous_signals).signals() {
+            self.put_event(DeviceEvent::SignalAsserted(signal));
+        }
+        for signal in signals.falling_edges(previous_signals).signals() {
+            self.put_event(DeviceEvent::SignalReleased(signal));
+        }
+    }
 }
 
 unsafe impl Send for DeviceInfo {}
\ No newline at end of file
@@ -1,3 +1,13 @@
+pub mod accessory;
+pub mod coordinate;
+pub mod homing;
+pub mod io;
+pub mod jog;
+pub mod laser;
+pub mod overrides;
+pub mod probe;
+pub mod startup;
+pub mod tool;
 pub mod util;
 
 /// Indicates if the executor of this command should expect a status code
@@ -24,6 +34,42 @@ pub mod realtime {
     pub const STATUS_REPORT: &str = "?";
     pub const CYCLE_START: &str = "~";
     pub const FEED_HOLD: &str = "!";
+
+    /// Extended realtime commands, must be written as single bytes
+    pub mod overrides {
+        pub const FEED_RESET: u8 = 0x90;
+        pub const FEED_COARSE_PLUS: u8 = 0x91;
+        pub const FEED_COARSE_MINUS: u8 = 0x92;
+        pub const FEED_FINE_PLUS: u8 = 0x93;
+        pub const FEED_FINE_MINUS: u8 = 0x94;
+
+        pub const RAPID_RESET: u8 = 0x95;
+        pub const RAPID_MEDIUM: u8 = 0x96;
+        pub const RAPID_LOW: u8 = 0x97;
+
+        pub const SPINDLE_RESET: u8 = 0x99;
+        pub const SPINDLE_COARSE_PLUS: u8 = 0x9A;
+        pub const SPINDLE_COARSE_MINUS: u8 = 0x9B;
+        pub const SPINDLE_FINE_PLUS: u8 = 0x9C;
+        pub const SPINDLE_FINE_MINUS: u8 = 0x9D;
+    }
+
+    /// Extended realtime accessory toggles, must be written as single bytes
+    pub mod accessory {
+        /// Toggles the spindle stop, only available in hold state
+        pub const SPINDLE_STOP_TOGGLE: u8 = 0x9E;
+        pub const FLOOD_TOGGLE: u8 = 0xA0;
+        pub const MIST_TOGGLE: u8 = 0xA1;
+    }
+
+    /// grblHAL realtime commands, must be written as single bytes
+    pub mod extended {
+        /// Requests a status report with all fields e.g. "WCO:", "Ov:" and "MPG:"
+        pub const FULL_STATUS_REPORT: u8 = 0x87;
+
+        /// Toggles the MPG mode, the pendant takes or releases control of the machine
+        pub const MPG_MODE_TOGGLE: u8 = 0x8B;
+    }
 }
 
 pub mod general {
@@ -44,6 +90,9 @@ pub mod general {
 
     pub const GET_NGC_PARAMETERS: &str = "$#\r";
     pub const GET_STARTUP_LINES: &str = "$N\r";
+    pub const GET_SPINDLES: &str = "$SPINDLES\r";
+    pub const GET_ODOMETERS: &str = "$ODOMETERS\r";
+    pub const GET_PID_LOG: &str = "$PIDL\r";
 
     pub const NO_TOOL: &str = "None";
 }
@@ -65,12 +114,26 @@ pub mod settings {
 }
 
 pub mod sdcard {
-    pub const MOUNT: &str = "FM\r";
-    pub const DIR: &str = "F\r";
-    pub const REWIND: &str = "FR\r";
-    pub const RUN: &str = "F=\r";
-    pub const UNLINK: &str = "FD=\r";
-    pub const DUMP: &str = "F<=\r";
+    pub const MOUNT: &str = "$FM\r";
+    pub const DIR: &str = "$F\r";
+    pub const REWIND: &str = "$FR\r";
+
+    /// Prefixes of commands which require a file name e.g. "$F=/job.nc\r"
+    pub const RUN: &str = "$F=";
+    pub const UNLINK: &str = "$FD=";
+    pub const DUMP: &str = "$F<=";
+
+    /// Returns the command with the file name e.g. "$F=/job.nc\r"
+    ///
+    /// # Examples
+    /// ```
+    /// use grbli::device::command::sdcard::{get_file_command, RUN};
+    ///
+    /// assert_eq!(get_file_command(RUN, "/jobs/part.nc"), "$F=/jobs/part.nc\r");
+    /// ```
+    pub fn get_file_command(prefix: &str, file_name: &str) -> String {
+        format!("{}{}\r", prefix, file_name)
+    }
 }
 
 pub mod gcode {
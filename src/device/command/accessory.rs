@@ -0,0 +1,62 @@
+/// Rotation direction of the spindle
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpindleDirection {
+    /// M3
+    Clockwise,
+
+    /// M4
+    CounterClockwise,
+}
+
+/// Returns the command to start the spindle e.g. "M3 S1000\r"
+///
+/// The spindle number selects a grblHAL spindle with the "$" word
+///
+/// # Examples
+/// ```
+/// use grbli::device::command::accessory::{get_spindle_start_command, SpindleDirection};
+///
+/// assert_eq!(get_spindle_start_command(SpindleDirection::Clockwise, 12000.0, None), "M3 S12000\r");
+/// assert_eq!(get_spindle_start_command(SpindleDirection::CounterClockwise, 500.0, Some(1)), "M4 S500 $1\r");
+/// ```
+pub fn get_spindle_start_command(direction: SpindleDirection, rpm: f32, spindle: Option<u32>) -> String {
+    let spindle_word = match direction {
+        SpindleDirection::Clockwise => "M3",
+        SpindleDirection::CounterClockwise => "M4",
+    };
+    match spindle {
+        Some(number) => format!("{} S{} ${}\r", spindle_word, rpm, number),
+        None => format!("{} S{}\r", spindle_word, rpm),
+    }
+}
+
+/// Returns the command to stop the spindle "M5\r"
+pub fn get_spindle_stop_command(spindle: Option<u32>) -> String {
+    match spindle {
+        Some(number) => format!("M5 ${}\r", number),
+        None => String::from("M5\r"),
+    }
+}
+
+/// Returns the commands to set flood and mist coolant
+///
+/// M7 and M8 are in the same modal group and require a line each,
+/// M9 turns off both coolants before the enabled coolants are set.
+///
+/// # Examples
+/// ```
+/// use grbli::device::command::accessory::get_coolant_commands;
+///
+/// assert_eq!(get_coolant_commands(true, true), vec!["M9\r", "M8\r", "M7\r"]);
+/// assert_eq!(get_coolant_commands(false, false), vec!["M9\r"]);
+/// ```
+pub fn get_coolant_commands(flood: bool, mist: bool) -> Vec<String> {
+    let mut commands = vec![String::from("M9\r")];
+    if flood {
+        commands.push(String::from("M8\r"));
+    }
+    if mist {
+        commands.push(String::from("M7\r"));
+    }
+    commands
+}
@@ -0,0 +1,49 @@
+use crate::device::util::{axis::{get_axis_name, Axis}, coordinate::{get_coordinate_system_index, get_coordinate_system_name, CoordinateSystem}};
+
+/// Removes the G92 coordinate offset
+pub const CLEAR_COORDINATE_OFFSET: &str = "G92.1\r";
+
+/// Positions stored in the device for G28 and G30
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StoredPosition {
+    /// G28, stored with G28.1
+    Home,
+
+    /// G30, stored with G30.1
+    SecondaryHome,
+}
+
+/// Returns the command to set the offset of the coordinate system in mm e.g. "G21 G10 L2 P1 X10.000\r"
+///
+/// # Examples
+/// ```
+/// use grbli::device::{command::coordinate::get_set_coordinate_offset_command, util::{axis::Axis, coordinate::CoordinateSystem}};
+///
+/// let command = get_set_coordinate_offset_command(&CoordinateSystem::G59_1, &[(Axis::X, -100.0), (Axis::Y, 50.5)]);
+/// assert_eq!(command, "G21 G10 L2 P7 X-100.000 Y50.500\r");
+/// ```
+pub fn get_set_coordinate_offset_command(coordinate_system: &CoordinateSystem, offset: &[(Axis, f32)]) -> String {
+    let axis_words: Vec<String> = offset.iter().map(|(axis, value)| format!("{}{:.3}", get_axis_name(axis), value)).collect();
+    format!("G21 G10 L2 P{} {}\r", get_coordinate_system_index(coordinate_system) + 1, axis_words.join(" "))
+}
+
+/// Returns the command to activate the coordinate system e.g. "G59.1\r"
+pub fn get_select_coordinate_system_command(coordinate_system: &CoordinateSystem) -> String {
+    format!("{}\r", get_coordinate_system_name(coordinate_system))
+}
+
+/// Returns the command to store the current machine position e.g. "G28.1\r"
+pub fn get_store_position_command(stored_position: StoredPosition) -> String {
+    match stored_position {
+        StoredPosition::Home => String::from("G28.1\r"),
+        StoredPosition::SecondaryHome => String::from("G30.1\r"),
+    }
+}
+
+/// Returns the command to move to the stored position e.g. "G28\r"
+pub fn get_go_to_stored_position_command(stored_position: StoredPosition) -> String {
+    match stored_position {
+        StoredPosition::Home => String::from("G28\r"),
+        StoredPosition::SecondaryHome => String::from("G30\r"),
+    }
+}
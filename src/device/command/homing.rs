@@ -0,0 +1,34 @@
+use crate::device::util::axis::Axis;
+
+use super::general::home::*;
+
+/// Returns the homing commands for the axes
+///
+/// All axes are homed with a single "$H" if no axis is given,
+/// otherwise each axis is homed separately in the given order.
+///
+/// # Examples
+/// ```
+/// use grbli::device::{command::homing::get_home_commands, util::axis::Axis};
+///
+/// assert_eq!(get_home_commands(&[]), vec!["$H\r"]);
+/// assert_eq!(get_home_commands(&[Axis::Z, Axis::X]), vec!["$HZ\r", "$HX\r"]);
+/// ```
+pub fn get_home_commands(axes: &[Axis]) -> Vec<String> {
+    if axes.is_empty() {
+        return vec![HOME_ALL.to_string()];
+    }
+    axes.iter()
+        .map(|axis| {
+            match axis {
+                Axis::X => HOME_X,
+                Axis::Y => HOME_Y,
+                Axis::Z => HOME_Z,
+                Axis::A => HOME_A,
+                Axis::B => HOME_B,
+                Axis::C => HOME_C,
+            }
+            .to_string()
+        })
+        .collect()
+}
@@ -0,0 +1,98 @@
+use std::{result::Result, time::Duration};
+
+/// Point of time an aux output is changed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputMode {
+    /// Changed together with the next motion (M62/M63, M67)
+    Synchronized,
+
+    /// Changed immediately (M64/M65, M68)
+    Immediate,
+}
+
+/// Aux input to wait for with M66
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuxInput {
+    /// Digital input port (P word)
+    Digital(u16),
+
+    /// Analog input port (E word)
+    Analog(u16),
+}
+
+/// Condition of M66 (L word)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InputWaitMode {
+    /// Reads the input without waiting, the only mode for analog inputs
+    Immediate,
+    Rise,
+    Fall,
+    High,
+    Low,
+}
+
+/// Returns the command to switch a digital output e.g. "M62 P0\r"
+///
+/// # Examples
+/// ```
+/// use grbli::device::command::io::{get_digital_output_command, OutputMode};
+///
+/// assert_eq!(get_digital_output_command(0, true, OutputMode::Synchronized), "M62 P0\r");
+/// assert_eq!(get_digital_output_command(2, false, OutputMode::Immediate), "M65 P2\r");
+/// ```
+pub fn get_digital_output_command(port: u16, enabled: bool, mode: OutputMode) -> String {
+    let code = match (mode, enabled) {
+        (OutputMode::Synchronized, true) => "M62",
+        (OutputMode::Synchronized, false) => "M63",
+        (OutputMode::Immediate, true) => "M64",
+        (OutputMode::Immediate, false) => "M65",
+    };
+    format!("{} P{}\r", code, port)
+}
+
+/// Returns the command to set an analog output e.g. "M67 E0 Q50\r"
+///
+/// # Examples
+/// ```
+/// use grbli::device::command::io::{get_analog_output_command, OutputMode};
+///
+/// assert_eq!(get_analog_output_command(1, 2.5, OutputMode::Immediate), "M68 E1 Q2.5\r");
+/// ```
+pub fn get_analog_output_command(port: u16, value: f32, mode: OutputMode) -> String {
+    let code = match mode {
+        OutputMode::Synchronized => "M67",
+        OutputMode::Immediate => "M68",
+    };
+    format!("{} E{} Q{}\r", code, port, value)
+}
+
+/// Returns the command to wait for an input e.g. "M66 P0 L3 Q5.000\r"
+///
+/// The timeout is sent in seconds, the result is reported by the "In:" field of the next status report
+///
+/// # Errors
+/// Returns an error if an analog input is combined with any mode but [`InputWaitMode::Immediate`]
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use grbli::device::command::io::{get_wait_for_input_command, AuxInput, InputWaitMode};
+///
+/// let command = get_wait_for_input_command(AuxInput::Digital(0), InputWaitMode::High, Duration::from_secs(5));
+/// assert_eq!(command.unwrap(), "M66 P0 L3 Q5.000\r");
+/// ```
+pub fn get_wait_for_input_command(input: AuxInput, mode: InputWaitMode, timeout: Duration) -> Result<String, String> {
+    let mode_value = match mode {
+        InputWaitMode::Immediate => 0,
+        InputWaitMode::Rise => 1,
+        InputWaitMode::Fall => 2,
+        InputWaitMode::High => 3,
+        InputWaitMode::Low => 4,
+    };
+    let port_word = match input {
+        AuxInput::Digital(port) => format!("P{}", port),
+        AuxInput::Analog(port) if mode == InputWaitMode::Immediate => format!("E{}", port),
+        AuxInput::Analog(port) => return Err(format!("Analog input {} can only be read immediately", port)),
+    };
+    Ok(format!("M66 {} L{} Q{:.3}\r", port_word, mode_value, timeout.as_secs_f32()))
+}
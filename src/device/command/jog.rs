@@ -0,0 +1,23 @@
+use crate::device::util::axis::{get_axis_name, Axis};
+
+const JOG_PREFIX: &str = "$J=";
+
+/// Returns a jog command for the target e.g. "$J=G90 G21 X10.000 F500.000\r"
+///
+/// Target values are in mm, either absolute work coordinates or relative to the current position
+///
+/// # Examples
+/// ```
+/// use grbli::device::{command::jog::get_jog_command, util::axis::Axis};
+///
+/// let command = get_jog_command(&[(Axis::X, 10.0)], 500.0, true);
+/// assert_eq!(command, "$J=G91 G21 X10.000 F500.000\r");
+/// ```
+pub fn get_jog_command(target: &[(Axis, f32)], feed_rate: f32, incremental: bool) -> String {
+    let distance_mode = match incremental {
+        true => "G91",
+        false => "G90",
+    };
+    let axis_words: Vec<String> = target.iter().map(|(axis, value)| format!("{}{:.3}", get_axis_name(axis), value)).collect();
+    format!("{}{} G21 {} F{:.3}\r", JOG_PREFIX, distance_mode, axis_words.join(" "), feed_rate)
+}
@@ -0,0 +1,55 @@
+use std::time::Duration;
+
+/// Returns the commands to trace the rectangle from (min x, min y) to (max x, max y) at low power
+///
+/// Coordinates are in mm of the active work coordinate system. Dynamic power (M4) keeps the
+/// laser off while the machine stands still at the corners. Distance mode, units, motion mode
+/// and feed rate stay changed.
+///
+/// # Examples
+/// ```
+/// use grbli::device::command::laser::get_frame_commands;
+///
+/// let commands = get_frame_commands((0.0, 0.0), (20.0, 10.0), 10.0, 3000.0);
+/// assert_eq!(commands[0], "G90 G21 G0 X0.000 Y0.000\r");
+/// assert_eq!(commands[1], "M4 S10\r");
+/// assert_eq!(commands[2], "G1 X20.000 Y0.000 F3000.000\r");
+/// assert_eq!(commands.last().unwrap(), "M5\r");
+/// ```
+pub fn get_frame_commands(min: (f32, f32), max: (f32, f32), spindle_speed: f32, feed_rate: f32) -> Vec<String> {
+    let corners = [(max.0, min.1), (max.0, max.1), (min.0, max.1), (min.0, min.1)];
+    let mut commands = vec![
+        format!("G90 G21 G0 X{:.3} Y{:.3}\r", min.0, min.1),
+        format!("M4 S{}\r", spindle_speed),
+    ];
+    for (x, y) in corners {
+        commands.push(format!("G1 X{:.3} Y{:.3} F{:.3}\r", x, y, feed_rate));
+    }
+    commands.push(String::from("M5\r"));
+    commands
+}
+
+/// Feed rate of the zero-length motion which switches the laser on, G1 requires any feed rate
+const TEST_FIRE_FEED_RATE: u32 = 100;
+
+/// Returns the commands to fire the laser at the current position for the duration
+///
+/// Laser mode ($32) only fires during G1/G2/G3 motions, the laser is switched on with a zero-length
+/// incremental G1 motion at constant power (M3) and kept on by the dwell. Distance mode, motion mode
+/// and feed rate stay changed.
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use grbli::device::command::laser::get_test_fire_commands;
+///
+/// let commands = get_test_fire_commands(50.0, Duration::from_millis(500));
+/// assert_eq!(commands, vec!["G91 G1 X0 F100 M3 S50\r", "G4 P0.500\r", "M5\r"]);
+/// ```
+pub fn get_test_fire_commands(spindle_speed: f32, duration: Duration) -> Vec<String> {
+    vec![
+        format!("G91 G1 X0 F{} M3 S{}\r", TEST_FIRE_FEED_RATE, spindle_speed),
+        format!("G4 P{:.3}\r", duration.as_secs_f32()),
+        String::from("M5\r"),
+    ]
+}
@@ -0,0 +1,129 @@
+use std::result::Result;
+
+use super::realtime::overrides::*;
+
+/// Minimum feed and spindle override in percent
+pub const MIN_OVERRIDE: i32 = 10;
+
+/// Maximum feed and spindle override in percent
+pub const MAX_OVERRIDE: i32 = 200;
+
+/// Percentage restored by the reset command
+const DEFAULT_OVERRIDE: i32 = 100;
+
+const COARSE_STEP: i32 = 10;
+
+/// Realtime commands of a stepwise override (feed or spindle)
+struct StepCommands {
+    reset: u8,
+    coarse_plus: u8,
+    coarse_minus: u8,
+    fine_plus: u8,
+    fine_minus: u8,
+}
+
+const FEED_COMMANDS: StepCommands = StepCommands {
+    reset: FEED_RESET,
+    coarse_plus: FEED_COARSE_PLUS,
+    coarse_minus: FEED_COARSE_MINUS,
+    fine_plus: FEED_FINE_PLUS,
+    fine_minus: FEED_FINE_MINUS,
+};
+
+const SPINDLE_COMMANDS: StepCommands = StepCommands {
+    reset: SPINDLE_RESET,
+    coarse_plus: SPINDLE_COARSE_PLUS,
+    coarse_minus: SPINDLE_COARSE_MINUS,
+    fine_plus: SPINDLE_FINE_PLUS,
+    fine_minus: SPINDLE_FINE_MINUS,
+};
+
+/// Returns the shortest sequence of realtime bytes to change the feed override from current to target
+///
+/// # Errors
+/// Returns an error if the target is outside of 10% - 200%
+///
+/// # Examples
+/// ```
+/// use grbli::device::command::{overrides::get_feed_override_commands, realtime::overrides::*};
+///
+/// // 100% -> 119%: +10% +10% -1%
+/// let commands = get_feed_override_commands(100, 119).unwrap();
+/// assert_eq!(commands, vec![FEED_COARSE_PLUS, FEED_COARSE_PLUS, FEED_FINE_MINUS]);
+/// ```
+pub fn get_feed_override_commands(current: i32, target: i32) -> Result<Vec<u8>, String> {
+    if !(MIN_OVERRIDE..=MAX_OVERRIDE).contains(&target) {
+        return Err(format!("Feed override {}% is outside of {}% - {}%", target, MIN_OVERRIDE, MAX_OVERRIDE));
+    }
+    Ok(get_step_commands(current, target, &FEED_COMMANDS))
+}
+
+/// Returns the shortest sequence of realtime bytes to change the spindle override from current to target
+///
+/// # Errors
+/// Returns an error if the target is outside of 10% - 200%
+pub fn get_spindle_override_commands(current: i32, target: i32) -> Result<Vec<u8>, String> {
+    if !(MIN_OVERRIDE..=MAX_OVERRIDE).contains(&target) {
+        return Err(format!("Spindle override {}% is outside of {}% - {}%", target, MIN_OVERRIDE, MAX_OVERRIDE));
+    }
+    Ok(get_step_commands(current, target, &SPINDLE_COMMANDS))
+}
+
+/// Returns the realtime byte to change the rapid override from current to target
+///
+/// Rapid overrides are limited to 100%, 50% and 25%
+///
+/// # Errors
+/// Returns an error if the target is not a rapid override level
+pub fn get_rapid_override_commands(current: i32, target: i32) -> Result<Vec<u8>, String> {
+    let command = match target {
+        100 => RAPID_RESET,
+        50 => RAPID_MEDIUM,
+        25 => RAPID_LOW,
+        _ => return Err(format!("Rapid override {}% is not one of 100%, 50% or 25%", target)),
+    };
+    match current == target {
+        true => Ok(Vec::new()),
+        false => Ok(vec![command]),
+    }
+}
+
+/// Selects the shorter sequence of either stepping from the current value or resetting to 100% first
+fn get_step_commands(current: i32, target: i32, commands: &StepCommands) -> Vec<u8> {
+    let direct_commands = get_steps(current, target, commands);
+    if current == DEFAULT_OVERRIDE {
+        return direct_commands;
+    }
+
+    let mut reset_commands = vec![commands.reset];
+    reset_commands.append(&mut get_steps(DEFAULT_OVERRIDE, target, commands));
+    match reset_commands.len() < direct_commands.len() {
+        true => reset_commands,
+        false => direct_commands,
+    }
+}
+
+/// Returns coarse steps followed by fine steps
+///
+/// Overshooting by one coarse step and stepping back is used if shorter and
+/// the intermediate value is not clamped by the device.
+fn get_steps(current: i32, target: i32, commands: &StepCommands) -> Vec<u8> {
+    let distance = (target - current).abs();
+    let (coarse, fine, fine_back) = match target > current {
+        true => (commands.coarse_plus, commands.fine_plus, commands.fine_minus),
+        false => (commands.coarse_minus, commands.fine_minus, commands.fine_plus),
+    };
+
+    let coarse_steps = (distance / COARSE_STEP) as usize;
+    let fine_steps = (distance % COARSE_STEP) as usize;
+    let mut steps = vec![coarse; coarse_steps];
+    steps.append(&mut vec![fine; fine_steps]);
+
+    let overshoot = current + (target - current).signum() * (coarse_steps as i32 + 1) * COARSE_STEP;
+    let overshoot_steps = coarse_steps + 1 + (COARSE_STEP as usize - fine_steps);
+    if fine_steps > 0 && overshoot_steps < steps.len() && (MIN_OVERRIDE..=MAX_OVERRIDE).contains(&overshoot) {
+        steps = vec![coarse; coarse_steps + 1];
+        steps.append(&mut vec![fine_back; COARSE_STEP as usize - fine_steps]);
+    }
+    steps
+}
@@ -0,0 +1,148 @@
+use crate::device::util::{axis::{get_axis_name, Axis}, coordinate::{get_coordinate_system_index, CoordinateSystem}};
+
+/// Probing cycle of G38.x
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProbeMode {
+    /// G38.2 probe toward the workpiece, alarm if no contact
+    Toward,
+
+    /// G38.3 probe toward the workpiece
+    TowardNoError,
+
+    /// G38.4 probe away from the workpiece, alarm if contact is not lost
+    Away,
+
+    /// G38.5 probe away from the workpiece
+    AwayNoError,
+}
+
+/// Outside corner of a workpiece seen from above with the front towards -Y
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProbeCorner {
+    FrontLeft,
+    FrontRight,
+    BackLeft,
+    BackRight,
+}
+
+impl ProbeCorner {
+
+    /// Returns the probing directions (X, Y) towards the corner's edges
+    ///
+    /// # Examples
+    /// ```
+    /// use grbli::device::command::probe::ProbeCorner;
+    ///
+    /// // the left edge is probed in +X, the back edge in -Y
+    /// assert_eq!(ProbeCorner::BackLeft.probe_directions(), (1.0, -1.0));
+    /// ```
+    pub fn probe_directions(&self) -> (f32, f32) {
+        match self {
+            ProbeCorner::FrontLeft => (1.0, 1.0),
+            ProbeCorner::FrontRight => (-1.0, 1.0),
+            ProbeCorner::BackLeft => (1.0, -1.0),
+            ProbeCorner::BackRight => (-1.0, -1.0),
+        }
+    }
+}
+
+/// Feed rates and distances of probing routines in mm and mm/min
+///
+/// Each probe is done with a fast pass, a retract and a slow second pass
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ProbeSettings {
+    max_distance: f32,
+    fast_feed_rate: f32,
+    slow_feed_rate: f32,
+    retract_distance: f32,
+}
+
+impl ProbeSettings {
+
+    /// Creates the settings, all values are used as absolute values
+    pub fn new(max_distance: f32, fast_feed_rate: f32, slow_feed_rate: f32, retract_distance: f32) -> Self {
+        ProbeSettings {
+            max_distance: max_distance.abs(),
+            fast_feed_rate: fast_feed_rate.abs(),
+            slow_feed_rate: slow_feed_rate.abs(),
+            retract_distance: retract_distance.abs(),
+        }
+    }
+
+    /// Get the maximum travel of the fast pass
+    pub fn max_distance(&self) -> f32 {
+        self.max_distance
+    }
+
+    /// Get the feed rate of the fast pass
+    pub fn fast_feed_rate(&self) -> f32 {
+        self.fast_feed_rate
+    }
+
+    /// Get the feed rate of the slow second pass
+    pub fn slow_feed_rate(&self) -> f32 {
+        self.slow_feed_rate
+    }
+
+    /// Get the distance moved back after each contact
+    pub fn retract_distance(&self) -> f32 {
+        self.retract_distance
+    }
+}
+
+impl Default for ProbeSettings {
+    fn default() -> Self {
+        ProbeSettings::new(20.0, 100.0, 25.0, 2.0)
+    }
+}
+
+/// Returns an incremental probe command in mm e.g. "G21 G91 G38.2 Z-10.000 F100.000\r"
+///
+/// # Examples
+/// ```
+/// use grbli::device::{command::probe::{get_probe_command, ProbeMode}, util::axis::Axis};
+///
+/// assert_eq!(get_probe_command(ProbeMode::Toward, &Axis::Z, -10.0, 100.0), "G21 G91 G38.2 Z-10.000 F100.000\r");
+/// ```
+pub fn get_probe_command(mode: ProbeMode, axis: &Axis, distance: f32, feed_rate: f32) -> String {
+    let probe_word = match mode {
+        ProbeMode::Toward => "G38.2",
+        ProbeMode::TowardNoError => "G38.3",
+        ProbeMode::Away => "G38.4",
+        ProbeMode::AwayNoError => "G38.5",
+    };
+    format!("G21 G91 {} {}{:.3} F{:.3}\r", probe_word, get_axis_name(axis), distance, feed_rate)
+}
+
+/// Returns an incremental rapid move in mm e.g. "G21 G91 G0 Z2.000\r"
+pub fn get_rapid_move_command(target: &[(Axis, f32)]) -> String {
+    format!("G21 G91 G0 {}\r", get_axis_words(target))
+}
+
+/// Returns a rapid move in machine coordinates in mm e.g. "G21 G90 G53 G0 X-10.000 Y-20.000\r"
+pub fn get_machine_move_command(target: &[(Axis, f32)]) -> String {
+    format!("G21 G90 G53 G0 {}\r", get_axis_words(target))
+}
+
+/// Returns the command to set the current position in the work coordinate system in mm e.g. "G21 G10 L20 P1 Z5.000\r"
+///
+/// Without coordinate system the active one is changed (P0)
+///
+/// # Examples
+/// ```
+/// use grbli::device::{command::probe::get_set_work_position_command, util::{axis::Axis, coordinate::CoordinateSystem}};
+///
+/// let command = get_set_work_position_command(Some(&CoordinateSystem::G55), &[(Axis::X, 0.0), (Axis::Z, 5.0)]);
+/// assert_eq!(command, "G21 G10 L20 P2 X0.000 Z5.000\r");
+/// ```
+pub fn get_set_work_position_command(coordinate_system: Option<&CoordinateSystem>, target: &[(Axis, f32)]) -> String {
+    let parameter = match coordinate_system {
+        Some(coordinate_system) => get_coordinate_system_index(coordinate_system) + 1,
+        None => 0,
+    };
+    format!("G21 G10 L20 P{} {}\r", parameter, get_axis_words(target))
+}
+
+fn get_axis_words(target: &[(Axis, f32)]) -> String {
+    target.iter().map(|(axis, value)| format!("{}{:.3}", get_axis_name(axis), value)).collect::<Vec<String>>().join(" ")
+}
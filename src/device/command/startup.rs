@@ -0,0 +1,49 @@
+use std::result::Result;
+
+use super::general::GET_STARTUP_LINES;
+
+/// Count of startup lines stored by the device ("$N0", "$N1")
+pub const STARTUP_LINES_COUNT: u32 = 2;
+
+/// Maximum length of a startup line, lines are stored in 80 bytes including a terminating zero
+pub const MAX_STARTUP_LINE_LENGTH: usize = 79;
+
+/// Returns the command to store the line as startup line e.g. "$N0=G54G21\r"
+///
+/// An empty line clears the startup line
+///
+/// # Errors
+/// Returns an error if the index is not a startup line, the line is too long
+/// or contains a system command or line break
+///
+/// # Examples
+/// ```
+/// use grbli::device::command::startup::get_set_startup_line_command;
+///
+/// assert_eq!(get_set_startup_line_command(1, "G54 G21").unwrap(), "$N1=G54 G21\r");
+/// assert_eq!(get_set_startup_line_command(0, "").unwrap(), "$N0=\r");
+/// assert!(get_set_startup_line_command(0, "$H").is_err());
+/// ```
+pub fn get_set_startup_line_command(index: u32, line: &str) -> Result<String, String> {
+    if index >= STARTUP_LINES_COUNT {
+        return Err(format!("Startup line {} does not exist (0 - {})", index, STARTUP_LINES_COUNT - 1));
+    }
+    if line.len() > MAX_STARTUP_LINE_LENGTH {
+        return Err(format!("Startup line has {} instead of at most {} characters", line.len(), MAX_STARTUP_LINE_LENGTH));
+    }
+    if line.contains('$') {
+        return Err(format!("Startup line \"{}\" must not contain system commands", line));
+    }
+    if line.contains(['\r', '\n']) {
+        return Err("Startup line must be a single line".to_string());
+    }
+    Ok(format!("{}{}={}\r", GET_STARTUP_LINES.trim_end(), index, line))
+}
+
+/// Returns the command to clear the startup line e.g. "$N1=\r"
+///
+/// # Errors
+/// Returns an error if the index is not a startup line
+pub fn get_clear_startup_line_command(index: u32) -> Result<String, String> {
+    get_set_startup_line_command(index, "")
+}
@@ -0,0 +1,45 @@
+use crate::device::util::axis::{get_axis_name, Axis};
+
+/// Probes the tool length with the tool setter and applies it as tool length offset (grblHAL)
+///
+/// The first probe after homing sets the tool length reference ("TLR:1")
+pub const PROBE_TOOL_LENGTH: &str = "$TPW\r";
+
+/// Returns the command to set the dynamic tool length offset in mm e.g. "G21 G43.1 Z1.500\r"
+///
+/// # Examples
+/// ```
+/// use grbli::device::command::tool::get_set_tool_length_offset_command;
+///
+/// assert_eq!(get_set_tool_length_offset_command(-12.5), "G21 G43.1 Z-12.500\r");
+/// ```
+pub fn get_set_tool_length_offset_command(offset: f32) -> String {
+    format!("G21 G43.1 Z{:.3}\r", offset)
+}
+
+/// Returns the command to select and change to the tool e.g. "T2 M6\r"
+pub fn get_tool_change_command(tool: u32) -> String {
+    format!("T{} M6\r", tool)
+}
+
+/// Returns the command to set the tool table entry in mm e.g. "G21 G10 L1 P1 Z-12.500 R1.500\r"
+///
+/// # Examples
+/// ```
+/// use grbli::device::{command::tool::get_set_tool_offset_command, util::axis::Axis};
+///
+/// assert_eq!(get_set_tool_offset_command(1, &[(Axis::Z, -12.5)], Some(1.5)), "G21 G10 L1 P1 Z-12.500 R1.500\r");
+/// ```
+pub fn get_set_tool_offset_command(tool: u32, offset: &[(Axis, f32)], radius: Option<f32>) -> String {
+    let mut words: Vec<String> = offset.iter().map(|(axis, value)| format!("{}{:.3}", get_axis_name(axis), value)).collect();
+    if let Some(radius) = radius {
+        words.push(format!("R{:.3}", radius));
+    }
+    format!("G21 G10 L1 P{} {}\r", tool, words.join(" "))
+}
+
+/// Returns the command to set the tool offset so that the current position gets the target coordinates in mm e.g. "G21 G10 L10 P1 Z0.000\r"
+pub fn get_set_tool_offset_from_position_command(tool: u32, target: &[(Axis, f32)]) -> String {
+    let words: Vec<String> = target.iter().map(|(axis, value)| format!("{}{:.3}", get_axis_name(axis), value)).collect();
+    format!("G21 G10 L10 P{} {}\r", tool, words.join(" "))
+}
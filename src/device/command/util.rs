@@ -1,8 +1,23 @@
+use crate::device::response::{firmware::dialect::FirmwareDialect, state::gcode_state::GCodeState};
+
 use super::{settings, state, general, realtime};
 
 
 
-pub fn load_device_metadata_commands() -> Vec<String> {
+/// Returns the commands to read settings, firmware info and the current state
+///
+/// Classic grbl reports neither descriptions ("$ES", "$EG", "$EE", "$EA") nor extended info ("$I+")
+pub fn load_device_metadata_commands(dialect: &FirmwareDialect) -> Vec<String> {
+    if !dialect.has_descriptions() {
+        return vec![
+            settings::GET.to_string(),
+            state::GET_INFO.to_string(),
+            general::GET_NGC_PARAMETERS.to_string(),
+            general::GET_STARTUP_LINES.to_string(),
+            realtime::STATUS_REPORT.to_string(),
+            general::SYNC.to_string()
+        ];
+    }
     vec![
         // read all setting info
         settings::GET_ALL.to_string(),
@@ -22,4 +37,24 @@ pub fn load_device_metadata_commands() -> Vec<String> {
         realtime::STATUS_REPORT.to_string(),
         general::SYNC.to_string()
     ] 
-}
\ No newline at end of file
+}
+/// Returns the command restoring the motion mode, units, distance mode and feed rate of the parser state
+///
+/// Only motion modes without required axis words (G0, G1, G80) are restored
+///
+/// # Examples
+/// ```
+/// use grbli::device::{command::util::get_restore_modal_state_command, response::state::gcode_state::GCodeState};
+///
+/// let gcode_state = GCodeState::from("[GC:G1 G54 G17 G20 G91 G94 M5 M9 T0 F20 S0]").unwrap();
+/// assert_eq!(get_restore_modal_state_command(&gcode_state), "G1 G20 G91 F20\r");
+/// ```
+pub fn get_restore_modal_state_command(gcode_state: &GCodeState) -> String {
+    let words: Vec<&str> = gcode_state
+        .values()
+        .iter()
+        .map(|value| value.as_str())
+        .filter(|value| matches!(*value, "G0" | "G1" | "G80" | "G20" | "G21" | "G90" | "G91") || value.starts_with('F'))
+        .collect();
+    format!("{}\r", words.join(" "))
+}
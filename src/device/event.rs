@@ -0,0 +1,56 @@
+use super::response::state::signal::MachineSignal;
+
+/// Maximum count of events kept until they are taken
+pub const MAX_DEVICE_EVENTS: usize = 256;
+
+/// Change of the device state detected between two responses
+///
+/// # Examples
+/// ```text
+/// // probe touched
+/// DeviceEvent::SignalAsserted(MachineSignal::ProbeTriggered)
+///
+/// // door opened
+/// DeviceEvent::SignalAsserted(MachineSignal::DoorSwitchAsserted)
+///
+/// // limit X hit
+/// DeviceEvent::SignalAsserted(MachineSignal::XLimitSwitchAsserted)
+///
+/// // M6 T2 with manual tool change
+/// DeviceEvent::ToolChangeRequested(2)
+///
+/// // welcome message after 0x18
+/// DeviceEvent::Reset
+///
+/// // "MPG:1" after the pendant switched to MPG mode
+/// DeviceEvent::PendantControlTaken
+///
+/// // "THC:0.0,ET" after "THC:121.5,AET"
+/// DeviceEvent::ArcLost
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum DeviceEvent {
+    /// Signal is asserted now but was not in the previous report
+    SignalAsserted(MachineSignal),
+
+    /// Signal was asserted in the previous report but is not anymore
+    SignalReleased(MachineSignal),
+
+    /// Device entered the "Tool" state and waits for the tool with this number
+    ToolChangeRequested(u32),
+
+    /// Device left the "Tool" state of a host or program driven tool change (M6)
+    ToolChangeCompleted,
+
+    /// Device sent its welcome message after power up or a soft reset
+    Reset,
+
+    /// Pendant took control of the machine ("MPG:1"), the host may only send realtime commands
+    PendantControlTaken,
+
+    /// Pendant released the control of the machine ("MPG:0")
+    PendantControlReleased,
+
+    /// Plasma arc was lost while the torch is on, see [`ThcState::is_arc_lost`](super::response::state::thc::ThcState::is_arc_lost)
+    ArcLost,
+}
@@ -5,12 +5,14 @@ use crate::device::response::{
         compile_option::{
             is_extended_compile_options, parse_extended_compile_options, CompileOptions,
         },
-        startup::FirmwareStartupResult,
+        spindle::SpindleInfo,
+        startup::{FirmwareStartupResult, StartupLine},
         version::FirmwareVersion,
+        welcome::WelcomeMessage,
     },
     report::MachineInfo,
-    state::gcode_state::GCodeState,
-    util::{echo::EchoMessage, message::Message},
+    state::{gcode_state::GCodeState, ngc_parameters::NgcParameter, odometer::Odometer, pid_log::PidLog, probe::ProbeResult, sdcard::SdCardFile, tool_table::ToolTableEntry},
+    util::{echo::EchoMessage, message::Message, status::{is_response_status, parse_response_status}},
 };
 
 use self::{
@@ -18,6 +20,7 @@ use self::{
 };
 
 use super::DeviceInfo;
+pub mod defaults;
 pub mod firmware;
 pub mod report;
 pub mod setting;
@@ -29,7 +32,18 @@ pub mod error;
 /// Reads any response and updates the device info accordingly
 pub fn read_response(response: &str, device_info: &mut DeviceInfo) -> Result<(), String> {
     debug!("Parse response: {}", response);
-    if MachineInfo::is_response(response) {
+    if device_info.is_sd_card_dump_active() && !is_response_status(response) && !MachineInfo::is_response(response) && !WelcomeMessage::is_response(response) && !Message::is_response(response) {
+        device_info.put_sd_card_dump_line(response);
+        Ok(())
+    } else if is_response_status(response) {
+        match parse_response_status(response) {
+            Ok(status) => {
+                device_info.put_response_status(status);
+                Ok(())
+            }
+            Err(err) => Err(err),
+        }
+    } else if MachineInfo::is_response(response) {
         match MachineInfo::from(response) {
             Ok(info) => {
                 device_info.update_machine_info(info);
@@ -37,6 +51,14 @@ pub fn read_response(response: &str, device_info: &mut DeviceInfo) -> Result<(),
             }
             Err(err) => Err(err),
         }
+    } else if WelcomeMessage::is_response(response) {
+        match WelcomeMessage::from(response) {
+            Ok(value) => {
+                device_info.put_welcome_message(value);
+                Ok(())
+            }
+            Err(err) => Err(err),
+        }
     } else if GCodeState::is_response(response) {
         match GCodeState::from(response) {
             Ok(value) => {
@@ -45,6 +67,63 @@ pub fn read_response(response: &str, device_info: &mut DeviceInfo) -> Result<(),
             }
             Err(err) => Err(err),
         }
+    } else if ProbeResult::is_response(response) {
+        match ProbeResult::from(response) {
+            Ok(value) => {
+                device_info.set_probe_result(value);
+                Ok(())
+            }
+            Err(err) => Err(err),
+        }
+    } else if NgcParameter::is_response(response) {
+        match NgcParameter::from(response) {
+            Ok(value) => {
+                device_info.put_ngc_parameter(value);
+                Ok(())
+            }
+            Err(err) => Err(err),
+        }
+    } else if ToolTableEntry::is_response(response) {
+        match ToolTableEntry::from(response) {
+            Ok(value) => {
+                device_info.put_tool_table_entry(value);
+                Ok(())
+            }
+            Err(err) => Err(err),
+        }
+    } else if SdCardFile::is_response(response) {
+        match SdCardFile::from(response) {
+            Ok(value) => {
+                device_info.put_sd_card_file(value);
+                Ok(())
+            }
+            Err(err) => Err(err),
+        }
+    } else if StartupLine::is_response(response) {
+        // has to be checked before settings which match any "$"
+        match StartupLine::from(response) {
+            Ok(value) => {
+                device_info.put_startup_line(value);
+                Ok(())
+            }
+            Err(err) => Err(err),
+        }
+    } else if Odometer::is_response(response) {
+        match Odometer::from(response) {
+            Ok(value) => {
+                device_info.set_odometer(value);
+                Ok(())
+            }
+            Err(err) => Err(err),
+        }
+    } else if PidLog::is_response(response) {
+        match PidLog::from(response) {
+            Ok(value) => {
+                device_info.set_pid_log(value);
+                Ok(())
+            }
+            Err(err) => Err(err),
+        }
     } else if Message::is_response(response) {
         match Message::from(response) {
             Ok(value) => {
@@ -83,6 +162,7 @@ fn is_firmware_info_response(response: &str) -> bool {
         || FirmwareStartupResult::is_response(response)
         || CompileOptions::is_response(response)
         || is_extended_compile_options(response)
+        || SpindleInfo::is_response(response)
         || is_firmware_board_response(response)
         || is_firmware_driver_response(response)
 }
@@ -175,9 +255,7 @@ fn read_firmware_info_response(
     } else if FirmwareStartupResult::is_response(response) {
         match FirmwareStartupResult::from(response) {
             Ok(value) => {
-                device_info
-                    .firmware_info_mut()
-                    .set_startup_result(Some(value));
+                device_info.firmware_info_mut().put_startup_result(value);
                 Ok(())
             }
             Err(err) => Err(err),
@@ -202,6 +280,14 @@ fn read_firmware_info_response(
             }
             Err(err) => Err(err),
         }
+    } else if SpindleInfo::is_response(response) {
+        match SpindleInfo::from(response) {
+            Ok(value) => {
+                device_info.firmware_info_mut().put_spindle(value);
+                Ok(())
+            }
+            Err(err) => Err(err),
+        }
     } else if is_firmware_driver_response(response) {
         read_firmware_driver_response(response, device_info)
     } else if is_firmware_board_response(response) {
@@ -0,0 +1,154 @@
+// Descriptions of the standard grbl status codes and core settings
+//
+// Classic grbl cannot report descriptions ("$EE", "$EA", "$ES"). The tables are stored
+// as initial descriptions, reported descriptions replace them.
+
+/// Value types of setting descriptions ("[SETTING:...]")
+const VALUE_TYPE_BOOL: u8 = 0;
+const VALUE_TYPE_MASK: u8 = 1;
+const VALUE_TYPE_AXIS_MASK: u8 = 4;
+const VALUE_TYPE_INTEGER: u8 = 5;
+const VALUE_TYPE_DECIMAL: u8 = 6;
+
+/// Error codes of grbl 1.1 and grblHAL, grblHAL keeps the grbl 1.1 numbers
+pub const DEFAULT_ERROR_CODES: &[(u16, &str)] = &[
+    (1, "G-code words consist of a letter and a value. Letter was not found."),
+    (2, "Numeric value format is not valid or missing an expected value."),
+    (3, "Grbl '$' system command was not recognized or supported."),
+    (4, "Negative value received for an expected positive value."),
+    (5, "Homing cycle is not enabled via settings."),
+    (6, "Minimum step pulse time must be greater than 3usec."),
+    (7, "EEPROM read failed. Reset and restored to default values."),
+    (8, "Grbl '$' command cannot be used unless Grbl is IDLE. Ensures smooth operation during a job."),
+    (9, "G-code locked out during alarm or jog state."),
+    (10, "Soft limits cannot be enabled without homing also enabled."),
+    (11, "Max characters per line exceeded. Line was not processed and executed."),
+    (12, "Grbl '$' setting value exceeds the maximum step rate supported."),
+    (13, "Safety door detected as opened and door state initiated."),
+    (14, "Build info or startup line exceeded EEPROM line length limit."),
+    (15, "Jog target exceeds machine travel. Command ignored."),
+    (16, "Jog command with no '=' or contains prohibited g-code."),
+    (17, "Laser mode requires PWM output."),
+    (18, "Reset asserted."),
+    (19, "Non positive value."),
+    (20, "Unsupported or invalid g-code command found in block."),
+    (21, "More than one g-code command from same modal group found in block."),
+    (22, "Feed rate has not yet been set or is undefined."),
+    (23, "G-code command in block requires an integer value."),
+    (24, "Two G-code commands that both require the use of the XYZ axis words were detected in the block."),
+    (25, "A G-code word was repeated in the block."),
+    (26, "A G-code command implicitly or explicitly requires XYZ axis words in the block, but none were detected."),
+    (27, "N line number value is not within the valid range of 1 - 9,999,999."),
+    (28, "A G-code command was sent, but is missing some required P or L value words in the line."),
+    (29, "Grbl supports six work coordinate systems G54-G59. G59.1, G59.2, and G59.3 are not supported."),
+    (30, "The G53 G-code command requires either a G0 seek or G1 feed motion mode to be active. A different motion was active."),
+    (31, "There are unused axis words in the block and G80 motion mode cancel is active."),
+    (32, "A G2 or G3 arc was commanded but there are no XYZ axis words in the selected plane to trace the arc."),
+    (33, "The motion command has an invalid target. G2, G3, and G38.2 generates this error, if the arc is impossible to generate or if the probe target is the current position."),
+    (34, "A G2 or G3 arc, traced with the radius definition, had a mathematical error when computing the arc geometry. Try either breaking up the arc into semi-circles or quadrants, or redefine them with the arc offset definition."),
+    (35, "A G2 or G3 arc, traced with the offset definition, is missing the IJK offset word in the selected plane to trace the arc."),
+    (36, "There are unused, leftover G-code words that aren't used by any command in the block."),
+    (37, "The G43.1 dynamic tool length offset command cannot apply an offset to an axis other than its configured axis. The Grbl default axis is the Z-axis."),
+    (38, "Tool number greater than max supported value."),
+    (39, "Value out of range."),
+    (40, "G-code command not allowed when tool change is pending."),
+    (41, "Spindle not running."),
+    (42, "Plane must be ZX for threading."),
+    (43, "Max. feed rate exceeded."),
+    (44, "RPM out of range."),
+    (45, "Only homing is allowed when a limit switch is engaged."),
+    (46, "Home machine to continue."),
+    (47, "ATC: current tool is not set. Set current tool with M61."),
+    (48, "Value word conflict."),
+    (49, "Power on self test failed. A hard reset is required."),
+    (50, "Emergency stop active."),
+    (51, "Motor fault."),
+    (52, "Setting value is out of range."),
+    (53, "Setting is not available, possibly due to limited driver support."),
+    (54, "Retract position is less than drill depth."),
+    (55, "Attempt to home two auto squared axes at the same time."),
+    (56, "Coordinate system is locked."),
+    (60, "SD Card mount failed."),
+    (61, "SD Card file open/read failed."),
+    (62, "SD Card directory listing failed."),
+    (63, "SD Card directory not found."),
+    (64, "SD Card file empty."),
+    (70, "Bluetooth initialisation failed."),
+    (71, "Unknown operation found in expression."),
+    (72, "Divide by zero in expression attempted."),
+    (73, "Too large or too small argument provided."),
+    (74, "Argument is not valid for the operation."),
+    (75, "Expression is not valid."),
+    (76, "Either NAN (not a number) or infinity was returned from expression."),
+    (77, "Authentication required."),
+    (78, "Access denied."),
+    (79, "Not allowed while critical event is active."),
+    (80, "Flow statement only allowed in filesystem macro."),
+    (81, "Unexpected flow statement."),
+    (82, "Stack overflow while executing flow statement."),
+    (83, "Out of memory while executing flow statement."),
+    (84, "Could not open file."),
+];
+
+/// Alarm codes of grbl 1.1 and grblHAL
+pub const DEFAULT_ALARM_CODES: &[(u16, &str)] = &[
+    (1, "Hard limit triggered. Machine position is likely lost due to sudden and immediate halt. Re-homing is highly recommended."),
+    (2, "G-code motion target exceeds machine travel. Machine position safely retained. Alarm may be unlocked."),
+    (3, "Reset while in motion. Grbl cannot guarantee position. Lost steps are likely. Re-homing is highly recommended."),
+    (4, "Probe fail. The probe is not in the expected initial state before starting probe cycle, where G38.2 and G38.3 is not triggered and G38.4 and G38.5 is triggered."),
+    (5, "Probe fail. Probe did not contact the workpiece within the programmed travel for G38.2 and G38.4."),
+    (6, "Homing fail. Reset during active homing cycle."),
+    (7, "Homing fail. Safety door was opened during active homing cycle."),
+    (8, "Homing fail. Cycle failed to clear limit switch when pulling off. Try increasing pull-off setting or check wiring."),
+    (9, "Homing fail. Could not find limit switch within search distance. Defined as 1.5 * max_travel on search and 5 * pulloff on locate phases."),
+    (10, "EStop asserted. Clear and reset."),
+    (11, "Homing required. Execute homing command ($H) to continue."),
+    (12, "Limit switch engaged. Clear before continuing."),
+    (13, "Probe protection triggered. Clear before continuing."),
+    (14, "Spindle at speed timeout. Clear before continuing."),
+    (15, "Homing fail. Could not find second limit switch for auto squared axis within search distances. Try increasing max travel, decreasing pull-off distance, or check wiring."),
+    (16, "Power on self test (POS) failed."),
+    (17, "Motor fault."),
+    (18, "Homing fail. Bad configuration."),
+    (19, "Modbus exception."),
+    (20, "I/O expander communication failed."),
+    (21, "Non volatile storage failed."),
+];
+
+/// Core settings of grbl 1.1 as (index, description, unit, value type)
+pub const DEFAULT_SETTING_DESCRIPTIONS: &[(u32, &str, Option<&str>, u8)] = &[
+    (0, "Step pulse time", Some("microseconds"), VALUE_TYPE_DECIMAL),
+    (1, "Step idle delay", Some("milliseconds"), VALUE_TYPE_INTEGER),
+    (2, "Step pulse invert", None, VALUE_TYPE_AXIS_MASK),
+    (3, "Step direction invert", None, VALUE_TYPE_AXIS_MASK),
+    (4, "Invert step enable pin", None, VALUE_TYPE_BOOL),
+    (5, "Invert limit pins", None, VALUE_TYPE_BOOL),
+    (6, "Invert probe pin", None, VALUE_TYPE_BOOL),
+    (10, "Status report options", None, VALUE_TYPE_MASK),
+    (11, "Junction deviation", Some("mm"), VALUE_TYPE_DECIMAL),
+    (12, "Arc tolerance", Some("mm"), VALUE_TYPE_DECIMAL),
+    (13, "Report in inches", None, VALUE_TYPE_BOOL),
+    (20, "Soft limits enable", None, VALUE_TYPE_BOOL),
+    (21, "Hard limits enable", None, VALUE_TYPE_BOOL),
+    (22, "Homing cycle enable", None, VALUE_TYPE_BOOL),
+    (23, "Homing direction invert", None, VALUE_TYPE_AXIS_MASK),
+    (24, "Homing locate feed rate", Some("mm/min"), VALUE_TYPE_DECIMAL),
+    (25, "Homing search seek rate", Some("mm/min"), VALUE_TYPE_DECIMAL),
+    (26, "Homing switch debounce delay", Some("milliseconds"), VALUE_TYPE_INTEGER),
+    (27, "Homing switch pull-off distance", Some("mm"), VALUE_TYPE_DECIMAL),
+    (30, "Maximum spindle speed", Some("RPM"), VALUE_TYPE_DECIMAL),
+    (31, "Minimum spindle speed", Some("RPM"), VALUE_TYPE_DECIMAL),
+    (32, "Laser-mode enable", None, VALUE_TYPE_BOOL),
+    (100, "X-axis travel resolution", Some("step/mm"), VALUE_TYPE_DECIMAL),
+    (101, "Y-axis travel resolution", Some("step/mm"), VALUE_TYPE_DECIMAL),
+    (102, "Z-axis travel resolution", Some("step/mm"), VALUE_TYPE_DECIMAL),
+    (110, "X-axis maximum rate", Some("mm/min"), VALUE_TYPE_DECIMAL),
+    (111, "Y-axis maximum rate", Some("mm/min"), VALUE_TYPE_DECIMAL),
+    (112, "Z-axis maximum rate", Some("mm/min"), VALUE_TYPE_DECIMAL),
+    (120, "X-axis acceleration", Some("mm/sec^2"), VALUE_TYPE_DECIMAL),
+    (121, "Y-axis acceleration", Some("mm/sec^2"), VALUE_TYPE_DECIMAL),
+    (122, "Z-axis acceleration", Some("mm/sec^2"), VALUE_TYPE_DECIMAL),
+    (130, "X-axis maximum travel", Some("mm"), VALUE_TYPE_DECIMAL),
+    (131, "Y-axis maximum travel", Some("mm"), VALUE_TYPE_DECIMAL),
+    (132, "Z-axis maximum travel", Some("mm"), VALUE_TYPE_DECIMAL),
+];
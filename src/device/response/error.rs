@@ -1,5 +1,7 @@
 use std::{collections::BTreeMap, fmt::Debug};
 
+use super::defaults::{DEFAULT_ALARM_CODES, DEFAULT_ERROR_CODES};
+
 const ERROR_CODE_PREFIX: &str = "[ERRORCODE:";
 const ERROR_CODE_SUFFIX: &str = "]";
 const ALARM_CODE_PREFIX: &str = "[ALARMCODE:";
@@ -26,9 +28,12 @@ pub struct AlarmCode {
 
 impl StatusCodes {
 
-    /// Creates a empty status code collection
+    /// Creates a status code collection with the built-in descriptions, reported codes replace them
     pub fn new() -> Self {
-        StatusCodes { error_codes: BTreeMap::new(), alarm_codes: BTreeMap::new() }
+        StatusCodes {
+            error_codes: DEFAULT_ERROR_CODES.iter().map(|(code, description)| (*code, ErrorCode::new(*code, description))).collect(),
+            alarm_codes: DEFAULT_ALARM_CODES.iter().map(|(code, description)| (*code, AlarmCode::new(*code, description))).collect(),
+        }
     }
 
     /// Inserts a new error code or replaces the old value
@@ -42,6 +47,14 @@ impl StatusCodes {
     }
 
     /// Get a reference to the status codes's error codes.
+    ///
+    /// # Examples
+    /// ```
+    /// use grbli::device::response::error::StatusCodes;
+    ///
+    /// let status_codes = StatusCodes::new();
+    /// assert_eq!(status_codes.error_codes().get(&22).unwrap().description(), "Feed rate has not yet been set or is undefined.");
+    /// ```
     #[must_use]
     pub fn error_codes(&self) -> &BTreeMap<u16, ErrorCode> {
         &self.error_codes
@@ -99,6 +112,11 @@ fn parse_status_code(
 
 impl AlarmCode {
 
+    /// Creates an alarm code with its description
+    pub fn new(code: u16, description: &str) -> Self {
+        AlarmCode { code, description: description.to_string() }
+    }
+
     /// Reads an alarm code description message and stores the description for the specific code
     ///
     /// # Errors
@@ -147,6 +165,11 @@ impl Debug for AlarmCode {
 
 impl ErrorCode {
 
+    /// Creates an error code with its description
+    pub fn new(code: u16, description: &str) -> Self {
+        ErrorCode { code, description: description.to_string() }
+    }
+
     /// Reads an error code description message and stores the description for the specific code
     ///
     /// # Errors
@@ -1,28 +1,42 @@
-use self::{startup::FirmwareStartupResult, version::FirmwareVersion, compile_option::CompileOptions, driver::DriverInfo, board::BoardInfo};
+use std::collections::{BTreeMap, VecDeque};
+
+use self::{capabilities::Capabilities, dialect::FirmwareDialect, spindle::SpindleInfo, startup::FirmwareStartupResult, welcome::WelcomeMessage, version::FirmwareVersion, compile_option::CompileOptions, driver::DriverInfo, board::BoardInfo};
 
 use super::state::compile::ExtendedCompileOption;
 
+pub mod capabilities;
 pub mod compile_option;
+pub mod dialect;
 pub mod startup;
 pub mod version;
 pub mod board;
 pub mod driver;
+pub mod spindle;
+pub mod welcome;
+
+/// Maximum count of stored startup executions
+pub const MAX_STARTUP_HISTORY: usize = 20;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct FirmwareInfo {
     startup_result: Option<FirmwareStartupResult>,
+
+    /// Results of all executed startup lines, the oldest result first
+    startup_history: VecDeque<FirmwareStartupResult>,
+    welcome_message: Option<WelcomeMessage>,
     version: Option<FirmwareVersion>,
     compile_options: Option<CompileOptions>,
     extended_compile_options: Option<Vec<ExtendedCompileOption>>,
     driver_info: DriverInfo,
     board_info: BoardInfo,
+    spindles: BTreeMap<u32, SpindleInfo>,
 }
 
 impl FirmwareInfo {
 
     /// Creates a new empty firmware info
     pub fn new() -> Self {
-        FirmwareInfo { startup_result: None, version: None, compile_options: None, extended_compile_options: None, driver_info: DriverInfo::new(), board_info: BoardInfo::new() }
+        FirmwareInfo { startup_result: None, startup_history: VecDeque::new(), welcome_message: None, version: None, compile_options: None, extended_compile_options: None, driver_info: DriverInfo::new(), board_info: BoardInfo::new(), spindles: BTreeMap::new() }
     }
     
     /// Get a reference to the firmware info's startup state.
@@ -36,6 +50,57 @@ impl FirmwareInfo {
         self.startup_result = startup_result;
     }
 
+    /// Stores the result of an executed startup line as last result and in the history
+    ///
+    /// Drops the oldest result if the history is full
+    pub fn put_startup_result(&mut self, startup_result: FirmwareStartupResult) {
+        if self.startup_history.len() >= MAX_STARTUP_HISTORY {
+            self.startup_history.pop_front();
+        }
+        self.startup_history.push_back(startup_result.clone());
+        self.startup_result = Some(startup_result);
+    }
+
+    /// Get a reference to the results of all executed startup lines, the oldest result first
+    #[must_use]
+    pub fn startup_history(&self) -> &VecDeque<FirmwareStartupResult> {
+        &self.startup_history
+    }
+
+    /// Get a reference to the firmware info's last welcome message.
+    #[must_use]
+    pub fn welcome_message(&self) -> Option<&WelcomeMessage> {
+        self.welcome_message.as_ref()
+    }
+
+    /// Set the firmware info's last welcome message.
+    pub fn set_welcome_message(&mut self, welcome_message: Option<WelcomeMessage>) {
+        self.welcome_message = welcome_message;
+    }
+
+    /// Get the dialect of the firmware
+    ///
+    /// Extended compile options ("[NEWOPT:...]") are only reported by grblHAL and take precedence, grblHAL
+    /// may announce itself as "Grbl 1.1f". Otherwise the welcome message is preferred over the "$I" version,
+    /// grblHAL is assumed until either is known.
+    #[must_use]
+    pub fn dialect(&self) -> FirmwareDialect {
+        if self.extended_compile_options.is_some() {
+            return FirmwareDialect::GrblHAL;
+        }
+        match (&self.welcome_message, &self.version) {
+            (Some(welcome_message), _) => FirmwareDialect::from_welcome_message(welcome_message),
+            (None, Some(version)) => FirmwareDialect::from_version(version, false),
+            (None, None) => FirmwareDialect::GrblHAL,
+        }
+    }
+
+    /// Get the capabilities derived from compile options, driver options and aux ports
+    #[must_use]
+    pub fn capabilities(&self) -> Capabilities {
+        Capabilities::from(self)
+    }
+
     /// Get a reference to the firmware info's version.
     #[must_use]
     pub fn version(&self) -> Option<&FirmwareVersion> {
@@ -95,4 +160,21 @@ impl FirmwareInfo {
     pub fn board_info_mut(&mut self) -> &mut BoardInfo {
         &mut self.board_info
     }
+
+    /// Get a reference to the firmware info's spindles by id.
+    #[must_use]
+    pub fn spindles(&self) -> &BTreeMap<u32, SpindleInfo> {
+        &self.spindles
+    }
+
+    /// Get the spindle which is selected by the gcode number ($\<number\>)
+    #[must_use]
+    pub fn get_spindle_by_number(&self, number: u32) -> Option<&SpindleInfo> {
+        self.spindles.values().find(|spindle| spindle.number() == Some(number))
+    }
+
+    /// Adds or replaces the spindle
+    pub fn put_spindle(&mut self, spindle: SpindleInfo) {
+        self.spindles.insert(spindle.id(), spindle);
+    }
 }
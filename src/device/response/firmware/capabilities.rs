@@ -0,0 +1,186 @@
+use crate::device::response::state::compile::{CompileOption, ExtendedCompileOption};
+
+use super::FirmwareInfo;
+
+/// Feature of the firmware which is enabled at compile time
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Capability {
+    /// Laser mode ($32) requires a variable (PWM) spindle ("V" of "[OPT:]")
+    LaserMode,
+
+    /// Lathe mode ("LATHE" of "[NEWOPT:]")
+    LatheMode,
+
+    /// Spindle synchronized motion ("SS" of "[NEWOPT:]")
+    SpindleSync,
+
+    /// MPG mode ("MPG" of "[NEWOPT:]")
+    MPG,
+
+    /// Sd card streaming ("SD" of "[NEWOPT:]")
+    SDCard,
+
+    /// Automatic tool change ("ATC" of "[NEWOPT:]")
+    AutomaticToolChange,
+
+    /// Manual tool change ("TC" of "[NEWOPT:]")
+    ManualToolChange,
+
+    /// Probe input, missing if "[NEWOPT:]" reports "NOPROBE"
+    Probing,
+
+    /// Mist coolant ("M" of "[OPT:]")
+    MistCoolant,
+
+    /// Line numbers ("N" of "[OPT:]")
+    LineNumbers,
+
+    /// Single axis homing commands ("H" of "[OPT:]")
+    SingleAxisHoming,
+
+    /// Odometer ("ODO" of "[NEWOPT:]")
+    Odometer,
+
+    /// PID log ("PID" of "[NEWOPT:]")
+    PIDLog,
+}
+
+impl Capability {
+
+    /// Get the readable name of the capability
+    pub fn name(&self) -> &'static str {
+        match self {
+            Capability::LaserMode => "Laser mode",
+            Capability::LatheMode => "Lathe mode",
+            Capability::SpindleSync => "Spindle sync",
+            Capability::MPG => "MPG mode",
+            Capability::SDCard => "Sd card streaming",
+            Capability::AutomaticToolChange => "Automatic tool change",
+            Capability::ManualToolChange => "Manual tool change",
+            Capability::Probing => "Probing",
+            Capability::MistCoolant => "Mist coolant",
+            Capability::LineNumbers => "Line numbers",
+            Capability::SingleAxisHoming => "Single axis homing",
+            Capability::Odometer => "Odometer",
+            Capability::PIDLog => "PID log",
+        }
+    }
+}
+
+/// Features of the device derived from compile options ("[OPT:...]", "[NEWOPT:...]"),
+/// driver options and aux ports of "$I"
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Capabilities {
+    capabilities: Vec<Capability>,
+    axes_count: usize,
+    digital_outputs: u16,
+    analog_outputs: u16,
+    driver_options: Vec<String>,
+}
+
+impl Capabilities {
+
+    /// Collects all capabilities of the firmware info
+    ///
+    /// Capabilities are missing until the compile options are loaded. The axes count defaults
+    /// to the maximum of the firmware dialect if "[OPT:...]" does not report it.
+    ///
+    /// # Examples
+    /// ```
+    /// use grbli::device::response::firmware::{capabilities::{Capabilities, Capability}, compile_option::CompileOptions, FirmwareInfo};
+    ///
+    /// let mut firmware_info = FirmwareInfo::new();
+    /// firmware_info.set_compile_options(Some(CompileOptions::from("[OPT:VNM,35,1024,4,0]").unwrap()));
+    /// let capabilities = Capabilities::from(&firmware_info);
+    /// assert!(capabilities.supports(Capability::MistCoolant));
+    /// assert!(!capabilities.supports(Capability::SDCard));
+    /// assert_eq!(capabilities.axes_count(), 4);
+    /// ```
+    pub fn from(firmware_info: &FirmwareInfo) -> Capabilities {
+        let mut capabilities = Vec::new();
+        let compile_options = firmware_info.compile_options();
+        if let Some(compile_options) = compile_options {
+            let options = compile_options.options();
+            let option_capabilities = [
+                (CompileOption::VariableSpindleEnabled, Capability::LaserMode),
+                (CompileOption::MistCoolantEnabled, Capability::MistCoolant),
+                (CompileOption::LineNumbersEnabled, Capability::LineNumbers),
+                (CompileOption::HomingSingleAxisEnabled, Capability::SingleAxisHoming),
+            ];
+            for (option, capability) in option_capabilities {
+                if options.contains(&option) {
+                    capabilities.push(capability);
+                }
+            }
+        }
+
+        match firmware_info.extended_compile_options() {
+            Some(options) => {
+                let option_capabilities = [
+                    (ExtendedCompileOption::LatheMode, Capability::LatheMode),
+                    (ExtendedCompileOption::SpindelSync, Capability::SpindleSync),
+                    (ExtendedCompileOption::MPGMode, Capability::MPG),
+                    (ExtendedCompileOption::SDCardStreaming, Capability::SDCard),
+                    (ExtendedCompileOption::AutomaticToolChange, Capability::AutomaticToolChange),
+                    (ExtendedCompileOption::ManualToolChange, Capability::ManualToolChange),
+                    (ExtendedCompileOption::Odometer, Capability::Odometer),
+                    (ExtendedCompileOption::PIDLog, Capability::PIDLog),
+                ];
+                for (option, capability) in option_capabilities {
+                    if options.contains(&option) {
+                        capabilities.push(capability);
+                    }
+                }
+                if !options.contains(&ExtendedCompileOption::NoProbeInput) {
+                    capabilities.push(Capability::Probing);
+                }
+            }
+            // classic grbl always has a probe input
+            None if compile_options.is_some() => capabilities.push(Capability::Probing),
+            None => (),
+        }
+
+        let axes_count = compile_options
+            .and_then(|options| options.axes_count())
+            .map(|axes_count| axes_count as usize)
+            .unwrap_or_else(|| firmware_info.dialect().max_axes_count());
+        let aux = firmware_info.board_info().aux();
+        Capabilities {
+            capabilities,
+            axes_count,
+            digital_outputs: aux.map(|aux| aux.digital_out()).unwrap_or_default(),
+            analog_outputs: aux.map(|aux| aux.analog_out()).unwrap_or_default(),
+            driver_options: firmware_info.driver_info().options().cloned().unwrap_or_default(),
+        }
+    }
+
+    /// Indicates if the firmware supports the capability
+    pub fn supports(&self, capability: Capability) -> bool {
+        self.capabilities.contains(&capability)
+    }
+
+    /// Get all supported capabilities
+    pub fn capabilities(&self) -> &[Capability] {
+        self.capabilities.as_ref()
+    }
+
+    /// Get the count of axes
+    pub fn axes_count(&self) -> usize {
+        self.axes_count
+    }
+
+    /// Get the count of digital aux outputs
+    pub fn digital_outputs(&self) -> u16 {
+        self.digital_outputs
+    }
+
+    /// Get the count of analog aux outputs
+    pub fn analog_outputs(&self) -> u16 {
+        self.analog_outputs
+    }
+
+    /// Indicates if the driver reports the option ("[DRIVER OPTIONS:...]")
+    pub fn has_driver_option(&self, option: &str) -> bool {
+        self.driver_options.iter().any(|driver_option| driver_option == option)
+    }
+}
@@ -0,0 +1,67 @@
+use super::{version::FirmwareVersion, welcome::{FirmwareFlavor, WelcomeMessage}};
+
+/// Version prefix of grbl 0.9 e.g. "0.9j"
+const GRBL_0_9_VERSION_PREFIX: &str = "0.";
+
+/// Maximum count of axes supported by classic grbl
+const GRBL_AXES_COUNT: usize = 3;
+
+/// Maximum count of axes supported by grblHAL
+const GRBL_HAL_AXES_COUNT: usize = 6;
+
+/// Protocol variant of the firmware
+///
+/// Classic grbl does not support the extended "$" commands of grblHAL and grbl 0.9 uses
+/// another status report format ("<Idle,MPos:0.000,0.000,0.000,WPos:0.000,0.000,0.000>")
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FirmwareDialect {
+    Grbl0_9,
+    Grbl1_1,
+    GrblHAL,
+}
+
+impl FirmwareDialect {
+
+    /// Detects the dialect from the welcome message
+    ///
+    /// # Examples
+    /// ```
+    /// use grbli::device::response::firmware::{dialect::FirmwareDialect, welcome::WelcomeMessage};
+    ///
+    /// let welcome = WelcomeMessage::from("Grbl 0.9j ['$' for help]").unwrap();
+    /// assert_eq!(FirmwareDialect::from_welcome_message(&welcome), FirmwareDialect::Grbl0_9);
+    /// ```
+    pub fn from_welcome_message(welcome_message: &WelcomeMessage) -> FirmwareDialect {
+        match welcome_message.flavor() {
+            FirmwareFlavor::GrblHAL => FirmwareDialect::GrblHAL,
+            FirmwareFlavor::Grbl if welcome_message.version().starts_with(GRBL_0_9_VERSION_PREFIX) => FirmwareDialect::Grbl0_9,
+            FirmwareFlavor::Grbl => FirmwareDialect::Grbl1_1,
+        }
+    }
+
+    /// Detects the dialect from the "$I" version and the presence of extended compile options ("[NEWOPT:...]")
+    ///
+    /// Extended compile options are only reported by grblHAL
+    pub fn from_version(version: &FirmwareVersion, has_extended_compile_options: bool) -> FirmwareDialect {
+        if version.version().starts_with(GRBL_0_9_VERSION_PREFIX) {
+            FirmwareDialect::Grbl0_9
+        } else if has_extended_compile_options {
+            FirmwareDialect::GrblHAL
+        } else {
+            FirmwareDialect::Grbl1_1
+        }
+    }
+
+    /// Indicates if the firmware reports setting, error and alarm descriptions ("$ES", "$EG", "$EE", "$EA")
+    pub fn has_descriptions(&self) -> bool {
+        *self == FirmwareDialect::GrblHAL
+    }
+
+    /// Get the maximum count of axes supported by the firmware
+    pub fn max_axes_count(&self) -> usize {
+        match self {
+            FirmwareDialect::GrblHAL => GRBL_HAL_AXES_COUNT,
+            _ => GRBL_AXES_COUNT,
+        }
+    }
+}
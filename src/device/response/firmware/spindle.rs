@@ -0,0 +1,94 @@
+use std::result::Result;
+
+const SPINDLE_SEPARATOR: &str = " - ";
+const SPINDLE_NUMBER_PREFIX: &str = "enabled as spindle ";
+const SPINDLE_ACTIVE: &str = "active";
+
+/// Spindle reported by grblHAL "$SPINDLES"
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpindleInfo {
+    id: u32,
+    name: String,
+
+    /// Number used to select the spindle in gcode ($\<number\>)
+    number: Option<u32>,
+    active: bool,
+}
+
+impl SpindleInfo {
+
+    /// Reads a spindle line "\<id\> - \<name\>[, enabled as spindle \<number\>][, active]"
+    ///
+    /// The active spindle without explicit number is spindle 0
+    ///
+    /// # Errors
+    /// Returns an error when parsing fails
+    ///
+    /// # Examples
+    /// ```
+    /// use grbli::device::response::firmware::spindle::SpindleInfo;
+    ///
+    /// let spindle = SpindleInfo::from("1 - Huanyang VFD, enabled as spindle 1").unwrap();
+    /// assert_eq!(spindle.id(), 1);
+    /// assert_eq!(spindle.name(), "Huanyang VFD");
+    /// assert_eq!(spindle.number(), Some(1));
+    /// ```
+    pub fn from(message: &str) -> Result<SpindleInfo, String> {
+        if SpindleInfo::is_response(message) {
+            let (id_string, description) = message.split_once(SPINDLE_SEPARATOR).unwrap();
+            let id = match id_string.parse::<u32>() {
+                Ok(id) => id,
+                Err(_) => return Err(format!("Cannot read spindle id \"{}\"", id_string)),
+            };
+
+            let mut segments = description.split(", ");
+            let name = segments.next().unwrap_or_default().to_string();
+            let mut number: Option<u32> = None;
+            let mut active = false;
+            for segment in segments {
+                if let Some(number_string) = segment.strip_prefix(SPINDLE_NUMBER_PREFIX) {
+                    number = match number_string.parse::<u32>() {
+                        Ok(number) => Some(number),
+                        Err(_) => return Err(format!("Cannot read spindle number \"{}\"", number_string)),
+                    };
+                } else if segment == SPINDLE_ACTIVE {
+                    active = true;
+                }
+            }
+            if active && number.is_none() {
+                number = Some(0);
+            }
+
+            return Ok(SpindleInfo { id, name, number, active });
+        }
+        Err(format!("Cannot read spindle \"{}\"", message))
+    }
+
+    /// Indicates if the message starts with "\<id\> - "
+    pub fn is_response(message: &str) -> bool {
+        match message.split_once(SPINDLE_SEPARATOR) {
+            Some((id, _)) => !id.is_empty() && id.bytes().all(|byte| byte.is_ascii_digit()),
+            None => false,
+        }
+    }
+
+    /// Get the spindle's id
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Get a reference to the spindle's name
+    pub fn name(&self) -> &str {
+        self.name.as_ref()
+    }
+
+    /// Get the spindle's gcode number if enabled
+    pub fn number(&self) -> Option<u32> {
+        self.number
+    }
+
+    /// Indicates if the spindle is active
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+}
@@ -1,6 +1,8 @@
-use std::result::Result;
+use std::{collections::BTreeMap, result::Result};
 
 const STARTUP_PREFIX: &str = ">";
+const STARTUP_LINE_PREFIX: &str = "$N";
+const STARTUP_LINE_SEPARATOR: char = '=';
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum StartupResult {
@@ -76,4 +78,85 @@ impl FirmwareStartupResult {
     pub fn result(&self) -> &StartupResult {
         &self.result
     }
-}
\ No newline at end of file
+}
+
+/// Stored startup line reported by "$N" e.g. "$N0=G54G21"
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StartupLine {
+    index: u32,
+    line: String,
+}
+
+impl StartupLine {
+
+    /// Reads a startup line, an empty line is not set
+    ///
+    /// # Errors
+    /// Returns an error when parsing fails
+    ///
+    /// # Examples
+    /// ```
+    /// use grbli::device::response::firmware::startup::StartupLine;
+    ///
+    /// let startup_line = StartupLine::from("$N1=G54G21").unwrap();
+    /// assert_eq!(startup_line.index(), 1);
+    /// assert_eq!(startup_line.line(), "G54G21");
+    /// assert!(StartupLine::from("$N0=").unwrap().is_empty());
+    /// ```
+    pub fn from(message: &str) -> Result<StartupLine, String> {
+        if StartupLine::is_response(message) {
+            let line_message = message.strip_prefix(STARTUP_LINE_PREFIX).unwrap();
+            let (index_str, line) = line_message.split_once(STARTUP_LINE_SEPARATOR).unwrap();
+            let index = match index_str.parse::<u32>() {
+                Ok(index) => index,
+                Err(_) => return Err(format!("Cannot read startup line index \"{}\"", index_str)),
+            };
+            return Ok(StartupLine { index, line: line.to_string() });
+        }
+        Err(format!("Cannot read startup line \"{}\"", message))
+    }
+
+    /// Indicates if message has startup line syntax "$N\<index\>=\<line\>"
+    pub fn is_response(message: &str) -> bool {
+        message.starts_with(STARTUP_LINE_PREFIX) && message.contains(STARTUP_LINE_SEPARATOR)
+    }
+
+    /// Get the index of the startup line ("$N0", "$N1")
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Get a reference to the stored line
+    pub fn line(&self) -> &str {
+        self.line.as_ref()
+    }
+
+    /// Indicates if no line is stored
+    pub fn is_empty(&self) -> bool {
+        self.line.is_empty()
+    }
+}
+
+/// All startup lines of the last "$N" response
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StartupLines {
+    lines: BTreeMap<u32, StartupLine>,
+}
+
+impl StartupLines {
+
+    /// Adds the line or replaces the line with the same index
+    pub fn put(&mut self, line: StartupLine) {
+        self.lines.insert(line.index, line);
+    }
+
+    /// Get a reference to the startup line with the index
+    pub fn get(&self, index: u32) -> Option<&StartupLine> {
+        self.lines.get(&index)
+    }
+
+    /// Get all startup lines ordered by index
+    pub fn lines(&self) -> Vec<&StartupLine> {
+        self.lines.values().collect()
+    }
+}
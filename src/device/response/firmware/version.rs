@@ -3,6 +3,9 @@ use std::result::Result;
 const VERSION_PREFIX: &str = "[VER:";
 const VERSION_SUFFIX: &str = "]";
 
+/// grbl 0.9 reports the version without name e.g. "[0.9j.20160726:]"
+const LEGACY_VERSION_PREFIX: &str = "[";
+
 /// Stores the version of the device firmware
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct FirmwareVersion {
@@ -21,6 +24,9 @@ impl FirmwareVersion {
     /// ```
     /// // stores version "1.1" and name "grbl"
     /// let response = FirmwareVersion::from("[VER:1.1:grbl]");
+    ///
+    /// // grbl 0.9
+    /// let response = FirmwareVersion::from("[0.9j.20160726:]");
     /// ```
     pub fn from(message: &str) -> Result<FirmwareVersion, String> {
 
@@ -28,7 +34,10 @@ impl FirmwareVersion {
         // and return the unwrapped value
         // "[Version:<value>]"
         if FirmwareVersion::is_response(&message) {
-            let message_payload = message.strip_prefix(VERSION_PREFIX).unwrap().strip_suffix(VERSION_SUFFIX).unwrap();
+            let message_payload = match message.strip_prefix(VERSION_PREFIX) {
+                Some(payload) => payload,
+                None => message.strip_prefix(LEGACY_VERSION_PREFIX).unwrap(),
+            }.strip_suffix(VERSION_SUFFIX).unwrap();
             let version_segements: Vec<&str> = message_payload.split(":").collect();
             
             // expect <version>:<name>
@@ -50,7 +59,11 @@ impl FirmwareVersion {
 
     /// Indicates if message has required version outline
     pub fn is_response(message: &str) -> bool {
-        message.starts_with(VERSION_PREFIX) && message.ends_with(VERSION_SUFFIX)
+        let is_legacy_version = match message.strip_prefix(LEGACY_VERSION_PREFIX) {
+            Some(payload) => payload.starts_with(|character: char| character.is_ascii_digit()),
+            None => false,
+        };
+        (message.starts_with(VERSION_PREFIX) || is_legacy_version) && message.ends_with(VERSION_SUFFIX)
     }
 
     pub fn get_version_slice(message: &str) -> &str  {
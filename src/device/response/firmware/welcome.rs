@@ -0,0 +1,76 @@
+use std::result::Result;
+
+const GRBL_NAME: &str = "Grbl";
+const GRBL_HAL_NAME: &str = "GrblHAL";
+const HELP_PREFIX: &str = "[";
+
+/// Firmware family announced by the welcome message
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FirmwareFlavor {
+    Grbl,
+    GrblHAL,
+}
+
+/// Welcome message sent by the firmware after power up or a soft reset
+/// e.g. "GrblHAL 1.1f ['$' or '$HELP' for help]"
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WelcomeMessage {
+    flavor: FirmwareFlavor,
+    version: String,
+}
+
+impl WelcomeMessage {
+
+    /// Reads firmware flavor and version of the welcome message
+    ///
+    /// # Errors
+    /// Returns an error if the message is not a welcome message
+    ///
+    /// # Examples
+    /// ```
+    /// use grbli::device::response::firmware::welcome::{FirmwareFlavor, WelcomeMessage};
+    ///
+    /// let welcome = WelcomeMessage::from("Grbl 1.1f ['$' for help]").unwrap();
+    /// assert_eq!(*welcome.flavor(), FirmwareFlavor::Grbl);
+    /// assert_eq!(welcome.version(), "1.1f");
+    ///
+    /// let welcome = WelcomeMessage::from("GrblHAL 1.1f ['$' or '$HELP' for help]").unwrap();
+    /// assert_eq!(*welcome.flavor(), FirmwareFlavor::GrblHAL);
+    /// ```
+    pub fn from(message: &str) -> Result<WelcomeMessage, String> {
+        if WelcomeMessage::is_response(message) {
+            let mut segments = message.split_whitespace();
+            let flavor = match segments.next() {
+                Some(GRBL_NAME) => FirmwareFlavor::Grbl,
+                Some(GRBL_HAL_NAME) => FirmwareFlavor::GrblHAL,
+                _ => return Err(format!("Unknown firmware in welcome message \"{}\"", message)),
+            };
+            let version = match segments.next() {
+                Some(version) if !version.starts_with(HELP_PREFIX) => version.to_string(),
+                _ => return Err(format!("Welcome message without version \"{}\"", message)),
+            };
+            return Ok(WelcomeMessage { flavor, version });
+        }
+        Err(format!("Cannot read welcome message \"{}\"", message))
+    }
+
+    /// Indicates if message starts with the firmware name followed by a version
+    pub fn is_response(message: &str) -> bool {
+        [GRBL_NAME, GRBL_HAL_NAME].iter().any(|name| {
+            match message.strip_prefix(name) {
+                Some(rest) => rest.starts_with(' ') && rest.trim_start().starts_with(|character: char| character.is_ascii_digit()),
+                None => false,
+            }
+        })
+    }
+
+    /// Get a reference to the firmware flavor
+    pub fn flavor(&self) -> &FirmwareFlavor {
+        &self.flavor
+    }
+
+    /// Get a reference to the version e.g. "1.1f"
+    pub fn version(&self) -> &str {
+        self.version.as_ref()
+    }
+}
@@ -1,12 +1,15 @@
-use std::result::Result;
+use std::{collections::BTreeMap, result::Result};
 
-use crate::device::util::axis::Axis;
+use crate::device::util::{axis::Axis, signals::SignalSet};
 
-use super::state::{machine::{state::MachineState, position::{MachinePosition, is_local_position_offset, parse_local_position_offset, is_coordinate_system, parse_coordinate_system, is_scaled_axes, parse_scaled_axes, is_local_position, is_global_position, parse_local_position, parse_global_position}, speed::MachineSpeed}, buffer::BufferState, signal::{MachineSignal, is_machine_signal, parse_machine_signal}, overrides::Overrides, accessory::{AccessoryState, is_accessory_state, parse_accessory_state}, pendant::{PendantControl, is_pendant_control, parse_pendant_control}, homing::HomingState, modes::{ArcMode, is_arc_mode, parse_arc_mode}, gcode::{is_line_number, parse_line_number}, tool::{is_tool_length_reference, parse_tool_length_reference}, firmware::{is_firmware, parse_firmware}, input::{is_input_wait_result, parse_input_wait_result}};
+use super::state::{machine::{state::MachineState, position::{Position, is_local_position_offset, parse_local_position_offset, is_coordinate_system, parse_coordinate_system, is_scaled_axes, parse_scaled_axes, is_local_position, is_global_position, parse_local_position, parse_global_position}, speed::MachineSpeed}, buffer::BufferState, signal::{MachineSignal, is_machine_signal, parse_machine_signal}, overrides::Overrides, accessory::{AccessoryState, is_accessory_state, parse_accessory_state}, pendant::{PendantControl, is_pendant_control, parse_pendant_control}, homing::HomingState, modes::{ArcMode, is_arc_mode, parse_arc_mode}, gcode::{is_line_number, parse_line_number}, tool::{is_tool_length_reference, parse_tool_length_reference, is_current_tool, parse_current_tool}, sdcard::SdCardProgress, thc::{ThcState, is_thc_state, parse_thc_state}, firmware::{is_firmware, parse_firmware}, input::{is_input_wait_result, parse_input_wait_result}};
 
 const REPORT_PREFIX: &str = "<";
 const REPORT_SUFFIX: &str = ">";
 
+/// grbl 0.9 separates fields and values by "," e.g. "<Idle,MPos:0.000,0.000,0.000,WPos:0.000,0.000,0.000>"
+const LEGACY_REPORT_SEPARATOR: char = ',';
+
 /// Reponse for report message
 #[derive(Clone, Debug, PartialEq)]
 pub struct MachineInfo {
@@ -14,13 +17,13 @@ pub struct MachineInfo {
     machine_state: MachineState,
 
     /// MPos: Absolute position in machine workspace
-    global_position: Option<MachinePosition>,
+    global_position: Option<Position>,
 
     /// WPos: Relative position to machine offset
-    local_position: Option<MachinePosition>,
+    local_position: Option<Position>,
 
     /// WCO: Relative position offset
-    local_offset: Option<MachinePosition>,
+    local_offset: Option<Position>,
 
     buffer_state: Option<BufferState>,
     line_number: Option<i32>,
@@ -36,6 +39,12 @@ pub struct MachineInfo {
     firmware: Option<String>,
     input_wait_result_succeeded: Option<bool>,
     arc_mode: Option<ArcMode>,
+    current_tool: Option<u32>,
+    sd_card_progress: Option<SdCardProgress>,
+    thc_state: Option<ThcState>,
+
+    /// Fields without parser by name e.g. "XY:1,2" => ("XY", "1,2")
+    unknown_fields: BTreeMap<String, String>,
 }
 
 impl MachineInfo {
@@ -47,6 +56,15 @@ impl MachineInfo {
     /// // stores all status messages from this message
     /// let response = MachineInfo::from("<Status:0|State2|...|StateN>");
     /// ```
+    ///
+    /// Reports of grbl 0.9 are read as well, fields without equivalent are kept as unknown fields
+    /// ```
+    /// use grbli::device::{response::report::MachineInfo, util::axis::Axis};
+    ///
+    /// let report = MachineInfo::from("<Idle,MPos:5.000,0.000,0.000,WPos:1.000,0.000,0.000,Buf:0,RX:0>").unwrap();
+    /// assert_eq!(report.global_position().unwrap().get(&Axis::X), Some(5.0));
+    /// assert_eq!(report.local_position().unwrap().get(&Axis::X), Some(1.0));
+    /// ```
     pub fn from(message: &str) -> Result<MachineInfo, String> {
         if MachineInfo::is_response(message) {
             let report_message = message
@@ -54,12 +72,65 @@ impl MachineInfo {
                 .unwrap()
                 .strip_suffix(REPORT_SUFFIX)
                 .unwrap();
+            if MachineInfo::is_legacy_report(report_message) {
+                return MachineInfo::parse_legacy_report(report_message);
+            }
             let report_states: Vec<&str> = report_message.split("|").collect();
             return MachineInfo::parse_report_states(report_states);
         }
         Err(format!("Cannot read report response \"{}\"", message))
     }
 
+    /// Indicates if the report has grbl 0.9 format, its state is followed by "," instead of "|" or ":"
+    fn is_legacy_report(report_message: &str) -> bool {
+        match report_message.split(['|', ':']).next() {
+            Some(state) => state.contains(LEGACY_REPORT_SEPARATOR),
+            None => false,
+        }
+    }
+
+    /// Reads a grbl 0.9 report which may contain both positions
+    fn parse_legacy_report(report_message: &str) -> Result<MachineInfo, String> {
+        // values without name belong to the previous field e.g. "MPos:0.000,0.000,0.000"
+        let mut states: Vec<String> = Vec::new();
+        for segment in report_message.split(LEGACY_REPORT_SEPARATOR) {
+            match states.last_mut() {
+                Some(state) if !segment.contains(':') => {
+                    state.push(LEGACY_REPORT_SEPARATOR);
+                    state.push_str(segment);
+                }
+                _ => states.push(segment.to_string()),
+            }
+        }
+
+        // the first position is mandatory, a second WPos is added after parsing
+        let position_index = match states.iter().position(|state| is_global_position(state) || is_local_position(state)) {
+            Some(index) => index,
+            None => return Err(MachineInfo::format_state_error(String::from("Report should contain a local or global/machine position"))),
+        };
+        let mut report_states: Vec<&str> = vec![states[0].as_str(), states[position_index].as_str()];
+        let mut local_position_state = None;
+        for (index, state) in states.iter().enumerate().skip(1) {
+            if index == position_index {
+                continue;
+            }
+            if is_local_position(state) {
+                local_position_state = Some(state.as_str());
+            } else {
+                report_states.push(state.as_str());
+            }
+        }
+
+        let mut report = MachineInfo::parse_report_states(report_states)?;
+        if let Some(local_position_state) = local_position_state {
+            report.local_position = match parse_local_position(local_position_state) {
+                Ok(position) => Some(position),
+                Err(error) => return Err(MachineInfo::format_state_error(error)),
+            };
+        }
+        Ok(report)
+    }
+
     pub fn is_response(message: &str) -> bool {
         message.starts_with(REPORT_PREFIX) && message.ends_with(REPORT_SUFFIX)
     }
@@ -72,7 +143,8 @@ impl MachineInfo {
         };
 
         // fill empty slots in report with remaining state options
-        for state in states {
+        // machine state and position are already read
+        for state in states.into_iter().skip(2) {
             if BufferState::is_buffer_state(state) {
                 report.buffer_state = match BufferState::from(state) {
                     Ok(state) => Some(state),
@@ -148,6 +220,25 @@ impl MachineInfo {
                     Ok(state) => Some(state),
                     Err(error) => return Err(MachineInfo::format_state_error(error)),
                 }
+            } else if is_current_tool(state) {
+                report.current_tool = match parse_current_tool(state) {
+                    Ok(state) => Some(state),
+                    Err(error) => return Err(MachineInfo::format_state_error(error)),
+                }
+            } else if SdCardProgress::is_sd_card_progress(state) {
+                report.sd_card_progress = match SdCardProgress::from(state) {
+                    Ok(state) => Some(state),
+                    Err(error) => return Err(MachineInfo::format_state_error(error)),
+                }
+            } else if is_thc_state(state) {
+                report.thc_state = match parse_thc_state(state) {
+                    Ok(state) => Some(state),
+                    Err(error) => return Err(MachineInfo::format_state_error(error)),
+                }
+            } else {
+                // keep fields of newer firmwares
+                let (name, value) = state.split_once(':').unwrap_or((state, ""));
+                report.unknown_fields.insert(name.to_string(), value.to_string());
             }
         }
 
@@ -167,8 +258,8 @@ impl MachineInfo {
             Err(err) => return Err(MachineInfo::format_state_error(err)),
         };
 
-        let mut local_position: Option<MachinePosition> = None;
-        let mut global_position: Option<MachinePosition> = None;
+        let mut local_position: Option<Position> = None;
+        let mut global_position: Option<Position> = None;
         let machine_position_string = states[1];
 
         if !is_local_position(machine_position_string)
@@ -212,6 +303,10 @@ impl MachineInfo {
             firmware: None,
             input_wait_result_succeeded: None,
             arc_mode: None,
+            current_tool: None,
+            sd_card_progress: None,
+            thc_state: None,
+            unknown_fields: BTreeMap::new(),
         })
     }
 
@@ -229,32 +324,80 @@ impl MachineInfo {
     }
 
     /// Get a reference to the report response's global position.
-    pub fn global_position(&self) -> Option<&MachinePosition> {
+    pub fn global_position(&self) -> Option<&Position> {
         self.global_position.as_ref()
     }
 
-    pub fn set_global_position(&mut self, global_position: MachinePosition) {
+    pub fn set_global_position(&mut self, global_position: Position) {
         self.global_position = Some(global_position)
     }
 
     /// Get a reference to the report response's local position.
-    pub fn local_position(&self) -> Option<&MachinePosition> {
+    pub fn local_position(&self) -> Option<&Position> {
         self.local_position.as_ref()
     }
 
-    pub fn set_local_position(&mut self, local_position: MachinePosition) {
+    pub fn set_local_position(&mut self, local_position: Position) {
         self.local_position = Some(local_position)
     }
 
     /// Get a reference to the report response's local offset.
-    pub fn local_offset(&self) -> Option<&MachinePosition> {
+    pub fn local_offset(&self) -> Option<&Position> {
         self.local_offset.as_ref()
     }
 
-    pub fn set_local_offset(&mut self, local_offset: MachinePosition) {
+    pub fn set_local_offset(&mut self, local_offset: Position) {
         self.local_offset = Some(local_offset)
     }
 
+    /// Get the work position as shown by a lathe DRO, X is a diameter while diameter mode (G7, "D:1") is active
+    pub fn display_local_position(&self) -> Option<Position> {
+        self.local_position.as_ref().map(|position| self.get_display_position(position))
+    }
+
+    /// Get the machine position as shown by a lathe DRO, X is a diameter while diameter mode (G7, "D:1") is active
+    pub fn display_global_position(&self) -> Option<Position> {
+        self.global_position.as_ref().map(|position| self.get_display_position(position))
+    }
+
+    fn get_display_position(&self, position: &Position) -> Position {
+        match self.arc_mode {
+            Some(ArcMode::Diameter) => position.to_diameter(),
+            _ => position.clone(),
+        }
+    }
+
+    /// Replaces both position frames, a missing frame is removed
+    pub fn set_positions(&mut self, global_position: Option<Position>, local_position: Option<Position>) {
+        self.global_position = global_position;
+        self.local_position = local_position;
+    }
+
+    /// Calculates the position frame missing in the report
+    ///
+    /// grbl reports either MPos or WPos ($10) and WCO only in some reports.
+    /// The missing frame is calculated with the reported or the given cached offset
+    /// (WPos = MPos - WCO) and stays empty if no offset is known.
+    pub fn derive_positions(&mut self, cached_offset: Option<&Position>) {
+        let offset = match self.local_offset.as_ref().or(cached_offset) {
+            Some(offset) => offset.clone(),
+            None => return,
+        };
+
+        if let (Some(global_position), None) = (&self.global_position, &self.local_position) {
+            self.local_position = Some(global_position.without_offset(&offset));
+        } else if let (None, Some(local_position)) = (&self.global_position, &self.local_position) {
+            self.global_position = Some(local_position.with_offset(&offset));
+        }
+    }
+
+    /// Multiplies the linear axes of all positions and the offset by the factor e.g. to convert inch to mm
+    pub fn scale_positions(&mut self, factor: f32) {
+        self.global_position = self.global_position.as_ref().map(|position| position.scaled(factor));
+        self.local_position = self.local_position.as_ref().map(|position| position.scaled(factor));
+        self.local_offset = self.local_offset.as_ref().map(|position| position.scaled(factor));
+    }
+
     /// Get a reference to the report response's buffer state.
     pub fn buffer_state(&self) -> Option<&BufferState> {
         self.buffer_state.as_ref()
@@ -291,6 +434,11 @@ impl MachineInfo {
         self.machine_signals = Some(machine_signals)
     }
 
+    /// Get the report response's machine signals as set
+    pub fn signal_set(&self) -> Option<SignalSet> {
+        self.machine_signals.as_ref().map(|signals| SignalSet::from_signals(signals))
+    }
+
     /// Get a reference to the report response's machine coordinate system.
     pub fn machine_coordinate_system(&self) -> Option<&String> {
         self.machine_coordinate_system.as_ref()
@@ -380,4 +528,41 @@ impl MachineInfo {
     pub fn set_arc_mode(&mut self, arc_mode: ArcMode) {
         self.arc_mode = Some(arc_mode)
     }
+
+    /// Get the report response's current tool number.
+    pub fn current_tool(&self) -> Option<u32> {
+        self.current_tool
+    }
+
+    pub fn set_current_tool(&mut self, current_tool: u32) {
+        self.current_tool = Some(current_tool)
+    }
+
+    /// Get a reference to the report response's sd card streaming progress.
+    pub fn sd_card_progress(&self) -> Option<&SdCardProgress> {
+        self.sd_card_progress.as_ref()
+    }
+
+    pub fn set_sd_card_progress(&mut self, sd_card_progress: Option<SdCardProgress>) {
+        self.sd_card_progress = sd_card_progress
+    }
+
+    /// Get a reference to the report response's torch height control state.
+    pub fn thc_state(&self) -> Option<&ThcState> {
+        self.thc_state.as_ref()
+    }
+
+    pub fn set_thc_state(&mut self, thc_state: ThcState) {
+        self.thc_state = Some(thc_state)
+    }
+
+    /// Get a reference to the report response's fields without parser.
+    pub fn unknown_fields(&self) -> &BTreeMap<String, String> {
+        &self.unknown_fields
+    }
+
+    /// Replaces all fields without parser
+    pub fn set_unknown_fields(&mut self, unknown_fields: BTreeMap<String, String>) {
+        self.unknown_fields = unknown_fields;
+    }
 }
@@ -2,6 +2,8 @@ use std::{collections::BTreeMap, fmt};
 
 use self::{group::DeviceSettingGroup, description::DeviceSettingDescription};
 
+use super::defaults::DEFAULT_SETTING_DESCRIPTIONS;
+
 pub mod description;
 pub mod group;
 
@@ -97,10 +99,14 @@ impl fmt::Debug for DeviceSetting {
 
 impl DeviceSettings {
 
+    /// Creates the settings with the built-in descriptions of the core settings, reported descriptions replace them
     pub fn new() -> Self {
         DeviceSettings {
             settings: BTreeMap::new(),
-            setting_descriptions: BTreeMap::new(),
+            setting_descriptions: DEFAULT_SETTING_DESCRIPTIONS
+                .iter()
+                .map(|(index, description, unit, value_type)| (*index, DeviceSettingDescription::new(*index, description, *unit, *value_type)))
+                .collect(),
             setting_groups: BTreeMap::new(),
         }
     }
@@ -125,6 +131,11 @@ impl DeviceSettings {
         self.settings.get(index)
     }
 
+    /// Get value for specific setting as float if present and numeric
+    pub fn get_setting_f32(&self, index: &u32) -> Option<f32> {
+        self.settings.get(index).and_then(|setting| setting.value().parse::<f32>().ok())
+    }
+
     /// Get the group by index if present
     pub fn get_setting_group(&self, index: &u32) -> Option<&DeviceSettingGroup> {
         self.setting_groups.get(index)
@@ -20,6 +20,20 @@ pub struct DeviceSettingDescription {
 
 impl DeviceSettingDescription {
 
+    /// Creates a description without group, format and limits
+    pub fn new(index: u32, description: &str, unit: Option<&str>, value_type: u8) -> Self {
+        DeviceSettingDescription {
+            index,
+            group_index: 0,
+            description: Some(description.to_string()),
+            unit: unit.map(|unit| unit.to_string()),
+            value_type,
+            value_format: None,
+            value_min: None,
+            value_max: None,
+        }
+    }
+
     /// Reads a single setting description line and retruns its parsed content
     ///
     /// Some fields are not required (see the actual struct)
@@ -4,6 +4,8 @@ const LINE_NUMBER_PREFIX: &str = "Ln:";
 
 /// Returns the line number from message "Ln:\<number\>"
 /// 
+/// Values appended by some firmwares e.g. "Ln:32,1" are ignored
+/// 
 /// # Examples
 /// ```
 /// let line_number = parse_line_number("Ln:32");
@@ -11,7 +13,8 @@ const LINE_NUMBER_PREFIX: &str = "Ln:";
 /// ```
 pub fn parse_line_number(message: &str) -> Result<i32, String> {
     if is_line_number(message) {
-        let line_number_str = &message[LINE_NUMBER_PREFIX.len()..];
+        let line_number_values = &message[LINE_NUMBER_PREFIX.len()..];
+        let line_number_str = line_number_values.split(',').next().unwrap_or_default().trim();
         return match line_number_str.parse() {
             Ok(line_number) => Ok(line_number),
             Err(_) => Err(format!("Cannot read line number \"{}\"", line_number_str))
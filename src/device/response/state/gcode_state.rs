@@ -2,6 +2,7 @@ use std::result::Result;
 
 const GCODE_PREFIX: &str = "[GC:";
 const GCODE_SUFFIX: &str = "]";
+const TOOL_PREFIX: &str = "T";
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct GCodeState {
@@ -47,4 +48,9 @@ impl GCodeState {
     pub fn values(&self) -> &Vec<String> {
         &self.values
     }
+
+    /// Returns the selected tool number "T\<number\>"
+    pub fn tool(&self) -> Option<u32> {
+        self.values.iter().find_map(|value| value.strip_prefix(TOOL_PREFIX)?.parse::<u32>().ok())
+    }
 }
\ No newline at end of file
@@ -1,5 +1,7 @@
 use crate::device::util::axis::{Axis, get_axes_from_mask, get_all_grbl_axes};
 
+use super::machine::position::Position;
+
 const HOMING_STATE_PREFIX: &str = "H:";
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -75,3 +77,26 @@ impl HomingState {
     }
 }
 
+/// Outcome of a completed homing cycle
+#[derive(Clone, Debug, PartialEq)]
+pub struct HomingResult {
+    homed_axes: Vec<Axis>,
+    machine_position: Position,
+}
+
+impl HomingResult {
+
+    pub fn new(homed_axes: Vec<Axis>, machine_position: Position) -> Self {
+        HomingResult { homed_axes, machine_position }
+    }
+
+    /// Get a reference to all axes reported as homed
+    pub fn homed_axes(&self) -> &[Axis] {
+        self.homed_axes.as_ref()
+    }
+
+    /// Get a reference to the machine position after homing
+    pub fn machine_position(&self) -> &Position {
+        &self.machine_position
+    }
+}
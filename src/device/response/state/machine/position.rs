@@ -1,4 +1,4 @@
-use crate::device::util::axis::{MAX_AXES, MIN_AXES, Axis, get_axis};
+use crate::device::util::axis::{MAX_AXES, MIN_AXES, Axis, get_axis, get_axis_index, get_axis_by_index, is_linear_axis};
 
 const POSITION_LOCAL_PREFIX: &str = "WPos:";
 const POSITION_LOCAL_OFFSET_PREFIX: &str = "WCO:";
@@ -6,7 +6,88 @@ const POSITION_GLOBAL_PREFIX: &str = "MPos:";
 const COORDINATE_SYSTEM_PREFIX: &str = "WCS:";
 const SCALED_AXES_PREFIX: &str = "Sc:";
 
-pub type MachinePosition = Vec<f32>;
+/// Position with a value for each reported axis
+///
+/// Values are stored in axis order (X,Y,Z,A,B,C) as reported by grbl
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Position {
+    values: Vec<f32>,
+}
+
+impl Position {
+
+    /// Creates a position from values in axis order (X,Y,Z,A,B,C)
+    ///
+    /// Values exceeding the maximum count of axes are ignored
+    pub fn from_values(values: &[f32]) -> Self {
+        Position { values: values.iter().take(MAX_AXES).copied().collect() }
+    }
+
+    /// Get the axis value or None if the axis is not reported
+    pub fn get(&self, axis: &Axis) -> Option<f32> {
+        self.values.get(get_axis_index(axis)).copied()
+    }
+
+    /// Sets the axis value, missing lower axes are set to 0
+    pub fn set(&mut self, axis: &Axis, value: f32) {
+        let axis_index = get_axis_index(axis);
+        if self.values.len() <= axis_index {
+            self.values.resize(axis_index + 1, 0.0);
+        }
+        self.values[axis_index] = value;
+    }
+
+    /// Get all reported axes
+    pub fn axes(&self) -> Vec<Axis> {
+        (0..self.values.len()).map(|axis_index| get_axis_by_index(axis_index).unwrap()).collect()
+    }
+
+    /// Get all values in axis order
+    pub fn values(&self) -> &[f32] {
+        self.values.as_ref()
+    }
+
+    /// Iterates all reported axes with their value
+    pub fn iter(&self) -> impl Iterator<Item = (Axis, f32)> + '_ {
+        self.values.iter().enumerate().map(|(axis_index, value)| (get_axis_by_index(axis_index).unwrap(), *value))
+    }
+
+    /// Get the count of reported axes
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Indicates if no axis is reported
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the position shifted by the offset e.g. MPos = WPos + WCO
+    ///
+    /// Axes missing in the offset are not shifted
+    pub fn with_offset(&self, offset: &Position) -> Position {
+        Position { values: self.values.iter().enumerate().map(|(axis_index, value)| value + offset.values.get(axis_index).copied().unwrap_or(0.0)).collect() }
+    }
+
+    /// Returns the position shifted back by the offset e.g. WPos = MPos - WCO
+    ///
+    /// Axes missing in the offset are not shifted
+    pub fn without_offset(&self, offset: &Position) -> Position {
+        Position { values: self.values.iter().enumerate().map(|(axis_index, value)| value - offset.values.get(axis_index).copied().unwrap_or(0.0)).collect() }
+    }
+
+    /// Returns the position with the linear axes multiplied by the factor e.g. to convert inch to mm
+    ///
+    /// Rotary axes (A, B, C) are reported in degrees and keep their values
+    pub fn scaled(&self, factor: f32) -> Position {
+        Position { values: self.iter().map(|(axis, value)| if is_linear_axis(&axis) { value * factor } else { value }).collect() }
+    }
+
+    /// Returns the position with X as lathe diameter (doubled radius)
+    pub fn to_diameter(&self) -> Position {
+        Position { values: self.values.iter().enumerate().map(|(axis_index, value)| if axis_index == 0 { value * 2.0 } else { *value }).collect() }
+    }
+}
 
 /// Creates position from "\<float\>,\<float\>,...."
 /// 
@@ -16,9 +97,9 @@ pub type MachinePosition = Vec<f32>;
 /// let position_4d = parse_position("3.21,2.0,-1,0.0")
 /// let position_5d = parse_position("3.21,2.0,-1,0.0,15")
 /// ```
-pub fn parse_position(position: &str) -> Result<MachinePosition, String> {
+pub fn parse_position(position: &str) -> Result<Position, String> {
     let axis_strings: Vec<&str> = position.split(",").collect();
-    let mut axis_values: MachinePosition = Vec::new();
+    let mut axis_values: Vec<f32> = Vec::new();
     if axis_strings.len() >= MIN_AXES && axis_strings.len() <= MAX_AXES {
         for (axis_index, axis_string) in axis_strings.iter().enumerate() {
             axis_values.push(match axis_string.parse() {
@@ -26,7 +107,7 @@ pub fn parse_position(position: &str) -> Result<MachinePosition, String> {
                 Err(_) => return Err(format!("Cannot read axis:{} \"{}\"", axis_index, axis_string))
             })
         }
-        return Ok(axis_values);
+        return Ok(Position { values: axis_values });
     }
     Err(format!("Invalid count of axis in \"{}\" ({} <= x <= {})", position, MIN_AXES, MAX_AXES))
 }
@@ -37,7 +118,7 @@ pub fn parse_position(position: &str) -> Result<MachinePosition, String> {
 /// ```
 /// let position = parse_local_position("WPos:3.21,2.0,-1")
 /// ```
-pub fn parse_local_position(position: &str) -> Result<MachinePosition, String> {
+pub fn parse_local_position(position: &str) -> Result<Position, String> {
     if is_local_position(position) {
         return parse_position(&position[POSITION_LOCAL_PREFIX.len()..]);
     }
@@ -57,7 +138,7 @@ pub fn is_local_position(position: &str) -> bool {
 /// ```
 /// let position = parse_global_position("MPos:3.21,2.0,-1")
 /// ```
-pub fn parse_global_position(position: &str) -> Result<MachinePosition, String> {
+pub fn parse_global_position(position: &str) -> Result<Position, String> {
     if is_global_position(position) {
         return parse_position(&position[POSITION_GLOBAL_PREFIX.len()..]);
     }
@@ -75,7 +156,7 @@ pub fn is_global_position(position: &str) -> bool {
 /// ```
 /// let position = parse_local_position_offset("WCO:3.21,2.0,-1")
 /// ```
-pub fn parse_local_position_offset(position: &str) -> Result<MachinePosition, String> {
+pub fn parse_local_position_offset(position: &str) -> Result<Position, String> {
     if is_local_position_offset(position) {
         return parse_position(&position[POSITION_LOCAL_OFFSET_PREFIX.len()..]);
     }
@@ -1,39 +1,60 @@
 
 const MACHINE_SPEED_PREFIX: &str = "FS:";
+const FEED_RATE_PREFIX: &str = "F:";
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct MachineSpeed {
-    feed_rate: i32,
-    spindle_programmed_rpm: i32,
-    spindle_actual_rpm: Option<i32>
+    feed_rate: f32,
+    spindle_programmed_rpm: Option<f32>,
+    spindle_actual_rpm: Option<f32>
 }
 
 
 impl MachineSpeed {
     
     /// Creates machine speed from "FS:\<feed rate\>,\<spindle programmed rpm\>,\<spindle actual rpm\>"
+    /// or "F:\<feed rate\>" if grbl is compiled without variable spindle
     /// 
     /// # Examples
     /// ```
-    /// let machine_speed = MachineSpeed::from("FS:100,3000,1677")
+    /// use grbli::device::response::state::machine::speed::MachineSpeed;
+    ///
+    /// let machine_speed = MachineSpeed::from("FS:1250.5,3000,1677").unwrap();
+    /// assert_eq!(machine_speed.feed_rate(), 1250.5);
+    /// assert_eq!(machine_speed.spindle_actual_rpm(), Some(1677.0));
+    ///
+    /// let machine_speed = MachineSpeed::from("F:500").unwrap();
+    /// assert_eq!(machine_speed.spindle_programmed_rpm(), None);
     /// ```
     pub fn from(message: &str) -> Result<MachineSpeed, String> {
-        if MachineSpeed::is_machine_speed(message) {
-            let values: Vec<&str> = (&message[MACHINE_SPEED_PREFIX.len()..]).split(",").collect();
+        if let Some(feed_rate_str) = message.strip_prefix(FEED_RATE_PREFIX) {
+            let feed_rate: f32 = match feed_rate_str.parse() {
+                Ok(value) => value,
+                Err(_) => return Err(format!("Cannot read feed rate \"{}\"", feed_rate_str))
+            };
+            return Ok(MachineSpeed {
+                feed_rate,
+                spindle_programmed_rpm: None,
+                spindle_actual_rpm: None,
+            })
+        }
+
+        if let Some(values_str) = message.strip_prefix(MACHINE_SPEED_PREFIX) {
+            let values: Vec<&str> = values_str.split(",").collect();
             if values.len() < 2 || values.len() > 3 {
                 return Err(format!("Invalid count of machine speed values \"{}\"", message))
             }
 
-            let feed_rate: i32 = match values[0].parse() {
+            let feed_rate: f32 = match values[0].parse() {
                 Ok(value) => value,
                 Err(_) => return Err(format!("Cannot read feed rate \"{}\"", values[0]))
             };
-            let spindle_programmed_rpm: i32 = match values[1].parse() {
+            let spindle_programmed_rpm: f32 = match values[1].parse() {
                 Ok(value) => value,
                 Err(_) => return Err(format!("Cannot read spindle programmed rpm \"{}\"", values[1]))
             };
 
-            let mut spindle_actual_rpm: Option<i32> = None;
+            let mut spindle_actual_rpm: Option<f32> = None;
             if values.len() == 3 {
                 spindle_actual_rpm = match values[2].parse() {
                     Ok(value) => Some(value),
@@ -43,30 +64,32 @@ impl MachineSpeed {
 
             return Ok(MachineSpeed {
                 feed_rate,
-                spindle_programmed_rpm,
+                spindle_programmed_rpm: Some(spindle_programmed_rpm),
                 spindle_actual_rpm,
             })
         }
         Err(format!("Cannot read machine speed \"{}\"", message))
     }
 
-    /// Indicates if message starts with "FS:"
+    /// Indicates if message starts with "FS:" or "F:"
     pub fn is_machine_speed(message: &str) -> bool {
-        message.starts_with(MACHINE_SPEED_PREFIX)
+        message.starts_with(MACHINE_SPEED_PREFIX) || message.starts_with(FEED_RATE_PREFIX)
     }
 
     /// Get a reference to the machine speed's feed rate.
-    pub fn feed_rate(&self) -> i32 {
+    pub fn feed_rate(&self) -> f32 {
         self.feed_rate
     }
 
     /// Get a reference to the machine speed's spindle programmed rpm.
-    pub fn spindle_programmed_rpm(&self) -> i32 {
+    /// 
+    /// Is not reported without variable spindle ("F:")
+    pub fn spindle_programmed_rpm(&self) -> Option<f32> {
         self.spindle_programmed_rpm
     }
 
     /// Get a reference to the machine speed's spindle actual rpm.
-    pub fn spindle_actual_rpm(&self) -> Option<i32> {
+    pub fn spindle_actual_rpm(&self) -> Option<f32> {
         self.spindle_actual_rpm
     }
-}
\ No newline at end of file
+}
@@ -11,4 +11,11 @@ pub mod gcode;
 pub mod input;
 pub mod firmware;
 pub mod tool;
-pub mod compile;
\ No newline at end of file
+pub mod compile;
+pub mod probe;
+pub mod sdcard;
+pub mod thc;
+pub mod ngc_parameters;
+pub mod tool_table;
+pub mod odometer;
+pub mod pid_log;
\ No newline at end of file
@@ -0,0 +1,165 @@
+use std::{collections::BTreeMap, result::Result};
+
+use crate::device::util::{axis::Axis, coordinate::{get_coordinate_system, get_coordinate_system_index, CoordinateSystem}};
+
+use super::machine::position::{parse_position, Position};
+
+const PARAMETER_PREFIX: &str = "[";
+const PARAMETER_SUFFIX: &str = "]";
+const HOME_POSITION_NAME: &str = "G28";
+const SECONDARY_HOME_POSITION_NAME: &str = "G30";
+const COORDINATE_OFFSET_NAME: &str = "G92";
+const TOOL_LENGTH_OFFSET_NAME: &str = "TLO";
+
+/// Positions and offsets reported by "$#"
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NgcParameterName {
+    /// Offset of a work coordinate system (G54 - G59.3)
+    CoordinateSystem(CoordinateSystem),
+
+    /// Stored position of G28
+    HomePosition,
+
+    /// Stored position of G30
+    SecondaryHomePosition,
+
+    /// Coordinate offset of G92
+    CoordinateOffset,
+
+    /// Tool length offset of G43.1
+    ToolLengthOffset,
+}
+
+/// Single line of the "$#" response
+#[derive(Clone, Debug, PartialEq)]
+pub struct NgcParameter {
+    name: NgcParameterName,
+    position: Position,
+}
+
+impl NgcParameter {
+
+    /// Reads a parameter line "[\<name\>:\<x\>,\<y\>,\<z\>,...]"
+    ///
+    /// A tool length offset with a single value ("[TLO:\<z\>]") is stored as Z
+    ///
+    /// # Errors
+    /// Returns an error when parsing fails
+    ///
+    /// # Examples
+    /// ```
+    /// use grbli::device::{response::state::ngc_parameters::{NgcParameter, NgcParameterName}, util::{axis::Axis, coordinate::CoordinateSystem}};
+    ///
+    /// let parameter = NgcParameter::from("[G55:10.000,-5.000,0.000]").unwrap();
+    /// assert_eq!(parameter.name(), &NgcParameterName::CoordinateSystem(CoordinateSystem::G55));
+    /// assert_eq!(parameter.position().get(&Axis::X), Some(10.0));
+    ///
+    /// let parameter = NgcParameter::from("[TLO:1.500]").unwrap();
+    /// assert_eq!(parameter.position().get(&Axis::Z), Some(1.5));
+    /// ```
+    pub fn from(message: &str) -> Result<NgcParameter, String> {
+        if NgcParameter::is_response(message) {
+            let parameter_message = message.strip_prefix(PARAMETER_PREFIX).unwrap().strip_suffix(PARAMETER_SUFFIX).unwrap();
+            let (name_str, position_str) = parameter_message.split_once(':').unwrap();
+            let name = get_ngc_parameter_name(name_str).unwrap();
+
+            let position = match (name, position_str.parse::<f32>()) {
+                (NgcParameterName::ToolLengthOffset, Ok(offset)) => {
+                    let mut position = Position::from_values(&[]);
+                    position.set(&Axis::Z, offset);
+                    position
+                }
+                _ => match parse_position(position_str) {
+                    Ok(position) => position,
+                    Err(err) => return Err(format!("Cannot read parameter \"{}\": {}", name_str, err)),
+                },
+            };
+            return Ok(NgcParameter { name, position });
+        }
+        Err(format!("Cannot read parameter \"{}\"", message))
+    }
+
+    /// Indicates if message is a known parameter line e.g. "[G54:...]"
+    pub fn is_response(message: &str) -> bool {
+        let parameter_message = match message.strip_prefix(PARAMETER_PREFIX).and_then(|message| message.strip_suffix(PARAMETER_SUFFIX)) {
+            Some(parameter_message) => parameter_message,
+            None => return false,
+        };
+        match parameter_message.split_once(':') {
+            Some((name, _)) => get_ngc_parameter_name(name).is_ok(),
+            None => false,
+        }
+    }
+
+    /// Get a reference to the parameter's name
+    pub fn name(&self) -> &NgcParameterName {
+        &self.name
+    }
+
+    /// Get a reference to the parameter's position
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
+
+    /// Multiplies the position by the factor e.g. to convert inch to mm
+    pub fn scale_position(&mut self, factor: f32) {
+        self.position = self.position.scaled(factor);
+    }
+}
+
+/// All parameters of the last "$#" response
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NgcParameters {
+    /// Offsets by coordinate system index
+    coordinate_systems: BTreeMap<usize, Position>,
+    home_position: Option<Position>,
+    secondary_home_position: Option<Position>,
+    coordinate_offset: Option<Position>,
+    tool_length_offset: Option<Position>,
+}
+
+impl NgcParameters {
+
+    /// Replaces the stored value of the parameter
+    pub fn put(&mut self, parameter: NgcParameter) {
+        match parameter.name {
+            NgcParameterName::CoordinateSystem(coordinate_system) => {
+                self.coordinate_systems.insert(get_coordinate_system_index(&coordinate_system), parameter.position);
+            }
+            NgcParameterName::HomePosition => self.home_position = Some(parameter.position),
+            NgcParameterName::SecondaryHomePosition => self.secondary_home_position = Some(parameter.position),
+            NgcParameterName::CoordinateOffset => self.coordinate_offset = Some(parameter.position),
+            NgcParameterName::ToolLengthOffset => self.tool_length_offset = Some(parameter.position),
+        }
+    }
+
+    /// Get a reference to the stored value of the parameter
+    pub fn get(&self, name: &NgcParameterName) -> Option<&Position> {
+        match name {
+            NgcParameterName::CoordinateSystem(coordinate_system) => self.coordinate_systems.get(&get_coordinate_system_index(coordinate_system)),
+            NgcParameterName::HomePosition => self.home_position.as_ref(),
+            NgcParameterName::SecondaryHomePosition => self.secondary_home_position.as_ref(),
+            NgcParameterName::CoordinateOffset => self.coordinate_offset.as_ref(),
+            NgcParameterName::ToolLengthOffset => self.tool_length_offset.as_ref(),
+        }
+    }
+
+    /// Get a reference to the offset of the work coordinate system
+    pub fn coordinate_system_offset(&self, coordinate_system: &CoordinateSystem) -> Option<&Position> {
+        self.get(&NgcParameterName::CoordinateSystem(*coordinate_system))
+    }
+}
+
+/// Get parameter name by "$#" name e.g. "G54", "G28" or "TLO"
+pub fn get_ngc_parameter_name(name: &str) -> Result<NgcParameterName, String> {
+    match name {
+        HOME_POSITION_NAME => Ok(NgcParameterName::HomePosition),
+        SECONDARY_HOME_POSITION_NAME => Ok(NgcParameterName::SecondaryHomePosition),
+        COORDINATE_OFFSET_NAME => Ok(NgcParameterName::CoordinateOffset),
+        TOOL_LENGTH_OFFSET_NAME => Ok(NgcParameterName::ToolLengthOffset),
+        _ => match get_coordinate_system(name) {
+            Ok(coordinate_system) => Ok(NgcParameterName::CoordinateSystem(coordinate_system)),
+            Err(_) => Err(format!("Unknown parameter \"{}\"", name)),
+        },
+    }
+}
@@ -0,0 +1,113 @@
+use std::result::Result;
+
+use crate::device::util::axis::{get_axis, Axis};
+
+const ODOMETER_PREFIX: &str = "[ODOMETER:";
+const ODOMETER_SUFFIX: &str = "]";
+const ODOMETER_FIELD_SEPARATOR: char = '|';
+const SPINDLE_FIELD: &str = "SPINDLE";
+const MOTORS_FIELD: &str = "MOTORS";
+
+/// Maintenance data of the grblHAL odometer plugin ("$ODOMETERS")
+///
+/// The line layout is not verified against the output of a device, spindle and motor hours are
+/// optional and unknown fields are skipped to tolerate other plugin versions
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Odometer {
+    /// Travel per axis in m
+    axis_distances: Vec<(Axis, f32)>,
+
+    /// Spindle runtime in hours
+    spindle_hours: Option<f32>,
+
+    /// Runtime of the enabled stepper motors in hours
+    motor_hours: Option<f32>,
+}
+
+impl Odometer {
+
+    /// Reads the odometer line e.g. "[ODOMETER:X=1234.5,Y=800.0,Z=120.3|SPINDLE=12.50|MOTORS=40.25]"
+    ///
+    /// Unknown fields of newer plugin versions are ignored
+    ///
+    /// # Errors
+    /// Returns an error when parsing fails
+    ///
+    /// # Examples
+    /// ```
+    /// use grbli::device::{response::state::odometer::Odometer, util::axis::Axis};
+    ///
+    /// let odometer = Odometer::from("[ODOMETER:X=1234.5,Y=800.0,Z=120.3|SPINDLE=12.50]").unwrap();
+    /// assert_eq!(odometer.distance(&Axis::Y), Some(800.0));
+    /// assert_eq!(odometer.spindle_hours(), Some(12.5));
+    /// assert_eq!(odometer.motor_hours(), None);
+    /// ```
+    pub fn from(message: &str) -> Result<Odometer, String> {
+        if Odometer::is_response(message) {
+            let odometer_message = message.strip_prefix(ODOMETER_PREFIX).unwrap().strip_suffix(ODOMETER_SUFFIX).unwrap();
+            let mut fields = odometer_message.split(ODOMETER_FIELD_SEPARATOR);
+
+            let mut odometer = Odometer::default();
+            for axis_distance in fields.next().unwrap_or_default().split(',').filter(|value| !value.is_empty()) {
+                let (axis_str, distance_str) = match axis_distance.split_once('=') {
+                    Some(values) => values,
+                    None => return Err(format!("Invalid odometer distance \"{}\"", axis_distance)),
+                };
+                let axis = get_axis(axis_str)?;
+                let distance = match distance_str.parse::<f32>() {
+                    Ok(distance) => distance,
+                    Err(_) => return Err(format!("Cannot read odometer distance of {} \"{}\"", axis_str, distance_str)),
+                };
+                odometer.axis_distances.push((axis, distance));
+            }
+
+            for field in fields {
+                let (name, value_str) = field.split_once('=').unwrap_or((field, ""));
+                match name {
+                    SPINDLE_FIELD => odometer.spindle_hours = Some(parse_hours(field, value_str)?),
+                    MOTORS_FIELD => odometer.motor_hours = Some(parse_hours(field, value_str)?),
+                    _ => (),
+                }
+            }
+            return Ok(odometer);
+        }
+        Err(format!("Cannot read odometer \"{}\"", message))
+    }
+
+    /// Indicates if message has odometer syntax
+    pub fn is_response(message: &str) -> bool {
+        message.starts_with(ODOMETER_PREFIX) && message.ends_with(ODOMETER_SUFFIX)
+    }
+
+    /// Get the travel of the axis in m
+    pub fn distance(&self, axis: &Axis) -> Option<f32> {
+        self.axis_distances.iter().find(|(distance_axis, _)| distance_axis == axis).map(|(_, distance)| *distance)
+    }
+
+    /// Get the travel of all reported axes in m
+    pub fn axis_distances(&self) -> &[(Axis, f32)] {
+        self.axis_distances.as_ref()
+    }
+
+    /// Get the spindle runtime in hours if reported
+    pub fn spindle_hours(&self) -> Option<f32> {
+        self.spindle_hours
+    }
+
+    /// Get the runtime of the stepper motors in hours if reported
+    pub fn motor_hours(&self) -> Option<f32> {
+        self.motor_hours
+    }
+
+    /// Indicates if the axis travelled at least the distance in m e.g. to schedule lubrication
+    pub fn is_distance_exceeded(&self, axis: &Axis, distance: f32) -> bool {
+        self.distance(axis).is_some_and(|axis_distance| axis_distance >= distance)
+    }
+}
+
+fn parse_hours(field: &str, hours: &str) -> Result<f32, String> {
+    match hours.parse::<f32>() {
+        Ok(hours) => Ok(hours),
+        Err(_) => Err(format!("Cannot read odometer hours \"{}\"", field)),
+    }
+}
@@ -0,0 +1,128 @@
+use std::{result::Result, time::Duration};
+
+const PID_LOG_PREFIX: &str = "[PID:";
+const PID_LOG_SUFFIX: &str = "]";
+const PID_LOG_SAMPLES_SEPARATOR: char = '|';
+
+/// Sample of the spindle sync PID loop
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PidSample {
+    target: f32,
+    actual: f32,
+}
+
+/// Spindle sync PID log of grblHAL ("$PIDL")
+///
+/// Samples are recorded with a fixed interval starting at the first sample, the layout follows
+/// `report_pid_log()` of the grblHAL core (setpoint, sample time, 2 values per sample)
+#[derive(Clone, Debug, PartialEq)]
+pub struct PidLog {
+    setpoint: f32,
+
+    /// Sample interval in seconds
+    sample_time: f32,
+    samples: Vec<PidSample>,
+}
+
+impl PidSample {
+
+    pub fn new(target: f32, actual: f32) -> Self {
+        PidSample { target, actual }
+    }
+
+    /// Get the value the PID loop tried to reach
+    pub fn target(&self) -> f32 {
+        self.target
+    }
+
+    /// Get the measured value
+    pub fn actual(&self) -> f32 {
+        self.actual
+    }
+
+    /// Get the control error (target - actual)
+    pub fn error(&self) -> f32 {
+        self.target - self.actual
+    }
+}
+
+impl PidLog {
+
+    /// Reads the log "[PID:\<setpoint\>,\<sample time\>,\<values per sample\>|\<target\>,\<actual\>,...]"
+    ///
+    /// # Errors
+    /// Returns an error when parsing fails or the values do not form complete samples
+    ///
+    /// # Examples
+    /// ```
+    /// use grbli::device::response::state::pid_log::PidLog;
+    ///
+    /// let log = PidLog::from("[PID:1000.0,0.01,2|990.0,985.5,1000.0,998.0]").unwrap();
+    /// assert_eq!(log.setpoint(), 1000.0);
+    /// assert_eq!(log.samples().len(), 2);
+    /// assert_eq!(log.samples()[0].error(), 4.5);
+    /// ```
+    pub fn from(message: &str) -> Result<PidLog, String> {
+        if PidLog::is_response(message) {
+            let log_message = message.strip_prefix(PID_LOG_PREFIX).unwrap().strip_suffix(PID_LOG_SUFFIX).unwrap();
+            let (header, values_str) = log_message.split_once(PID_LOG_SAMPLES_SEPARATOR).unwrap_or((log_message, ""));
+
+            let header_values = parse_values(header)?;
+            if header_values.len() != 3 {
+                return Err(format!("Invalid PID log header \"{}\"", header));
+            }
+            let values_per_sample = header_values[2] as usize;
+            if values_per_sample != 2 {
+                return Err(format!("Unsupported count of PID log values per sample {}", header_values[2]));
+            }
+
+            let values = parse_values(values_str)?;
+            if values.len() % values_per_sample != 0 {
+                return Err(format!("Incomplete PID log sample in \"{}\"", message));
+            }
+            let samples = values.chunks(values_per_sample).map(|sample| PidSample::new(sample[0], sample[1])).collect();
+            return Ok(PidLog { setpoint: header_values[0], sample_time: header_values[1], samples });
+        }
+        Err(format!("Cannot read PID log \"{}\"", message))
+    }
+
+    /// Indicates if message has PID log syntax
+    pub fn is_response(message: &str) -> bool {
+        message.starts_with(PID_LOG_PREFIX) && message.ends_with(PID_LOG_SUFFIX)
+    }
+
+    pub fn setpoint(&self) -> f32 {
+        self.setpoint
+    }
+
+    /// Get the interval between two samples
+    pub fn sample_time(&self) -> Duration {
+        Duration::from_secs_f32(self.sample_time.max(0.0))
+    }
+
+    /// Get the samples in recorded order
+    pub fn samples(&self) -> &[PidSample] {
+        self.samples.as_ref()
+    }
+
+    /// Get the samples with their time since the first sample in seconds
+    pub fn time_series(&self) -> Vec<(f32, PidSample)> {
+        self.samples.iter().enumerate().map(|(index, sample)| (index as f32 * self.sample_time, *sample)).collect()
+    }
+
+    /// Get the largest absolute control error or `None` without samples
+    pub fn max_error(&self) -> Option<f32> {
+        self.samples.iter().map(|sample| sample.error().abs()).reduce(f32::max)
+    }
+}
+
+fn parse_values(values: &str) -> Result<Vec<f32>, String> {
+    let mut parsed_values = Vec::new();
+    for value in values.split(',').filter(|value| !value.is_empty()) {
+        match value.parse::<f32>() {
+            Ok(value) => parsed_values.push(value),
+            Err(_) => return Err(format!("Cannot read PID log value \"{}\"", value)),
+        }
+    }
+    Ok(parsed_values)
+}
@@ -0,0 +1,74 @@
+use std::result::Result;
+
+use super::machine::position::{parse_position, Position};
+
+const PROBE_RESULT_PREFIX: &str = "[PRB:";
+const PROBE_RESULT_SUFFIX: &str = "]";
+
+/// Result of the last probing cycle (G38.x)
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProbeResult {
+    /// Machine position at which the probe was triggered
+    position: Position,
+
+    /// Indicates if the probe made contact
+    succeeded: bool,
+}
+
+impl ProbeResult {
+
+    /// Reads the probe result from "[PRB:\<x\>,\<y\>,\<z\>,...:\<0|1\>]"
+    ///
+    /// # Errors
+    /// Returns an error when parsing fails
+    ///
+    /// # Examples
+    /// ```
+    /// use grbli::device::{response::state::probe::ProbeResult, util::axis::Axis};
+    ///
+    /// let result = ProbeResult::from("[PRB:0.000,10.000,-5.125:1]").unwrap();
+    /// assert!(result.succeeded());
+    /// assert_eq!(result.position().get(&Axis::Z), Some(-5.125));
+    /// ```
+    pub fn from(message: &str) -> Result<ProbeResult, String> {
+        if ProbeResult::is_response(message) {
+            let probe_message = message.strip_prefix(PROBE_RESULT_PREFIX).unwrap().strip_suffix(PROBE_RESULT_SUFFIX).unwrap();
+            let (position_str, succeeded_str) = match probe_message.rsplit_once(':') {
+                Some(values) => values,
+                None => return Err(format!("Probe result requires position and success flag \"{}\"", message)),
+            };
+
+            let position = match parse_position(position_str) {
+                Ok(position) => position,
+                Err(err) => return Err(format!("Cannot read probe position: {}", err)),
+            };
+            let succeeded = match succeeded_str {
+                "1" => true,
+                "0" => false,
+                _ => return Err(format!("Cannot read probe success flag \"{}\"", succeeded_str)),
+            };
+            return Ok(ProbeResult { position, succeeded });
+        }
+        Err(format!("Cannot read probe result \"{}\"", message))
+    }
+
+    /// Indicates if message has probe result syntax
+    pub fn is_response(message: &str) -> bool {
+        message.starts_with(PROBE_RESULT_PREFIX) && message.ends_with(PROBE_RESULT_SUFFIX)
+    }
+
+    /// Get a reference to the machine position of the probe result
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
+
+    /// Multiplies the position by the factor e.g. to convert inch to mm
+    pub fn scale_position(&mut self, factor: f32) {
+        self.position = self.position.scaled(factor);
+    }
+
+    /// Indicates if the probe made contact
+    pub fn succeeded(&self) -> bool {
+        self.succeeded
+    }
+}
@@ -0,0 +1,137 @@
+const SD_CARD_PROGRESS_PREFIX: &str = "SD:";
+const SD_CARD_FILE_PREFIX: &str = "[FILE:";
+const SD_CARD_FILE_SUFFIX: &str = "]";
+const SD_CARD_FILE_SIZE_SEPARATOR: &str = "|SIZE:";
+
+/// grblHAL progress of a job streamed from the sd card
+#[derive(Clone, Debug, PartialEq)]
+pub struct SdCardProgress {
+    /// Progress in percent of the file size
+    percentage: f32,
+    file_name: Option<String>,
+}
+
+impl SdCardProgress {
+
+    /// Creates the progress from "SD:\<percentage\>,\<file name\>"
+    ///
+    /// # Examples
+    /// ```
+    /// use grbli::device::response::state::sdcard::SdCardProgress;
+    ///
+    /// let progress = SdCardProgress::from("SD:12.5,/jobs/part,1.nc").unwrap();
+    /// assert_eq!(progress.percentage(), 12.5);
+    /// assert_eq!(progress.file_name(), Some("/jobs/part,1.nc"));
+    /// ```
+    pub fn from(message: &str) -> Result<SdCardProgress, String> {
+        if SdCardProgress::is_sd_card_progress(message) {
+            let progress_message = &message[SD_CARD_PROGRESS_PREFIX.len()..];
+
+            // file names may contain ",", only the first one separates the percentage
+            let (percentage_str, file_name) = match progress_message.split_once(',') {
+                Some((percentage_str, file_name)) => (percentage_str, Some(file_name.to_string())),
+                None => (progress_message, None),
+            };
+            let percentage: f32 = match percentage_str.parse() {
+                Ok(value) => value,
+                Err(_) => return Err(format!("Cannot read sd card progress \"{}\"", percentage_str))
+            };
+            return Ok(SdCardProgress { percentage, file_name });
+        }
+        Err(format!("Cannot read sd card progress message \"{}\"", message))
+    }
+
+    /// Indicates if message starts with "SD:"
+    pub fn is_sd_card_progress(message: &str) -> bool {
+        message.starts_with(SD_CARD_PROGRESS_PREFIX)
+    }
+
+    /// Get the progress in percent
+    pub fn percentage(&self) -> f32 {
+        self.percentage
+    }
+
+    /// Get a reference to the streamed file name
+    pub fn file_name(&self) -> Option<&str> {
+        self.file_name.as_deref()
+    }
+}
+
+/// File on the sd card reported by "$F"
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SdCardFile {
+    name: String,
+
+    /// Size in bytes
+    size: u64,
+}
+
+impl SdCardFile {
+
+    /// Creates the file from "[FILE:\<name\>|SIZE:\<size\>]"
+    ///
+    /// # Examples
+    /// ```
+    /// use grbli::device::response::state::sdcard::SdCardFile;
+    ///
+    /// let file = SdCardFile::from("[FILE:/jobs/part.nc|SIZE:1024]").unwrap();
+    /// assert_eq!(file.name(), "/jobs/part.nc");
+    /// assert_eq!(file.size(), 1024);
+    /// ```
+    pub fn from(message: &str) -> Result<SdCardFile, String> {
+        if SdCardFile::is_response(message) {
+            let file_message = message.strip_prefix(SD_CARD_FILE_PREFIX).unwrap().strip_suffix(SD_CARD_FILE_SUFFIX).unwrap();
+
+            // file names may contain "|", only the last size field is split off
+            let (name, size_str) = match file_message.rsplit_once(SD_CARD_FILE_SIZE_SEPARATOR) {
+                Some(values) => values,
+                None => return Err(format!("Sd card file requires a size \"{}\"", message)),
+            };
+            let size = match size_str.parse::<u64>() {
+                Ok(size) => size,
+                Err(_) => return Err(format!("Cannot read sd card file size \"{}\"", size_str)),
+            };
+            return Ok(SdCardFile { name: name.to_string(), size });
+        }
+        Err(format!("Cannot read sd card file \"{}\"", message))
+    }
+
+    /// Indicates if message has sd card file syntax
+    pub fn is_response(message: &str) -> bool {
+        message.starts_with(SD_CARD_FILE_PREFIX) && message.ends_with(SD_CARD_FILE_SUFFIX)
+    }
+
+    /// Get a reference to the file name including its directory
+    pub fn name(&self) -> &str {
+        self.name.as_ref()
+    }
+
+    /// Get the file size in bytes
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+}
+
+/// Files of the last sd card directory listing
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SdCardListing {
+    files: Vec<SdCardFile>,
+}
+
+impl SdCardListing {
+
+    pub fn put_file(&mut self, file: SdCardFile) {
+        self.files.push(file);
+    }
+
+    /// Get a reference to all files in reported order
+    pub fn files(&self) -> &[SdCardFile] {
+        self.files.as_ref()
+    }
+
+    /// Get a reference to the file with the name, a leading "/" is ignored
+    pub fn get_file(&self, name: &str) -> Option<&SdCardFile> {
+        let name = name.trim_start_matches('/');
+        self.files.iter().find(|file| file.name.trim_start_matches('/') == name)
+    }
+}
@@ -1,6 +1,9 @@
 
 const MACHINE_SIGNALE_PREFIX: &str = "PN:";
 
+/// Prefix used by grbl 1.1 and grblHAL
+const MACHINE_SIGNAL_PREFIX_PN: &str = "Pn:";
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum MachineSignal {
     ProbeTriggered,
@@ -45,7 +48,7 @@ pub fn get_machine_signal(signal: &str) -> Result<MachineSignal, String> {
     }
 }
 
-/// Returns all signals from signal message e.g. "PN:WT"
+/// Returns all signals from signal message e.g. "Pn:WT" or "PN:WT"
 /// 
 /// # Examples
 /// ```
@@ -53,6 +56,7 @@ pub fn get_machine_signal(signal: &str) -> Result<MachineSignal, String> {
 /// ```
 pub fn parse_machine_signal(message: &str) -> Result<Vec<MachineSignal>, String> {
     if is_machine_signal(message) {
+        // both prefixes have the same length
         return parse_machine_signal_values(&message[MACHINE_SIGNALE_PREFIX.len()..]);
     }
     Err(format!("Cannot read machine signal \"{}\"", message))
@@ -78,5 +82,5 @@ fn parse_machine_signal_values(signal_values: &str) -> Result<Vec<MachineSignal>
 }
 
 pub fn is_machine_signal(message: &str) -> bool {
-    message.starts_with(MACHINE_SIGNALE_PREFIX)
+    message.starts_with(MACHINE_SIGNALE_PREFIX) || message.starts_with(MACHINE_SIGNAL_PREFIX_PN)
 }
\ No newline at end of file
@@ -0,0 +1,109 @@
+use std::result::Result;
+
+use crate::device::util::signals::{ThcSignal, ThcSignalSet};
+
+const THC_STATE_PREFIX: &str = "THC:";
+
+/// Torch height control state of the grblHAL plasma plugin
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ThcState {
+    /// Measured arc voltage in V
+    arc_voltage: f32,
+    signals: ThcSignalSet,
+}
+
+impl ThcState {
+
+    /// Creates a thc state from the arc voltage in V and the asserted signals
+    pub fn new(arc_voltage: f32, signals: ThcSignalSet) -> Self {
+        ThcState { arc_voltage, signals }
+    }
+
+    /// Get the measured arc voltage in V
+    pub fn arc_voltage(&self) -> f32 {
+        self.arc_voltage
+    }
+
+    /// Get the asserted thc signals
+    pub fn signals(&self) -> ThcSignalSet {
+        self.signals
+    }
+
+    /// Indicates if the plasma cutter reports a stable arc
+    pub fn is_arc_ok(&self) -> bool {
+        self.signals.contains(&ThcSignal::ArcOk)
+    }
+
+    /// Indicates if height control is enabled for the job
+    pub fn is_enabled(&self) -> bool {
+        self.signals.contains(&ThcSignal::Enabled)
+    }
+
+    /// Indicates if height control currently adjusts the torch height
+    pub fn is_active(&self) -> bool {
+        self.signals.contains(&ThcSignal::Active)
+    }
+
+    /// Indicates if the torch is switched on
+    pub fn is_torch_on(&self) -> bool {
+        self.signals.contains(&ThcSignal::TorchOn)
+    }
+
+    /// Indicates if height control is paused because the feed dropped below the velocity threshold (corners)
+    pub fn is_velocity_locked(&self) -> bool {
+        self.signals.contains(&ThcSignal::VelocityLock)
+    }
+
+    /// Indicates if height control is paused because the arc voltage jumped (holes, crossing kerfs)
+    pub fn is_void_locked(&self) -> bool {
+        self.signals.contains(&ThcSignal::VoidLock)
+    }
+
+    /// Indicates if height control moves the torch up
+    pub fn is_moving_up(&self) -> bool {
+        self.signals.contains(&ThcSignal::Up)
+    }
+
+    /// Indicates if height control moves the torch down
+    pub fn is_moving_down(&self) -> bool {
+        self.signals.contains(&ThcSignal::Down)
+    }
+
+    /// Indicates if the arc was lost while the torch is still on, compared to the previous state
+    pub fn is_arc_lost(&self, previous: &ThcState) -> bool {
+        self.is_torch_on() && previous.is_torch_on() && previous.is_arc_ok() && !self.is_arc_ok()
+    }
+}
+
+/// Returns the torch height control state of plasma machines from "THC:\<arc voltage\>,\<signals\>"
+///
+/// # Examples
+/// ```
+/// use grbli::device::response::state::thc::parse_thc_state;
+///
+/// let state = parse_thc_state("THC:121.5,AET").unwrap();
+/// assert_eq!(state.arc_voltage(), 121.5);
+/// assert!(state.is_arc_ok() && state.is_torch_on());
+/// assert!(!state.is_velocity_locked());
+/// ```
+pub fn parse_thc_state(message: &str) -> Result<ThcState, String> {
+    if is_thc_state(message) {
+        let thc_message = &message[THC_STATE_PREFIX.len()..];
+        let (voltage_str, letters) = thc_message.split_once(',').unwrap_or((thc_message, ""));
+        let arc_voltage: f32 = match voltage_str.parse() {
+            Ok(value) => value,
+            Err(_) => return Err(format!("Cannot read arc voltage \"{}\"", voltage_str)),
+        };
+        let signals = match ThcSignalSet::from_letters(letters) {
+            Ok(signals) => signals,
+            Err(_) => return Err(format!("Cannot read thc signals \"{}\"", letters)),
+        };
+        return Ok(ThcState { arc_voltage, signals });
+    }
+    Err(format!("Cannot read thc state \"{}\"", message))
+}
+
+/// Indicates if message has thc state syntax
+pub fn is_thc_state(message: &str) -> bool {
+    message.starts_with(THC_STATE_PREFIX)
+}
@@ -25,4 +25,30 @@ pub fn parse_tool_length_reference(message: &str) -> Result<bool, String> {
 /// Indicates if message has tlr syntax
 pub fn is_tool_length_reference(message: &str) -> bool {
     message.starts_with(TOOL_LENGTH_REFERENCE_PREFIX)
-}
\ No newline at end of file
+}
+
+const CURRENT_TOOL_PREFIX: &str = "T:";
+
+/// Returns the current tool number e.g. "T:3"
+/// 
+/// # Examples
+/// ```
+/// use grbli::device::response::state::tool::parse_current_tool;
+///
+/// assert_eq!(parse_current_tool("T:3").unwrap(), 3);
+/// ```
+pub fn parse_current_tool(message: &str) -> Result<u32, String> {
+    if is_current_tool(message) {
+        let tool_message = &message[CURRENT_TOOL_PREFIX.len()..];
+        return match tool_message.parse::<u32>() {
+            Ok(tool) => Ok(tool),
+            Err(_) => Err(format!("Cannot read current tool \"{}\"", tool_message))
+        };
+    }
+    Err(format!("Cannot read current tool message \"{}\"", message))
+}
+
+/// Indicates if message has current tool syntax
+pub fn is_current_tool(message: &str) -> bool {
+    message.starts_with(CURRENT_TOOL_PREFIX)
+}
@@ -0,0 +1,207 @@
+use std::{collections::BTreeMap, result::Result};
+
+use crate::device::util::axis::{get_axis, get_axis_name, Axis};
+
+use super::machine::position::{parse_position, Position};
+
+const TOOL_TABLE_ENTRY_PREFIX: &str = "[T:";
+const TOOL_TABLE_ENTRY_SUFFIX: &str = "]";
+const TOOL_TABLE_ENTRY_SEPARATOR: char = '|';
+
+const CSV_SEPARATOR: char = ',';
+const CSV_TOOL_COLUMN: &str = "tool";
+const CSV_RADIUS_COLUMN: &str = "radius";
+
+/// X, Y and Z are always part of the CSV
+const CSV_MIN_AXES: usize = 3;
+
+/// Offsets and radius of a tool in the grblHAL tool table
+#[derive(Clone, Debug, PartialEq)]
+pub struct ToolTableEntry {
+    tool: u32,
+    offset: Position,
+    radius: f32,
+}
+
+impl ToolTableEntry {
+
+    pub fn new(tool: u32, offset: Position, radius: f32) -> Self {
+        ToolTableEntry { tool, offset, radius }
+    }
+
+    /// Reads a tool table line of "$#" e.g. "[T:1|0.000,0.000,-12.500|1.500]"
+    ///
+    /// # Errors
+    /// Returns an error when parsing fails
+    ///
+    /// # Examples
+    /// ```
+    /// use grbli::device::{response::state::tool_table::ToolTableEntry, util::axis::Axis};
+    ///
+    /// let entry = ToolTableEntry::from("[T:3|0.000,0.000,-12.500|1.500]").unwrap();
+    /// assert_eq!(entry.tool(), 3);
+    /// assert_eq!(entry.offset().get(&Axis::Z), Some(-12.5));
+    /// assert_eq!(entry.radius(), 1.5);
+    /// ```
+    pub fn from(message: &str) -> Result<ToolTableEntry, String> {
+        if ToolTableEntry::is_response(message) {
+            let entry_message = message.strip_prefix(TOOL_TABLE_ENTRY_PREFIX).unwrap().strip_suffix(TOOL_TABLE_ENTRY_SUFFIX).unwrap();
+            let entry_values: Vec<&str> = entry_message.split(TOOL_TABLE_ENTRY_SEPARATOR).collect();
+            if entry_values.len() < 2 || entry_values.len() > 3 {
+                return Err(format!("Invalid tool table entry \"{}\"", message));
+            }
+
+            let tool = match entry_values[0].parse::<u32>() {
+                Ok(tool) => tool,
+                Err(_) => return Err(format!("Cannot read tool number \"{}\"", entry_values[0])),
+            };
+            let offset = match parse_position(entry_values[1]) {
+                Ok(offset) => offset,
+                Err(err) => return Err(format!("Cannot read offset of tool {}: {}", tool, err)),
+            };
+            let radius = match entry_values.get(2) {
+                Some(radius_str) => match radius_str.parse::<f32>() {
+                    Ok(radius) => radius,
+                    Err(_) => return Err(format!("Cannot read radius of tool {} \"{}\"", tool, radius_str)),
+                },
+                None => 0.0,
+            };
+            return Ok(ToolTableEntry { tool, offset, radius });
+        }
+        Err(format!("Cannot read tool table entry \"{}\"", message))
+    }
+
+    /// Indicates if message has tool table entry syntax
+    pub fn is_response(message: &str) -> bool {
+        message.starts_with(TOOL_TABLE_ENTRY_PREFIX) && message.ends_with(TOOL_TABLE_ENTRY_SUFFIX)
+    }
+
+    /// Get the tool number
+    pub fn tool(&self) -> u32 {
+        self.tool
+    }
+
+    /// Get a reference to the tool offset
+    pub fn offset(&self) -> &Position {
+        &self.offset
+    }
+
+    /// Get the tool radius
+    pub fn radius(&self) -> f32 {
+        self.radius
+    }
+
+    /// Multiplies offset and radius by the factor e.g. to convert inch to mm
+    pub fn scale(&mut self, factor: f32) {
+        self.offset = self.offset.scaled(factor);
+        self.radius *= factor;
+    }
+}
+
+/// All tool table entries reported by "$#"
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ToolTable {
+    entries: BTreeMap<u32, ToolTableEntry>,
+}
+
+impl ToolTable {
+
+    /// Adds the entry or replaces the entry of the same tool
+    pub fn put(&mut self, entry: ToolTableEntry) {
+        self.entries.insert(entry.tool, entry);
+    }
+
+    /// Get a reference to the entry of the tool
+    pub fn get(&self, tool: u32) -> Option<&ToolTableEntry> {
+        self.entries.get(&tool)
+    }
+
+    /// Get all entries ordered by tool number
+    pub fn entries(&self) -> Vec<&ToolTableEntry> {
+        self.entries.values().collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Writes the table as CSV with a header "tool,X,Y,Z,...,radius"
+    ///
+    /// # Examples
+    /// ```
+    /// use grbli::device::response::state::tool_table::{ToolTable, ToolTableEntry};
+    ///
+    /// let mut table = ToolTable::default();
+    /// table.put(ToolTableEntry::from("[T:1|0.000,0.000,-12.500|1.500]").unwrap());
+    /// assert_eq!(table.to_csv(), "tool,X,Y,Z,radius\n1,0,0,-12.5,1.5\n");
+    /// ```
+    pub fn to_csv(&self) -> String {
+        let axes_count = self.entries.values().map(|entry| entry.offset.len()).max().unwrap_or(CSV_MIN_AXES).max(CSV_MIN_AXES);
+        let axes = Position::from_values(&vec![0.0; axes_count]).axes();
+
+        let mut header: Vec<String> = vec![CSV_TOOL_COLUMN.to_string()];
+        header.extend(axes.iter().map(get_axis_name));
+        header.push(CSV_RADIUS_COLUMN.to_string());
+
+        let mut csv = header.join(&CSV_SEPARATOR.to_string()) + "\n";
+        for entry in self.entries.values() {
+            let mut row: Vec<String> = vec![entry.tool.to_string()];
+            row.extend(axes.iter().map(|axis| entry.offset.get(axis).unwrap_or_default().to_string()));
+            row.push(entry.radius.to_string());
+            csv += &(row.join(&CSV_SEPARATOR.to_string()) + "\n");
+        }
+        csv
+    }
+
+    /// Reads a table written by [`ToolTable::to_csv`]
+    ///
+    /// Axis columns are matched by name and may be omitted
+    ///
+    /// # Errors
+    /// Returns an error with the line number if a line cannot be read
+    pub fn from_csv(csv: &str) -> Result<ToolTable, String> {
+        let mut lines = csv.lines().enumerate().filter(|(_, line)| !line.trim().is_empty());
+        let header: Vec<&str> = match lines.next() {
+            Some((_, header)) => header.split(CSV_SEPARATOR).map(|column| column.trim()).collect(),
+            None => return Err("Tool table is empty".to_string()),
+        };
+        if header.first() != Some(&CSV_TOOL_COLUMN) {
+            return Err(format!("Tool table has to start with a \"{}\" column", CSV_TOOL_COLUMN));
+        }
+        let mut columns: Vec<Option<Axis>> = Vec::new();
+        for column in &header[1..] {
+            match (*column, get_axis(&column.to_uppercase())) {
+                (CSV_RADIUS_COLUMN, _) => columns.push(None),
+                (_, Ok(axis)) => columns.push(Some(axis)),
+                _ => return Err(format!("Unknown tool table column \"{}\"", column)),
+            }
+        }
+
+        let mut table = ToolTable::default();
+        for (line_index, line) in lines {
+            let values: Vec<&str> = line.split(CSV_SEPARATOR).map(|value| value.trim()).collect();
+            if values.len() != header.len() {
+                return Err(format!("Line {} has {} instead of {} values", line_index + 1, values.len(), header.len()));
+            }
+            let tool = match values[0].parse::<u32>() {
+                Ok(tool) => tool,
+                Err(_) => return Err(format!("Cannot read tool number \"{}\" in line {}", values[0], line_index + 1)),
+            };
+
+            let mut offset = Position::from_values(&[0.0; CSV_MIN_AXES]);
+            let mut radius = 0.0;
+            for (column, value_str) in columns.iter().zip(&values[1..]) {
+                let value = match value_str.parse::<f32>() {
+                    Ok(value) => value,
+                    Err(_) => return Err(format!("Cannot read value \"{}\" in line {}", value_str, line_index + 1)),
+                };
+                match column {
+                    Some(axis) => offset.set(axis, value),
+                    None => radius = value,
+                }
+            }
+            table.put(ToolTableEntry { tool, offset, radius });
+        }
+        Ok(table)
+    }
+}
@@ -47,6 +47,23 @@ pub fn get_axis_by_index(axis_index: usize) -> Result<Axis, String> {
     }
 }
 
+/// Returns index 0 - 5 for axis
+pub fn get_axis_index(axis: &Axis) -> usize {
+    match axis {
+        Axis::X => 0,
+        Axis::Y => 1,
+        Axis::Z => 2,
+        Axis::A => 3,
+        Axis::B => 4,
+        Axis::C => 5,
+    }
+}
+
+/// Indicates if the axis is linear (X, Y, Z), rotary axes (A, B, C) are programmed in degrees
+pub fn is_linear_axis(axis: &Axis) -> bool {
+    matches!(axis, Axis::X | Axis::Y | Axis::Z)
+}
+
 /// Returns name for axis
 pub fn get_axis_name(axis: &Axis) -> String {
     match axis {
@@ -0,0 +1,84 @@
+use std::result::Result;
+
+/// Count of work coordinate systems available in grblHAL (G54 - G59.3)
+pub const COORDINATE_SYSTEM_COUNT: usize = 9;
+
+/// Work coordinate systems
+///
+/// [Coordinate systems](https://linuxcnc.org/docs/html/gcode/g-code.html#gcode:g54-g59.3)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CoordinateSystem {
+    G54,
+    G55,
+    G56,
+    G57,
+    G58,
+    G59,
+    G59_1,
+    G59_2,
+    G59_3,
+}
+
+/// Get coordinate system by name e.g. "G54" or "G59.1"
+pub fn get_coordinate_system(name: &str) -> Result<CoordinateSystem, String> {
+    match name {
+        "G54" => Ok(CoordinateSystem::G54),
+        "G55" => Ok(CoordinateSystem::G55),
+        "G56" => Ok(CoordinateSystem::G56),
+        "G57" => Ok(CoordinateSystem::G57),
+        "G58" => Ok(CoordinateSystem::G58),
+        "G59" => Ok(CoordinateSystem::G59),
+        "G59.1" => Ok(CoordinateSystem::G59_1),
+        "G59.2" => Ok(CoordinateSystem::G59_2),
+        "G59.3" => Ok(CoordinateSystem::G59_3),
+        _ => Err(format!("Unknown coordinate system \"{}\"", name))
+    }
+}
+
+/// Get coordinate system by index 0 - 8 (G54 - G59.3)
+pub fn get_coordinate_system_by_index(index: usize) -> Result<CoordinateSystem, String> {
+    match index {
+        0 => Ok(CoordinateSystem::G54),
+        1 => Ok(CoordinateSystem::G55),
+        2 => Ok(CoordinateSystem::G56),
+        3 => Ok(CoordinateSystem::G57),
+        4 => Ok(CoordinateSystem::G58),
+        5 => Ok(CoordinateSystem::G59),
+        6 => Ok(CoordinateSystem::G59_1),
+        7 => Ok(CoordinateSystem::G59_2),
+        8 => Ok(CoordinateSystem::G59_3),
+        _ => Err(format!("Unknown coordinate system index \"{}\"", index))
+    }
+}
+
+/// Returns the index 0 - 8 of the coordinate system
+///
+/// The G10 parameter number (P) is index + 1
+pub fn get_coordinate_system_index(coordinate_system: &CoordinateSystem) -> usize {
+    match coordinate_system {
+        CoordinateSystem::G54 => 0,
+        CoordinateSystem::G55 => 1,
+        CoordinateSystem::G56 => 2,
+        CoordinateSystem::G57 => 3,
+        CoordinateSystem::G58 => 4,
+        CoordinateSystem::G59 => 5,
+        CoordinateSystem::G59_1 => 6,
+        CoordinateSystem::G59_2 => 7,
+        CoordinateSystem::G59_3 => 8,
+    }
+}
+
+/// Returns the gcode name of the coordinate system e.g. "G59.1"
+pub fn get_coordinate_system_name(coordinate_system: &CoordinateSystem) -> String {
+    match coordinate_system {
+        CoordinateSystem::G54 => String::from("G54"),
+        CoordinateSystem::G55 => String::from("G55"),
+        CoordinateSystem::G56 => String::from("G56"),
+        CoordinateSystem::G57 => String::from("G57"),
+        CoordinateSystem::G58 => String::from("G58"),
+        CoordinateSystem::G59 => String::from("G59"),
+        CoordinateSystem::G59_1 => String::from("G59.1"),
+        CoordinateSystem::G59_2 => String::from("G59.2"),
+        CoordinateSystem::G59_3 => String::from("G59.3"),
+    }
+}
@@ -0,0 +1,90 @@
+use std::collections::BTreeMap;
+
+/// Commanded states of the aux outputs (M62 - M65, M67/M68)
+///
+/// grblHAL does not report the outputs, ports are unknown until commanded by this host.
+/// Synchronized outputs (M62/M63, M67) are pending until a motion queued after them is completed.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AuxOutputStates {
+    digital: BTreeMap<u16, bool>,
+    analog: BTreeMap<u16, f32>,
+    pending_digital: BTreeMap<u16, bool>,
+    pending_analog: BTreeMap<u16, f32>,
+
+    /// A motion was executed since the pending outputs were commanded
+    pending_motion_seen: bool,
+}
+
+impl AuxOutputStates {
+
+    /// Get the applied state of the digital output
+    pub fn digital(&self, port: u16) -> Option<bool> {
+        self.digital.get(&port).copied()
+    }
+
+    /// Get the applied value of the analog output
+    pub fn analog(&self, port: u16) -> Option<f32> {
+        self.analog.get(&port).copied()
+    }
+
+    /// Get all applied digital outputs ordered by port
+    pub fn digital_outputs(&self) -> &BTreeMap<u16, bool> {
+        &self.digital
+    }
+
+    /// Get all applied analog outputs ordered by port
+    pub fn analog_outputs(&self) -> &BTreeMap<u16, f32> {
+        &self.analog
+    }
+
+    /// Get the synchronized state of the digital output waiting for the next motion
+    pub fn pending_digital(&self, port: u16) -> Option<bool> {
+        self.pending_digital.get(&port).copied()
+    }
+
+    /// Get the synchronized value of the analog output waiting for the next motion
+    pub fn pending_analog(&self, port: u16) -> Option<f32> {
+        self.pending_analog.get(&port).copied()
+    }
+
+    /// Indicates if any synchronized output waits for the next motion
+    pub fn has_pending(&self) -> bool {
+        !self.pending_digital.is_empty() || !self.pending_analog.is_empty()
+    }
+
+    pub fn put_digital(&mut self, port: u16, enabled: bool) {
+        self.digital.insert(port, enabled);
+    }
+
+    pub fn put_analog(&mut self, port: u16, value: f32) {
+        self.analog.insert(port, value);
+    }
+
+    /// Stores the synchronized state until a following motion is completed
+    pub fn put_pending_digital(&mut self, port: u16, enabled: bool) {
+        self.pending_digital.insert(port, enabled);
+        self.pending_motion_seen = false;
+    }
+
+    /// Stores the synchronized value until a following motion is completed
+    pub fn put_pending_analog(&mut self, port: u16, value: f32) {
+        self.pending_analog.insert(port, value);
+        self.pending_motion_seen = false;
+    }
+
+    /// Marks that the device executes a motion ("Run")
+    pub fn put_motion(&mut self) {
+        if self.has_pending() {
+            self.pending_motion_seen = true;
+        }
+    }
+
+    /// Applies the pending outputs if a motion was executed since they were commanded
+    pub fn apply_pending(&mut self) {
+        if self.pending_motion_seen {
+            self.digital.append(&mut self.pending_digital);
+            self.analog.append(&mut self.pending_analog);
+            self.pending_motion_seen = false;
+        }
+    }
+}
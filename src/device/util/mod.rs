@@ -1,2 +1,4 @@
 pub mod axis;
+pub mod coordinate;
+pub mod io;
 pub mod signals;
\ No newline at end of file
@@ -1,3 +1,7 @@
+use std::result::Result;
+
+use crate::device::response::state::signal::{get_machine_signal, MachineSignal};
+
 pub mod mask {
     pub const OFF: u32                  = 0b00000000000000001u32;
     pub const LIMIT_X: u32              = 0b00000000000000010u32;
@@ -16,4 +20,210 @@ pub mod mask {
     pub const OPTIONAL_STOP: u32        = 0b00100000000000000u32;
     pub const PROBE_DISCONNECTED: u32   = 0b01000000000000000u32;
     pub const MOTOR_WARNING: u32        = 0b10000000000000000u32;
-}
\ No newline at end of file
+    pub const MOTOR_FAULT: u32          = 0b100000000000000000u32;
+}
+
+/// All machine signals in order of their mask bit
+const MACHINE_SIGNALS: [(MachineSignal, u32, char); 17] = [
+    (MachineSignal::XLimitSwitchAsserted, mask::LIMIT_X, 'X'),
+    (MachineSignal::YLimitSwitchAsserted, mask::LIMIT_Y, 'Y'),
+    (MachineSignal::ZLimitSwitchAsserted, mask::LIMIT_Z, 'Z'),
+    (MachineSignal::ALimitSwitchAsserted, mask::LIMIT_A, 'A'),
+    (MachineSignal::BLimitSwitchAsserted, mask::LIMIT_B, 'B'),
+    (MachineSignal::CLimitSwitchAsserted, mask::LIMIT_C, 'C'),
+    (MachineSignal::EStopSwitchAsserted, mask::E_STOP, 'E'),
+    (MachineSignal::ProbeTriggered, mask::PROBE, 'P'),
+    (MachineSignal::ResetSwitchAsserted, mask::RESET, 'R'),
+    (MachineSignal::DoorSwitchAsserted, mask::SAFETY_DOOR, 'D'),
+    (MachineSignal::FeedHoldSwitchAsserted, mask::HOLD, 'H'),
+    (MachineSignal::CycleStartSwitchAsserted, mask::CYCLE_START, 'S'),
+    (MachineSignal::BlockDeleteSwitchAsserted, mask::BLOCK_DELETE, 'L'),
+    (MachineSignal::OptionalProgramStopSwitchAsserted, mask::OPTIONAL_STOP, 'T'),
+    (MachineSignal::ProbeDisconnected, mask::PROBE_DISCONNECTED, 'O'),
+    (MachineSignal::MotorWarning, mask::MOTOR_WARNING, 'W'),
+    (MachineSignal::MotorFault, mask::MOTOR_FAULT, 'M'),
+];
+
+/// Returns the mask bit of the signal
+pub fn get_signal_mask(signal: &MachineSignal) -> u32 {
+    MACHINE_SIGNALS.iter().find(|(machine_signal, _, _)| machine_signal == signal).map(|(_, bit, _)| *bit).unwrap()
+}
+
+/// Returns the report letter of the signal e.g. 'P' for [`MachineSignal::ProbeTriggered`]
+pub fn get_signal_letter(signal: &MachineSignal) -> char {
+    MACHINE_SIGNALS.iter().find(|(machine_signal, _, _)| machine_signal == signal).map(|(_, _, letter)| *letter).unwrap()
+}
+
+/// Set of asserted machine signals stored as [`mask`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SignalSet {
+    mask: u32,
+}
+
+impl SignalSet {
+
+    /// Creates an empty set
+    pub fn new() -> Self {
+        SignalSet { mask: 0 }
+    }
+
+    /// Creates a set from signal mask bits
+    pub fn from_mask(mask: u32) -> Self {
+        SignalSet { mask }
+    }
+
+    /// Creates a set from signals
+    pub fn from_signals(signals: &[MachineSignal]) -> Self {
+        SignalSet { mask: signals.iter().fold(0, |mask, signal| mask | get_signal_mask(signal)) }
+    }
+
+    /// Creates a set from the letters of a "Pn:" field e.g. "PXD"
+    ///
+    /// # Errors
+    /// Returns an error if a letter is unknown
+    ///
+    /// # Examples
+    /// ```
+    /// use grbli::device::{response::state::signal::MachineSignal, util::signals::{mask, SignalSet}};
+    ///
+    /// let signals = SignalSet::from_letters("PX").unwrap();
+    /// assert_eq!(signals.mask(), mask::PROBE | mask::LIMIT_X);
+    /// assert!(signals.contains(&MachineSignal::ProbeTriggered));
+    /// assert_eq!(signals.letters(), "XP");
+    /// ```
+    pub fn from_letters(letters: &str) -> Result<Self, String> {
+        let mut mask = 0;
+        for letter in letters.chars() {
+            match get_machine_signal(&letter.to_string()) {
+                Ok(signal) => mask |= get_signal_mask(&signal),
+                Err(_) => return Err(format!("Unknown machine signal \"{}\"", letter)),
+            }
+        }
+        Ok(SignalSet { mask })
+    }
+
+    /// Get the signal mask bits
+    pub fn mask(&self) -> u32 {
+        self.mask
+    }
+
+    /// Get all signals in order of their mask bit
+    pub fn signals(&self) -> Vec<MachineSignal> {
+        MACHINE_SIGNALS.iter().filter(|(_, bit, _)| self.mask & bit != 0).map(|(signal, _, _)| *signal).collect()
+    }
+
+    /// Get the report letters of all signals in order of their mask bit
+    pub fn letters(&self) -> String {
+        MACHINE_SIGNALS.iter().filter(|(_, bit, _)| self.mask & bit != 0).map(|(_, _, letter)| *letter).collect()
+    }
+
+    /// Indicates if the signal is asserted
+    pub fn contains(&self, signal: &MachineSignal) -> bool {
+        self.mask & get_signal_mask(signal) != 0
+    }
+
+    /// Indicates if no signal is asserted
+    pub fn is_empty(&self) -> bool {
+        self.mask == 0
+    }
+
+    /// Returns the signals which are asserted now but not in the previous set
+    pub fn rising_edges(&self, previous: &SignalSet) -> SignalSet {
+        SignalSet { mask: self.mask & !previous.mask }
+    }
+
+    /// Returns the signals which were asserted in the previous set but not anymore
+    pub fn falling_edges(&self, previous: &SignalSet) -> SignalSet {
+        SignalSet { mask: previous.mask & !self.mask }
+    }
+}
+
+/// Torch height control signals of the grblHAL plasma plugin
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ThcSignal {
+    ArcOk,
+    Enabled,
+    Active,
+    TorchOn,
+    OhmicProbe,
+    VelocityLock,
+    VoidLock,
+    Down,
+    Up,
+}
+
+/// All thc signals in order of [`command::signals::THC`](crate::device::command::signals::THC)
+const THC_SIGNALS: [(ThcSignal, char); 9] = [
+    (ThcSignal::ArcOk, 'A'),
+    (ThcSignal::Enabled, 'E'),
+    (ThcSignal::Active, 'R'),
+    (ThcSignal::TorchOn, 'T'),
+    (ThcSignal::OhmicProbe, 'O'),
+    (ThcSignal::VelocityLock, 'V'),
+    (ThcSignal::VoidLock, 'H'),
+    (ThcSignal::Down, 'D'),
+    (ThcSignal::Up, 'U'),
+];
+
+/// Returns the thc signal by report letter e.g. "A"
+pub fn get_thc_signal(letter: &str) -> Result<ThcSignal, String> {
+    match THC_SIGNALS.iter().find(|(_, thc_letter)| thc_letter.to_string() == letter) {
+        Some((signal, _)) => Ok(*signal),
+        None => Err(format!("Unknown thc signal \"{}\"", letter)),
+    }
+}
+
+/// Set of asserted thc signals, bit n is the n-th letter of [`command::signals::THC`](crate::device::command::signals::THC)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ThcSignalSet {
+    mask: u32,
+}
+
+impl ThcSignalSet {
+
+    /// Creates a set from the thc letters e.g. "AET"
+    ///
+    /// # Errors
+    /// Returns an error if a letter is unknown
+    pub fn from_letters(letters: &str) -> Result<Self, String> {
+        let mut mask = 0;
+        for letter in letters.chars() {
+            mask |= ThcSignalSet::get_mask(&get_thc_signal(&letter.to_string())?);
+        }
+        Ok(ThcSignalSet { mask })
+    }
+
+    /// Get the signal mask bits
+    pub fn mask(&self) -> u32 {
+        self.mask
+    }
+
+    /// Get all signals in letter order
+    pub fn signals(&self) -> Vec<ThcSignal> {
+        THC_SIGNALS.iter().filter(|(signal, _)| self.contains(signal)).map(|(signal, _)| *signal).collect()
+    }
+
+    /// Get the report letters of all signals
+    pub fn letters(&self) -> String {
+        THC_SIGNALS.iter().filter(|(signal, _)| self.contains(signal)).map(|(_, letter)| *letter).collect()
+    }
+
+    /// Indicates if the signal is asserted
+    pub fn contains(&self, signal: &ThcSignal) -> bool {
+        self.mask & ThcSignalSet::get_mask(signal) != 0
+    }
+
+    /// Returns the signals which are asserted now but not in the previous set
+    pub fn rising_edges(&self, previous: &ThcSignalSet) -> ThcSignalSet {
+        ThcSignalSet { mask: self.mask & !previous.mask }
+    }
+
+    /// Returns the signals which were asserted in the previous set but not anymore
+    pub fn falling_edges(&self, previous: &ThcSignalSet) -> ThcSignalSet {
+        ThcSignalSet { mask: previous.mask & !self.mask }
+    }
+
+    fn get_mask(signal: &ThcSignal) -> u32 {
+        1 << THC_SIGNALS.iter().position(|(thc_signal, _)| thc_signal == signal).unwrap()
+    }
+}
@@ -1,6 +1,7 @@
 use std::time::Duration;
 
 pub mod serial;
+pub mod ymodem;
 
 /// Endpoint for device communication
 pub trait Endpoint {
@@ -20,10 +21,38 @@ pub trait Endpoint {
     /// Returns an error if write fails
     fn write(&mut self, message: &str) -> Result<(), String>;
 
+    /// Writes raw bytes to the device stream e.g. extended realtime commands (0x80 - 0xFF)
+    /// 
+    /// The default implementation writes ASCII bytes as message, other bytes are unsupported
+    /// 
+    /// Returns an error if write fails
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), String> {
+        match bytes.is_ascii() {
+            true => self.write(&String::from_utf8_lossy(bytes)),
+            false => Err(String::from("Writing non ASCII bytes is unsupported by the endpoint")),
+        }
+    }
+
     /// Returns all received messages since last read call
     /// 
     /// Timeout stops read if message stream is continuous
     /// 
     /// Returns all messages in message buffer or until timeout triggered
     fn read_new_messages(&self, timeout: Duration) -> Vec<String>;
+
+    /// Switches between line based and raw reading e.g. for file transfers
+    /// 
+    /// Received bytes are returned by read_raw_bytes instead of read_new_messages while enabled
+    /// 
+    /// Returns an error if the endpoint does not support raw mode
+    fn set_raw_mode(&mut self, _enabled: bool) -> Result<(), String> {
+        Err(String::from("Raw mode is unsupported by the endpoint"))
+    }
+
+    /// Returns all bytes received in raw mode since last read call
+    /// 
+    /// Returns after timeout if no bytes were received or an error if the endpoint does not support raw mode
+    fn read_raw_bytes(&self, _timeout: Duration) -> Result<Vec<u8>, String> {
+        Err(String::from("Raw mode is unsupported by the endpoint"))
+    }
 }
\ No newline at end of file
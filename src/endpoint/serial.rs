@@ -1,7 +1,11 @@
 use std::{
     borrow::Borrow,
     panic,
-    sync::mpsc::{self, Receiver, Sender},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Receiver, Sender},
+        Arc,
+    },
     thread::{self, JoinHandle},
     time::{Duration, Instant},
 };
@@ -42,9 +46,13 @@ pub struct SerialEndpoint {
     // handler for buffer read messages
     channel_read: (Sender<String>, Receiver<String>),
     channel_error: (Sender<String>, Receiver<String>),
-    tx_write: Option<Sender<String>>,
+    channel_raw: (Sender<Vec<u8>>, Receiver<Vec<u8>>),
+    tx_write: Option<Sender<Vec<u8>>>,
     tx_close: Option<Sender<bool>>,
 
+    // received bytes are not split into lines while set
+    raw_mode: Arc<AtomicBool>,
+
     // handle for active connection
     serial_thread: Option<JoinHandle<()>>,
 }
@@ -68,8 +76,10 @@ impl SerialEndpoint {
             baud_rate: baud_rate,
             channel_read: mpsc::channel(),
             channel_error: mpsc::channel(),
+            channel_raw: mpsc::channel(),
             tx_write: None,
             tx_close: None,
+            raw_mode: Arc::new(AtomicBool::new(false)),
             serial_thread: None,
         }
     }
@@ -94,6 +104,10 @@ impl SerialEndpoint {
         // channel for sending serial errors
         let tx_error_ref = self.channel_error.0.clone();
 
+        // channel for sending raw bytes e.g. during file transfers
+        let tx_raw_ref = self.channel_raw.0.clone();
+        let raw_mode = self.raw_mode.clone();
+
         // params of serial port
         // e.g /dev/ttyGRBL - 115200
         let port_name = self.port_name.clone();
@@ -155,11 +169,18 @@ impl SerialEndpoint {
 
             loop {
                 // read new serial data
-                SerialEndpoint::read_buffer(
-                    serial_port.try_clone().unwrap(),
-                    &mut message_buffer,
-                    tx_read_ref.borrow(),
-                );
+                if raw_mode.load(Ordering::SeqCst) {
+                    SerialEndpoint::read_raw_buffer(
+                        serial_port.try_clone().unwrap(),
+                        tx_raw_ref.borrow(),
+                    );
+                } else {
+                    SerialEndpoint::read_buffer(
+                        serial_port.try_clone().unwrap(),
+                        &mut message_buffer,
+                        tx_read_ref.borrow(),
+                    );
+                }
 
                 // write pending serial data
                 if let Err(err) = SerialEndpoint::write_buffer(
@@ -230,6 +251,20 @@ impl SerialEndpoint {
         }
     }
 
+    /// Reads all bytes from the serial device buffer and sends them to tx_raw
+    fn read_raw_buffer(mut serial_port: Box<dyn SerialPort>, tx_raw: &Sender<Vec<u8>>) {
+        if let Ok(bytes) = serial_port.bytes_to_read() {
+            if bytes == 0 {
+                return;
+            }
+            let mut byte_buffer: Vec<u8> = vec![0; bytes as usize];
+            if let Ok(read_bytes) = serial_port.read(&mut byte_buffer) {
+                byte_buffer.truncate(read_bytes);
+                let _ = tx_raw.send(byte_buffer);
+            }
+        }
+    }
+
     /// Reads a message from rx_write and sends the content to the serial port
     ///
     /// Returns
@@ -237,10 +272,11 @@ impl SerialEndpoint {
     /// * An error if reading from rx failed
     fn write_buffer(
         mut serial_port: Box<dyn SerialPort>,
-        rx_write: &Receiver<String>,
+        rx_write: &Receiver<Vec<u8>>,
     ) -> Result<bool, String> {
-        if let Ok(message) = rx_write.recv_timeout(Duration::from_millis(1)) {
-            if let Err(_) = serial_port.write(message.as_bytes()) {
+        if let Ok(bytes) = rx_write.recv_timeout(Duration::from_millis(1)) {
+            let message = String::from_utf8_lossy(&bytes);
+            if let Err(_) = serial_port.write(&bytes) {
                 return Err(format!(
                     "Unable to send message \"{}\" to {}",
                     message,
@@ -271,13 +307,17 @@ impl SerialEndpoint {
 
 impl Endpoint for SerialEndpoint {
     fn write(&mut self, message: &str) -> Result<(), String> {
-        // send message to device thread
+        self.write_bytes(message.as_bytes())
+    }
+
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), String> {
+        // send bytes to device thread
         if let Some(tx) = self.tx_write.clone() {
-            match tx.send(message.to_string()) {
+            match tx.send(bytes.to_vec()) {
                 Ok(_) => Ok(()),
                 Err(_) => Err(String::from(format!(
                     "Unable to send \"{}\" to {}",
-                    message, self.port_name
+                    String::from_utf8_lossy(bytes), self.port_name
                 ))),
             }
         } else {
@@ -338,6 +378,27 @@ impl Endpoint for SerialEndpoint {
 
         return buffered_messages;
     }
+
+    fn set_raw_mode(&mut self, enabled: bool) -> Result<(), String> {
+        // drop bytes of a previous transfer
+        while self.channel_raw.1.try_recv().is_ok() {}
+        self.raw_mode.store(enabled, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn read_raw_bytes(&self, timeout: Duration) -> Result<Vec<u8>, String> {
+        let mut buffered_bytes = Vec::new();
+        let read_start = Instant::now();
+        while buffered_bytes.is_empty() && read_start.elapsed() < timeout {
+            if let Ok(bytes) = self.channel_raw.1.recv_timeout(Duration::from_millis(1)) {
+                buffered_bytes.extend(bytes);
+            }
+        }
+        while let Ok(bytes) = self.channel_raw.1.try_recv() {
+            buffered_bytes.extend(bytes);
+        }
+        Ok(buffered_bytes)
+    }
 }
 
 impl Drop for SerialEndpoint {
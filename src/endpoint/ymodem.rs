@@ -0,0 +1,224 @@
+use std::{result::Result, time::{Duration, Instant}};
+
+use super::Endpoint;
+
+/// Start of a 128 byte block
+pub const SOH: u8 = 0x01;
+
+/// Start of a 1024 byte block
+pub const STX: u8 = 0x02;
+
+/// End of transmission
+pub const EOT: u8 = 0x04;
+
+pub const ACK: u8 = 0x06;
+pub const NAK: u8 = 0x15;
+
+/// Cancels the transfer if received twice
+pub const CAN: u8 = 0x18;
+
+/// Receiver requests blocks with CRC-16 checksums
+pub const CRC_REQUEST: u8 = b'C';
+
+/// Padding of the last data block
+pub const PADDING: u8 = 0x1A;
+
+pub const HEADER_BLOCK_SIZE: usize = 128;
+pub const DATA_BLOCK_SIZE: usize = 1024;
+
+/// Maximum count of attempts to send a single block
+const MAX_RETRIES: usize = 10;
+
+/// Maximum time until the receiver has to answer a block
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Time to wait for the initial CRC request of the receiver
+///
+/// grblHAL switches to YMODEM when it receives the first block and does not request it
+const START_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Sends a single file with YMODEM (CRC-16, 1024 byte blocks)
+///
+/// The endpoint has to deliver raw bytes ([`Endpoint::set_raw_mode`]) while sending
+///
+/// # Examples
+/// ```no_run
+/// use grbli::endpoint::{serial::SerialEndpoint, ymodem::YmodemSender, Endpoint};
+///
+/// let mut endpoint = SerialEndpoint::from("/dev/ttyACM0", 115200);
+/// endpoint.open().unwrap();
+/// endpoint.set_raw_mode(true).unwrap();
+/// YmodemSender::new("job.nc", b"G0 X10\n".to_vec())
+///     .send(&mut endpoint, |sent, total| println!("{}/{}", sent, total))
+///     .unwrap();
+/// endpoint.set_raw_mode(false).unwrap();
+/// ```
+pub struct YmodemSender {
+    file_name: String,
+    data: Vec<u8>,
+
+    /// Bytes received but not processed yet
+    received: Vec<u8>,
+}
+
+impl YmodemSender {
+
+    pub fn new(file_name: &str, data: Vec<u8>) -> Self {
+        YmodemSender { file_name: file_name.to_string(), data, received: Vec::new() }
+    }
+
+    /// Sends the file and reports the count of acknowledged bytes after each block
+    ///
+    /// # Errors
+    /// Returns an error if the receiver cancels the transfer, does not respond or rejects a block too often
+    pub fn send<F>(&mut self, endpoint: &mut dyn Endpoint, mut on_progress: F) -> Result<(), String>
+    where
+        F: FnMut(usize, usize),
+    {
+        // an explicit request is optional, grblHAL starts receiving with the first block
+        self.read_response(endpoint, START_TIMEOUT, true)?;
+
+        let header = get_header_block(&self.file_name, self.data.len());
+        self.send_block(endpoint, &get_packet(0, &header))?;
+        self.expect_crc_request(endpoint)?;
+
+        let total_bytes = self.data.len();
+        let mut block = Vec::with_capacity(DATA_BLOCK_SIZE);
+        for (block_index, block_start) in (0..total_bytes).step_by(DATA_BLOCK_SIZE).enumerate() {
+            let block_end = (block_start + DATA_BLOCK_SIZE).min(total_bytes);
+            block.clear();
+            block.extend_from_slice(&self.data[block_start..block_end]);
+            block.resize(DATA_BLOCK_SIZE, PADDING);
+            self.send_block(endpoint, &get_packet(((block_index + 1) % 256) as u8, &block))?;
+            on_progress(block_end, total_bytes);
+        }
+
+        self.send_end_of_transmission(endpoint)?;
+
+        // an empty header ends the batch
+        self.expect_crc_request(endpoint)?;
+        self.send_block(endpoint, &get_packet(0, &[0; HEADER_BLOCK_SIZE]))
+    }
+
+    /// Sends the packet until it is acknowledged
+    fn send_block(&mut self, endpoint: &mut dyn Endpoint, packet: &[u8]) -> Result<(), String> {
+        for _ in 0..MAX_RETRIES {
+            endpoint.write_bytes(packet)?;
+            match self.read_response(endpoint, RESPONSE_TIMEOUT, false)? {
+                Some(ACK) => return Ok(()),
+                Some(CAN) => return self.cancelled(endpoint),
+                _ => continue,
+            }
+        }
+        Err(format!("Block {} was not acknowledged", packet[1]))
+    }
+
+    /// Sends EOT, the receiver may reject the first EOT with NAK to confirm it
+    fn send_end_of_transmission(&mut self, endpoint: &mut dyn Endpoint) -> Result<(), String> {
+        for _ in 0..MAX_RETRIES {
+            endpoint.write_bytes(&[EOT])?;
+            match self.read_response(endpoint, RESPONSE_TIMEOUT, false)? {
+                Some(ACK) => return Ok(()),
+                Some(CAN) => return self.cancelled(endpoint),
+                _ => continue,
+            }
+        }
+        Err("End of transmission was not acknowledged".to_string())
+    }
+
+    fn expect_crc_request(&mut self, endpoint: &mut dyn Endpoint) -> Result<(), String> {
+        match self.read_response(endpoint, RESPONSE_TIMEOUT, true)? {
+            Some(CRC_REQUEST) => Ok(()),
+            Some(CAN) => self.cancelled(endpoint),
+            Some(response) => Err(format!("Expected CRC request instead of 0x{:02X}", response)),
+            None => Err("Receiver did not request the next block".to_string()),
+        }
+    }
+
+    /// Confirms a cancel request which requires two CAN bytes
+    fn cancelled(&mut self, endpoint: &mut dyn Endpoint) -> Result<(), String> {
+        match self.read_response(endpoint, RESPONSE_TIMEOUT, false)? {
+            Some(CAN) => Err("Transfer was cancelled by the receiver".to_string()),
+            _ => Err("Receiver sent an incomplete cancel request".to_string()),
+        }
+    }
+
+    /// Returns the next received control byte, other bytes (e.g. line noise) are skipped
+    ///
+    /// CRC requests ('C') are only accepted while waiting for the start of a file
+    fn read_response(&mut self, endpoint: &mut dyn Endpoint, timeout: Duration, accept_crc_request: bool) -> Result<Option<u8>, String> {
+        let read_start = Instant::now();
+        loop {
+            while !self.received.is_empty() {
+                let byte = self.received.remove(0);
+                if matches!(byte, ACK | NAK | CAN) || (accept_crc_request && byte == CRC_REQUEST) {
+                    return Ok(Some(byte));
+                }
+            }
+            if read_start.elapsed() >= timeout {
+                return Ok(None);
+            }
+            self.received.extend(endpoint.read_raw_bytes(Duration::from_millis(10))?);
+        }
+    }
+}
+
+/// Returns the header block "\<file name\>\0\<size\>\0" padded with zeros
+///
+/// # Examples
+/// ```
+/// use grbli::endpoint::ymodem::get_header_block;
+///
+/// let header = get_header_block("job.nc", 1234);
+/// assert_eq!(&header[..12], b"job.nc\01234\0");
+/// assert_eq!(header.len(), 128);
+/// ```
+pub fn get_header_block(file_name: &str, size: usize) -> Vec<u8> {
+    let mut block = Vec::new();
+    block.extend_from_slice(file_name.as_bytes());
+    block.push(0);
+    block.extend_from_slice(size.to_string().as_bytes());
+    block.push(0);
+    let block_size = match block.len() > HEADER_BLOCK_SIZE {
+        true => DATA_BLOCK_SIZE,
+        false => HEADER_BLOCK_SIZE,
+    };
+    block.resize(block_size, 0);
+    block
+}
+
+/// Returns the packet of the block "\<SOH|STX\> \<number\> \<255 - number\> \<data\> \<crc\>"
+///
+/// Blocks of 128 bytes are sent with SOH, all others with STX
+pub fn get_packet(block_number: u8, block: &[u8]) -> Vec<u8> {
+    let start = match block.len() {
+        HEADER_BLOCK_SIZE => SOH,
+        _ => STX,
+    };
+    let mut packet = vec![start, block_number, 255 - block_number];
+    packet.extend_from_slice(block);
+    packet.extend_from_slice(&get_crc16(block).to_be_bytes());
+    packet
+}
+
+/// Returns the CRC-16/XMODEM checksum (polynomial 0x1021, initial value 0)
+///
+/// # Examples
+/// ```
+/// use grbli::endpoint::ymodem::get_crc16;
+///
+/// assert_eq!(get_crc16(b"123456789"), 0x31C3);
+/// ```
+pub fn get_crc16(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0;
+    for byte in data {
+        crc ^= (*byte as u16) << 8;
+        for _ in 0..8 {
+            crc = match crc & 0x8000 {
+                0 => crc << 1,
+                _ => (crc << 1) ^ 0x1021,
+            };
+        }
+    }
+    crc
+}
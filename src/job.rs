@@ -0,0 +1,6 @@
+pub mod analyzer;
+pub mod gcode;
+pub mod laser;
+pub mod lathe;
+pub mod limits;
+pub mod progress;
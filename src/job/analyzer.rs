@@ -0,0 +1,690 @@
+use std::{f32::consts::{FRAC_PI_2, PI}, result::Result, time::Duration};
+
+use crate::device::{response::{setting::DeviceSettings, state::modes::ArcMode}, util::{axis::{get_axis, get_axis_index, Axis, MAX_AXES}, coordinate::{get_coordinate_system_index, CoordinateSystem, COORDINATE_SYSTEM_COUNT}}};
+
+use super::gcode::{parse_gcode_line, GCodeWord};
+
+const MM_PER_INCH: f32 = 25.4;
+
+/// First max rate setting ($110 = X max rate in mm/min)
+const MAX_RATE_SETTING: u32 = 110;
+
+/// First acceleration setting ($120 = X acceleration in mm/sec^2)
+const ACCELERATION_SETTING: u32 = 120;
+
+/// grbl default max rate in mm/min
+const DEFAULT_MAX_RATE: f32 = 500.0;
+
+/// grbl default acceleration in mm/sec^2
+const DEFAULT_ACCELERATION: f32 = 10.0;
+
+/// Minimal and maximal position of each axis
+///
+/// Positions are relative to the work coordinate system active at job start
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BoundingBox {
+    min: [f32; MAX_AXES],
+    max: [f32; MAX_AXES],
+}
+
+/// Result of an offline job analysis
+///
+/// All lengths are in millimeters
+#[derive(Clone, Debug, PartialEq)]
+pub struct JobAnalysis {
+    bounds: Option<BoundingBox>,
+    machine_bounds: Option<BoundingBox>,
+    cutting_distance: f32,
+    rapid_distance: f32,
+    tool_changes: Vec<u32>,
+    estimated_runtime: Duration,
+    spindle_sync: bool,
+    diameter_mode: bool,
+}
+
+/// Analyzes gcode programs without a connected device
+///
+/// Handles G0/G1/G2/G3, G17-G19, G20/G21, G90/G91, G54-G59.3, G53, G4,
+/// lathe diameter/radius mode (G7/G8) and threading (G33/G76)
+///
+/// # Examples
+/// ```
+/// use grbli::job::analyzer::JobAnalyzer;
+/// use grbli::device::util::axis::Axis;
+///
+/// let analysis = JobAnalyzer::new().analyze("G0 X10\nG1 Y5 F100").unwrap();
+/// assert_eq!(analysis.bounds().unwrap().max(&Axis::X), 10.0);
+/// assert_eq!(analysis.cutting_distance(), 5.0);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct JobAnalyzer {
+    /// Max rate per axis in mm/min
+    max_rates: [f32; MAX_AXES],
+
+    /// Acceleration per axis in mm/sec^2
+    accelerations: [f32; MAX_AXES],
+    work_offsets: [[f32; MAX_AXES]; COORDINATE_SYSTEM_COUNT],
+    coordinate_system: CoordinateSystem,
+    start_position: [f32; MAX_AXES],
+    arc_mode: ArcMode,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum MotionMode {
+    None,
+    Rapid,
+    Linear,
+
+    /// Spindle synchronized motion (G33), the feed depends on the spindle speed
+    SpindleSynchronized,
+    ArcClockwise,
+    ArcCounterClockwise,
+}
+
+/// Modal state and accumulated results while walking through a program
+struct AnalyzerState {
+    position: [f32; MAX_AXES],
+    motion_mode: MotionMode,
+    absolute_distance: bool,
+    units_scale: f32,
+    plane: (usize, usize, usize),
+    coordinate_system_index: usize,
+    feed_rate: f32,
+    arc_mode: ArcMode,
+    selected_tool: u32,
+    bounds: Option<BoundingBox>,
+    cutting_distance: f32,
+    rapid_distance: f32,
+    tool_changes: Vec<u32>,
+    runtime_seconds: f32,
+    spindle_sync: bool,
+    diameter_mode: bool,
+}
+
+impl BoundingBox {
+
+    /// Creates a bounding box containing a single position
+    pub fn from_position(position: &[f32; MAX_AXES]) -> Self {
+        BoundingBox { min: *position, max: *position }
+    }
+
+    /// Extends the bounding box to contain the position
+    pub fn include(&mut self, position: &[f32; MAX_AXES]) {
+        for (axis_index, value) in position.iter().enumerate() {
+            self.min[axis_index] = self.min[axis_index].min(*value);
+            self.max[axis_index] = self.max[axis_index].max(*value);
+        }
+    }
+
+    /// Get the minimal position of the axis
+    pub fn min(&self, axis: &Axis) -> f32 {
+        self.min[get_axis_index(axis)]
+    }
+
+    /// Get the maximal position of the axis
+    pub fn max(&self, axis: &Axis) -> f32 {
+        self.max[get_axis_index(axis)]
+    }
+
+    /// Get the extent of the axis
+    pub fn size(&self, axis: &Axis) -> f32 {
+        self.max(axis) - self.min(axis)
+    }
+
+    fn translate(&self, offset: &[f32; MAX_AXES]) -> BoundingBox {
+        let mut translated = *self;
+        for (axis_index, value) in offset.iter().enumerate() {
+            translated.min[axis_index] += value;
+            translated.max[axis_index] += value;
+        }
+        translated
+    }
+}
+
+impl JobAnalysis {
+
+    /// Get the job's bounding box, None if the job contains no motion
+    pub fn bounds(&self) -> Option<&BoundingBox> {
+        self.bounds.as_ref()
+    }
+
+    /// Get the job's bounding box in machine coordinates, None if the job contains no motion
+    pub fn machine_bounds(&self) -> Option<&BoundingBox> {
+        self.machine_bounds.as_ref()
+    }
+
+    /// Get the distance of all feed moves (G1/G2/G3) in mm
+    pub fn cutting_distance(&self) -> f32 {
+        self.cutting_distance
+    }
+
+    /// Get the distance of all rapid moves (G0) in mm
+    pub fn rapid_distance(&self) -> f32 {
+        self.rapid_distance
+    }
+
+    /// Get the tool numbers of all tool changes (M6) in order of appearance
+    pub fn tool_changes(&self) -> &[u32] {
+        self.tool_changes.as_ref()
+    }
+
+    /// Get the estimated runtime of the job
+    ///
+    /// Each move is assumed to start and end at rest, which overestimates jobs with many short moves
+    pub fn estimated_runtime(&self) -> Duration {
+        self.estimated_runtime
+    }
+
+    /// Indicates if the job contains spindle synchronized motion (G33/G76)
+    pub fn uses_spindle_sync(&self) -> bool {
+        self.spindle_sync
+    }
+
+    /// Indicates if the job programs X as diameter (G7)
+    pub fn uses_diameter_mode(&self) -> bool {
+        self.diameter_mode
+    }
+}
+
+impl Default for JobAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl JobAnalyzer {
+
+    /// Creates an analyzer with grbl default rates and without work offsets
+    pub fn new() -> Self {
+        JobAnalyzer {
+            max_rates: [DEFAULT_MAX_RATE; MAX_AXES],
+            accelerations: [DEFAULT_ACCELERATION; MAX_AXES],
+            work_offsets: [[0.0; MAX_AXES]; COORDINATE_SYSTEM_COUNT],
+            coordinate_system: CoordinateSystem::G54,
+            start_position: [0.0; MAX_AXES],
+            arc_mode: ArcMode::Radius,
+        }
+    }
+
+    /// Creates an analyzer with max rates ($110+) and accelerations ($120+) from the device settings
+    ///
+    /// Missing settings fall back to the grbl defaults
+    pub fn from_settings(settings: &DeviceSettings) -> Self {
+        let mut analyzer = JobAnalyzer::new();
+        for axis_index in 0..MAX_AXES {
+            if let Some(max_rate) = settings.get_setting_f32(&(MAX_RATE_SETTING + axis_index as u32)) {
+                analyzer.max_rates[axis_index] = max_rate;
+            }
+            if let Some(acceleration) = settings.get_setting_f32(&(ACCELERATION_SETTING + axis_index as u32)) {
+                analyzer.accelerations[axis_index] = acceleration;
+            }
+        }
+        analyzer
+    }
+
+    /// Set the offset of a work coordinate system in machine coordinates
+    ///
+    /// Missing axes are set to zero
+    pub fn set_work_offset(&mut self, coordinate_system: &CoordinateSystem, offset: &[f32]) {
+        let mut work_offset = [0.0; MAX_AXES];
+        for (axis_index, value) in offset.iter().take(MAX_AXES).enumerate() {
+            work_offset[axis_index] = *value;
+        }
+        self.work_offsets[get_coordinate_system_index(coordinate_system)] = work_offset;
+    }
+
+    /// Set the work coordinate system active at job start
+    pub fn set_coordinate_system(&mut self, coordinate_system: CoordinateSystem) {
+        self.coordinate_system = coordinate_system;
+    }
+
+    /// Set the work position at job start
+    ///
+    /// Missing axes are set to zero
+    pub fn set_start_position(&mut self, position: &[f32]) {
+        let mut start_position = [0.0; MAX_AXES];
+        for (axis_index, value) in position.iter().take(MAX_AXES).enumerate() {
+            start_position[axis_index] = *value;
+        }
+        self.start_position = start_position;
+    }
+
+    /// Set the lathe arc mode active at job start
+    pub fn set_arc_mode(&mut self, arc_mode: ArcMode) {
+        self.arc_mode = arc_mode;
+    }
+
+    /// Get the max rate of the axis in mm/min
+    pub fn max_rate(&self, axis: &Axis) -> f32 {
+        self.max_rates[get_axis_index(axis)]
+    }
+
+    /// Get the acceleration of the axis in mm/sec^2
+    pub fn acceleration(&self, axis: &Axis) -> f32 {
+        self.accelerations[get_axis_index(axis)]
+    }
+
+    /// Walks through the whole program and returns its extents, distances, tool changes and runtime
+    ///
+    /// Analysis stops at program end (M2/M30)
+    ///
+    /// # Errors
+    /// Returns an error with the line number if a line cannot be parsed or a move is invalid
+    pub fn analyze(&self, program: &str) -> Result<JobAnalysis, String> {
+        let start_index = get_coordinate_system_index(&self.coordinate_system);
+        let mut position = self.start_position;
+        for (axis_index, value) in position.iter_mut().enumerate() {
+            *value += self.work_offsets[start_index][axis_index];
+        }
+
+        let mut state = AnalyzerState {
+            position,
+            motion_mode: MotionMode::None,
+            absolute_distance: true,
+            units_scale: 1.0,
+            plane: (0, 1, 2),
+            coordinate_system_index: start_index,
+            feed_rate: 0.0,
+            arc_mode: self.arc_mode,
+            selected_tool: 0,
+            bounds: None,
+            cutting_distance: 0.0,
+            rapid_distance: 0.0,
+            tool_changes: Vec::new(),
+            runtime_seconds: 0.0,
+            spindle_sync: false,
+            diameter_mode: self.arc_mode == ArcMode::Diameter,
+        };
+
+        for (line_index, line) in program.lines().enumerate() {
+            let words = match parse_gcode_line(line) {
+                Ok(words) => words,
+                Err(err) => return Err(format!("Line {}: {}", line_index + 1, err)),
+            };
+            match self.analyze_block(&mut state, &words) {
+                Ok(true) => break,
+                Ok(false) => {}
+                Err(err) => return Err(format!("Line {}: {}", line_index + 1, err)),
+            }
+        }
+
+        // invalid max rate or acceleration settings result in an infinite runtime
+        let estimated_runtime = match Duration::try_from_secs_f32(state.runtime_seconds) {
+            Ok(runtime) => runtime,
+            Err(_) => return Err(format!("Cannot estimate runtime of {} s", state.runtime_seconds)),
+        };
+        let start_offset = self.work_offsets[start_index];
+        Ok(JobAnalysis {
+            machine_bounds: state.bounds.map(|bounds| bounds.translate(&start_offset)),
+            bounds: state.bounds,
+            cutting_distance: state.cutting_distance,
+            rapid_distance: state.rapid_distance,
+            tool_changes: state.tool_changes,
+            estimated_runtime,
+            spindle_sync: state.spindle_sync,
+            diameter_mode: state.diameter_mode,
+        })
+    }
+
+    /// Applies a single block to the state
+    ///
+    /// Returns true if the block ends the program
+    fn analyze_block(&self, state: &mut AnalyzerState, words: &[GCodeWord]) -> Result<bool, String> {
+        let mut axis_words: [Option<f32>; MAX_AXES] = [None; MAX_AXES];
+        let mut arc_offsets: [Option<f32>; 3] = [None; 3];
+        let mut arc_radius: Option<f32> = None;
+        let mut feed_rate: Option<f32> = None;
+        let mut dwell = false;
+        let mut p_word: Option<f32> = None;
+        let mut machine_coordinates = false;
+        let mut non_motion_axis_words = false;
+        let mut intermediate_rapid = false;
+        let mut tool_change = false;
+        let mut threading_cycle = false;
+        let mut program_end = false;
+
+        for word in words {
+            match word.letter() {
+                'G' => {
+                    let code = word.value();
+                    if word.is_command('G', 0.0) {
+                        state.motion_mode = MotionMode::Rapid;
+                    } else if word.is_command('G', 1.0) || (38.0..39.0).contains(&code) {
+                        state.motion_mode = MotionMode::Linear;
+                    } else if word.is_command('G', 33.0) {
+                        state.motion_mode = MotionMode::SpindleSynchronized;
+                        state.spindle_sync = true;
+                    } else if word.is_command('G', 76.0) {
+                        threading_cycle = true;
+                        state.spindle_sync = true;
+                    } else if word.is_command('G', 2.0) {
+                        state.motion_mode = MotionMode::ArcClockwise;
+                    } else if word.is_command('G', 3.0) {
+                        state.motion_mode = MotionMode::ArcCounterClockwise;
+                    } else if word.is_command('G', 80.0) {
+                        state.motion_mode = MotionMode::None;
+                    } else if word.is_command('G', 4.0) {
+                        dwell = true;
+                    } else if word.is_command('G', 7.0) {
+                        state.arc_mode = ArcMode::Diameter;
+                        state.diameter_mode = true;
+                    } else if word.is_command('G', 8.0) {
+                        state.arc_mode = ArcMode::Radius;
+                    } else if word.is_command('G', 17.0) {
+                        state.plane = (0, 1, 2);
+                    } else if word.is_command('G', 18.0) {
+                        state.plane = (2, 0, 1);
+                    } else if word.is_command('G', 19.0) {
+                        state.plane = (1, 2, 0);
+                    } else if word.is_command('G', 20.0) {
+                        state.units_scale = MM_PER_INCH;
+                    } else if word.is_command('G', 21.0) {
+                        state.units_scale = 1.0;
+                    } else if word.is_command('G', 28.0) || word.is_command('G', 30.0) {
+                        intermediate_rapid = true;
+                    } else if word.is_command('G', 53.0) {
+                        machine_coordinates = true;
+                    } else if word.is_command('G', 90.0) {
+                        state.absolute_distance = true;
+                    } else if word.is_command('G', 91.0) {
+                        state.absolute_distance = false;
+                    } else if word.is_command('G', 10.0)
+                        || word.is_command('G', 28.1)
+                        || word.is_command('G', 30.1)
+                        || word.is_command('G', 43.1)
+                        || word.is_command('G', 92.0)
+                    {
+                        non_motion_axis_words = true;
+                    } else if let Some(index) = JobAnalyzer::get_coordinate_system_word_index(word) {
+                        state.coordinate_system_index = index;
+                    }
+                }
+                'M' => {
+                    if word.is_command('M', 6.0) {
+                        tool_change = true;
+                    } else if word.is_command('M', 2.0) || word.is_command('M', 30.0) {
+                        program_end = true;
+                    }
+                }
+                'T' => state.selected_tool = word.value() as u32,
+                'F' => feed_rate = Some(word.value()),
+                'P' => p_word = Some(word.value()),
+                'R' => arc_radius = Some(word.value()),
+                'I' => arc_offsets[0] = Some(word.value()),
+                'J' => arc_offsets[1] = Some(word.value()),
+                'K' => arc_offsets[2] = Some(word.value()),
+                letter => {
+                    if let Ok(axis) = get_axis(letter.to_string().as_str()) {
+                        axis_words[get_axis_index(&axis)] = Some(word.value());
+                    }
+                }
+            }
+        }
+
+        // P is read as seconds for G4, regardless of its position in the block
+        if dwell {
+            state.runtime_seconds += p_word.unwrap_or(0.0).max(0.0);
+        }
+
+        if let Some(feed_rate) = feed_rate {
+            state.feed_rate = feed_rate * state.units_scale;
+        }
+
+        if tool_change {
+            state.tool_changes.push(state.selected_tool);
+        }
+
+        let has_axis_words = axis_words.iter().any(|word| word.is_some());
+        if !has_axis_words || non_motion_axis_words {
+            return Ok(program_end);
+        }
+
+        let target = self.get_target(state, &axis_words, machine_coordinates);
+        if threading_cycle {
+            let offsets = arc_offsets.map(|offset| offset.map(|value| value * state.units_scale));
+            self.add_threading_cycle(state, &target, &offsets);
+            return Ok(program_end);
+        }
+        let motion_mode = match intermediate_rapid {
+            true => MotionMode::Rapid,
+            false => state.motion_mode,
+        };
+
+        match motion_mode {
+            MotionMode::None => {}
+            MotionMode::Rapid => self.add_linear_move(state, &target, true)?,
+            MotionMode::Linear => self.add_linear_move(state, &target, false)?,
+            MotionMode::SpindleSynchronized => self.add_spindle_synchronized_move(state, &target),
+            MotionMode::ArcClockwise | MotionMode::ArcCounterClockwise => {
+                let offsets = arc_offsets.map(|offset| offset.map(|value| value * state.units_scale));
+                let radius = arc_radius.map(|radius| radius * state.units_scale);
+                self.add_arc_move(state, &target, &offsets, radius, motion_mode == MotionMode::ArcClockwise)?
+            }
+        }
+        Ok(program_end)
+    }
+
+    /// Returns the work coordinate system index for G54 - G59.3 words
+    fn get_coordinate_system_word_index(word: &GCodeWord) -> Option<usize> {
+        let codes = [54.0, 55.0, 56.0, 57.0, 58.0, 59.0, 59.1, 59.2, 59.3];
+        codes.iter().position(|code| word.is_command('G', *code))
+    }
+
+    /// Converts the axis words of a block to a target in machine coordinates
+    fn get_target(&self, state: &AnalyzerState, axis_words: &[Option<f32>; MAX_AXES], machine_coordinates: bool) -> [f32; MAX_AXES] {
+        let mut target = state.position;
+        for (axis_index, axis_word) in axis_words.iter().enumerate() {
+            let mut value = match axis_word {
+                Some(value) => *value,
+                None => continue,
+            };
+
+            // rotary axes are not affected by units
+            if axis_index < 3 {
+                value *= state.units_scale;
+            }
+
+            // lathe diameter mode programs X as diameter
+            if axis_index == 0 && state.arc_mode == ArcMode::Diameter {
+                value /= 2.0;
+            }
+
+            target[axis_index] = if machine_coordinates {
+                value
+            } else if state.absolute_distance {
+                value + self.work_offsets[state.coordinate_system_index][axis_index]
+            } else {
+                state.position[axis_index] + value
+            };
+        }
+        target
+    }
+
+    /// Adds a straight move to the analysis
+    fn add_linear_move(&self, state: &mut AnalyzerState, target: &[f32; MAX_AXES], rapid: bool) -> Result<(), String> {
+        let mut delta = *target;
+        for (axis_index, value) in delta.iter_mut().enumerate() {
+            *value -= state.position[axis_index];
+        }
+        let length = delta.iter().map(|value| value * value).sum::<f32>().sqrt();
+        if length == 0.0 {
+            return Ok(());
+        }
+
+        // limit rate and acceleration by the slowest participating axis
+        let mut max_rate = f32::MAX;
+        let mut acceleration = f32::MAX;
+        for (axis_index, axis_delta) in delta.iter().enumerate() {
+            let direction = axis_delta.abs() / length;
+            if direction > 0.0 {
+                max_rate = max_rate.min(self.max_rates[axis_index] / direction);
+                acceleration = acceleration.min(self.accelerations[axis_index] / direction);
+            }
+        }
+
+        if rapid {
+            state.rapid_distance += length;
+        } else {
+            if state.feed_rate <= 0.0 {
+                return Err(String::from("Undefined feed rate"));
+            }
+            max_rate = max_rate.min(state.feed_rate);
+            state.cutting_distance += length;
+        }
+
+        state.runtime_seconds += JobAnalyzer::get_move_time(length, max_rate / 60.0, acceleration);
+        state.position = *target;
+        JobAnalyzer::include_position(state, &self.get_start_frame_position(target));
+        Ok(())
+    }
+
+    /// Adds a spindle synchronized move (G33) to the analysis
+    ///
+    /// The runtime is not estimated as the feed depends on the spindle speed
+    fn add_spindle_synchronized_move(&self, state: &mut AnalyzerState, target: &[f32; MAX_AXES]) {
+        let length = target.iter().zip(state.position.iter()).map(|(target, position)| (target - position).powi(2)).sum::<f32>().sqrt();
+        state.cutting_distance += length;
+        state.position = *target;
+        JobAnalyzer::include_position(state, &self.get_start_frame_position(target));
+    }
+
+    /// Adds a circular or helical move to the analysis
+    ///
+    /// Arcs are defined either by center offsets (IJK) or by radius (R)
+    fn add_arc_move(&self, state: &mut AnalyzerState, target: &[f32; MAX_AXES], offsets: &[Option<f32>; 3], radius: Option<f32>, clockwise: bool) -> Result<(), String> {
+        if state.feed_rate <= 0.0 {
+            return Err(String::from("Undefined feed rate"));
+        }
+
+        let (axis_0, axis_1, axis_linear) = state.plane;
+        let start = state.position;
+        let x = target[axis_0] - start[axis_0];
+        let y = target[axis_1] - start[axis_1];
+
+        // center offset relative to the start point
+        let (center_offset_0, center_offset_1) = match radius {
+            Some(radius) => {
+                let mut h_x2_div_d = 4.0 * radius * radius - x * x - y * y;
+                if h_x2_div_d < 0.0 || (x == 0.0 && y == 0.0) {
+                    return Err(format!("Invalid arc radius {}", radius));
+                }
+                h_x2_div_d = -h_x2_div_d.sqrt() / x.hypot(y);
+                if !clockwise {
+                    h_x2_div_d = -h_x2_div_d;
+                }
+                if radius < 0.0 {
+                    h_x2_div_d = -h_x2_div_d;
+                }
+                (0.5 * (x - y * h_x2_div_d), 0.5 * (y + x * h_x2_div_d))
+            }
+            None => {
+                let offset_0 = offsets[axis_0].unwrap_or(0.0);
+                let offset_1 = offsets[axis_1].unwrap_or(0.0);
+                if offset_0 == 0.0 && offset_1 == 0.0 {
+                    return Err(String::from("Arc requires a radius or center offset"));
+                }
+                (offset_0, offset_1)
+            }
+        };
+
+        let center_0 = start[axis_0] + center_offset_0;
+        let center_1 = start[axis_1] + center_offset_1;
+        let arc_radius = center_offset_0.hypot(center_offset_1);
+        let start_angle = (start[axis_1] - center_1).atan2(start[axis_0] - center_0);
+        let end_angle = (target[axis_1] - center_1).atan2(target[axis_0] - center_0);
+
+        // same start and end point results in a full circle
+        let mut sweep = match clockwise {
+            true => start_angle - end_angle,
+            false => end_angle - start_angle,
+        };
+        if sweep <= 1e-6 {
+            sweep += 2.0 * PI;
+        }
+
+        let linear_delta = target[axis_linear] - start[axis_linear];
+        let length = (arc_radius * sweep).hypot(linear_delta);
+
+        // the arc reaches its extremes at the end point and at each quadrant angle within the sweep
+        JobAnalyzer::include_position(state, &self.get_start_frame_position(target));
+        for quadrant in 0..4 {
+            let quadrant_angle = quadrant as f32 * FRAC_PI_2;
+            let travel = match clockwise {
+                true => (start_angle - quadrant_angle).rem_euclid(2.0 * PI),
+                false => (quadrant_angle - start_angle).rem_euclid(2.0 * PI),
+            };
+            if travel < sweep {
+                let mut point = *target;
+                point[axis_0] = center_0 + arc_radius * quadrant_angle.cos();
+                point[axis_1] = center_1 + arc_radius * quadrant_angle.sin();
+                point[axis_linear] = start[axis_linear] + linear_delta * travel / sweep;
+                JobAnalyzer::include_position(state, &self.get_start_frame_position(&point));
+            }
+        }
+
+        // arcs are limited by the slowest axis of the plane
+        let mut max_rate = state.feed_rate;
+        let mut acceleration = f32::MAX;
+        for axis_index in [axis_0, axis_1, axis_linear] {
+            max_rate = max_rate.min(self.max_rates[axis_index]);
+            acceleration = acceleration.min(self.accelerations[axis_index]);
+        }
+
+        state.cutting_distance += length;
+        state.runtime_seconds += JobAnalyzer::get_move_time(length, max_rate / 60.0, acceleration);
+        state.position = *target;
+        Ok(())
+    }
+
+    /// Adds a threading cycle (G76) from the current position to the final Z position
+    ///
+    /// The thread crest is offset by I from the drive line (current X), the root by the full depth K
+    /// further away. The tool returns to the start position, only a single pass is counted as cutting
+    /// distance as the count of passes depends on the depth degression.
+    fn add_threading_cycle(&self, state: &mut AnalyzerState, target: &[f32; MAX_AXES], offsets: &[Option<f32>; 3]) {
+        let start = state.position;
+        let crest_offset = offsets[0].unwrap_or(0.0);
+        let depth = offsets[2].unwrap_or(0.0).abs();
+        let root_offset = crest_offset + depth * crest_offset.signum();
+
+        for (x_offset, z) in [(crest_offset, start[2]), (root_offset, target[2]), (0.0, target[2])] {
+            let mut point = start;
+            point[0] += x_offset;
+            point[2] = z;
+            JobAnalyzer::include_position(state, &self.get_start_frame_position(&point));
+        }
+        state.cutting_distance += (target[2] - start[2]).abs();
+    }
+
+    /// Converts a machine position to the work coordinates of the job start
+    fn get_start_frame_position(&self, position: &[f32; MAX_AXES]) -> [f32; MAX_AXES] {
+        let start_offset = self.work_offsets[get_coordinate_system_index(&self.coordinate_system)];
+        let mut start_frame_position = *position;
+        for (axis_index, value) in start_frame_position.iter_mut().enumerate() {
+            *value -= start_offset[axis_index];
+        }
+        start_frame_position
+    }
+
+    fn include_position(state: &mut AnalyzerState, position: &[f32; MAX_AXES]) {
+        match state.bounds.as_mut() {
+            Some(bounds) => bounds.include(position),
+            None => state.bounds = Some(BoundingBox::from_position(position)),
+        }
+    }
+
+    /// Returns the duration in seconds of a trapezoidal move starting and ending at rest
+    ///
+    /// Speed is in mm/sec and acceleration in mm/sec^2
+    fn get_move_time(length: f32, speed: f32, acceleration: f32) -> f32 {
+        if speed <= 0.0 || acceleration <= 0.0 {
+            return 0.0;
+        }
+        let acceleration_distance = speed * speed / (2.0 * acceleration);
+        if 2.0 * acceleration_distance >= length {
+            // triangular profile, top speed is never reached
+            return 2.0 * (length / acceleration).sqrt();
+        }
+        2.0 * speed / acceleration + (length - 2.0 * acceleration_distance) / speed
+    }
+}
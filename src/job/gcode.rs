@@ -0,0 +1,115 @@
+use std::result::Result;
+
+use crate::device::command::gcode::PROGRAM_DEMARCATION;
+
+const COMMENT_START: char = '(';
+const COMMENT_END: char = ')';
+const LINE_COMMENT: char = ';';
+const BLOCK_DELETE: char = '/';
+
+/// Single word of a gcode block e.g. "G1" or "X12.5"
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GCodeWord {
+    letter: char,
+    value: f32,
+}
+
+impl GCodeWord {
+
+    /// Creates a new word from letter and value
+    pub fn new(letter: char, value: f32) -> Self {
+        GCodeWord { letter: letter.to_ascii_uppercase(), value }
+    }
+
+    /// Indicates if the word is the given command e.g. ('G', 38.2)
+    pub fn is_command(&self, letter: char, code: f32) -> bool {
+        self.letter == letter && (self.value - code).abs() < 0.001
+    }
+
+    /// Get the word's letter (always upper case)
+    pub fn letter(&self) -> char {
+        self.letter
+    }
+
+    /// Get the word's value
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+}
+
+/// Removes comments, block delete and program demarcation from a gcode line
+///
+/// # Examples
+/// ```
+/// use grbli::job::gcode::strip_gcode_comments;
+///
+/// assert_eq!(strip_gcode_comments("G1 X1 (move) Y2 ; end"), "G1 X1  Y2 ");
+/// ```
+pub fn strip_gcode_comments(line: &str) -> String {
+    let mut block = String::new();
+    let mut in_comment = false;
+    for character in line.chars() {
+        if in_comment {
+            in_comment = character != COMMENT_END;
+        } else if character == COMMENT_START {
+            in_comment = true;
+        } else if character == LINE_COMMENT {
+            break;
+        } else {
+            block.push(character);
+        }
+    }
+
+    let trimmed = block.trim_start();
+    if trimmed.starts_with(PROGRAM_DEMARCATION) {
+        return String::new();
+    }
+    match trimmed.strip_prefix(BLOCK_DELETE) {
+        Some(remaining) => remaining.to_string(),
+        None => block,
+    }
+}
+
+/// Parses a gcode line and returns all words in order of appearance
+///
+/// Comments are removed and letters are converted to upper case
+///
+/// # Errors
+/// Returns an error if a word has no letter or no valid number
+///
+/// # Examples
+/// ```
+/// use grbli::job::gcode::parse_gcode_line;
+///
+/// let words = parse_gcode_line("g1 x-1.5 F200 (cut)").unwrap();
+/// assert_eq!(words.len(), 3);
+/// assert!(words[0].is_command('G', 1.0));
+/// assert_eq!(words[1].value(), -1.5);
+/// ```
+pub fn parse_gcode_line(line: &str) -> Result<Vec<GCodeWord>, String> {
+    let block: Vec<char> = strip_gcode_comments(line).chars().filter(|c| !c.is_whitespace()).collect();
+    let mut words: Vec<GCodeWord> = Vec::new();
+
+    // each word is a letter followed by a signed decimal number
+    let mut index = 0usize;
+    while index < block.len() {
+        let letter = block[index];
+        if !letter.is_ascii_alphabetic() {
+            return Err(format!("Invalid word letter \"{}\" in \"{}\"", letter, line));
+        }
+        index += 1;
+
+        let value_start = index;
+        while index < block.len() && (block[index].is_ascii_digit() || matches!(block[index], '.' | '-' | '+')) {
+            index += 1;
+        }
+
+        let value_string: String = block[value_start..index].iter().collect();
+        let value = match value_string.parse::<f32>() {
+            Ok(value) => value,
+            Err(_) => return Err(format!("Cannot read value of word \"{}\" in \"{}\"", letter, line)),
+        };
+        words.push(GCodeWord::new(letter, value));
+    }
+    Ok(words)
+}
@@ -0,0 +1,178 @@
+use std::{fmt, result::Result};
+
+use crate::device::response::setting::DeviceSettings;
+
+use super::gcode::parse_gcode_line;
+
+/// Laser mode setting ($32)
+const LASER_MODE_SETTING: u32 = 32;
+
+/// Maximum spindle speed setting ($30), S value of full laser power
+const MAX_SPINDLE_SPEED_SETTING: u32 = 30;
+
+/// Minimum spindle speed setting ($31), S value of the lowest laser power
+const MIN_SPINDLE_SPEED_SETTING: u32 = 31;
+
+/// grbl default of $30
+const DEFAULT_MAX_SPINDLE_SPEED: f32 = 1000.0;
+
+/// Possible mistake of a job run in laser mode
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LaserWarning {
+    /// Rapid move (G0) programs a power (S > 0) in the given line, grbl turns the laser off while G0
+    RapidWithPower(usize),
+
+    /// Constant power (M3) is enabled in the given line, M4 reduces the power while accelerating
+    ConstantPower(usize),
+
+    /// Cutting moves start in the given line without an enabled laser (M3/M4)
+    LaserOff(usize),
+}
+
+/// Range of S values between the minimum ($31) and maximum ($30) spindle speed
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LaserPowerRange {
+    min: f32,
+    max: f32,
+}
+
+impl fmt::Display for LaserWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LaserWarning::RapidWithPower(line) => write!(f, "Line {}: rapid move with laser power", line),
+            LaserWarning::ConstantPower(line) => write!(f, "Line {}: constant power (M3) instead of dynamic power (M4)", line),
+            LaserWarning::LaserOff(line) => write!(f, "Line {}: cutting move without enabled laser", line),
+        }
+    }
+}
+
+/// Joins all warnings to a single report e.g. "Line 2: rapid move with laser power, Line 5: ..."
+pub fn format_laser_warnings(warnings: &[LaserWarning]) -> String {
+    warnings.iter().map(|warning| warning.to_string()).collect::<Vec<String>>().join(", ")
+}
+
+/// Indicates if laser mode is enabled ($32=1)
+pub fn is_laser_mode(settings: &DeviceSettings) -> bool {
+    settings.get_setting_f32(&LASER_MODE_SETTING).unwrap_or(0.0) as i32 == 1
+}
+
+impl LaserPowerRange {
+
+    pub fn new(min: f32, max: f32) -> Self {
+        LaserPowerRange { min, max }
+    }
+
+    /// Creates the range from the spindle speed settings ($30/$31)
+    ///
+    /// Missing settings fall back to the grbl defaults
+    pub fn from_settings(settings: &DeviceSettings) -> Self {
+        LaserPowerRange {
+            min: settings.get_setting_f32(&MIN_SPINDLE_SPEED_SETTING).unwrap_or(0.0),
+            max: settings.get_setting_f32(&MAX_SPINDLE_SPEED_SETTING).unwrap_or(DEFAULT_MAX_SPINDLE_SPEED),
+        }
+    }
+
+    /// Returns the S value of the power in percent (0% - 100%)
+    ///
+    /// grbl switches the laser off at S0, every other power is at least the minimum
+    ///
+    /// # Examples
+    /// ```
+    /// use grbli::job::laser::LaserPowerRange;
+    ///
+    /// let range = LaserPowerRange::new(100.0, 1000.0);
+    /// assert_eq!(range.get_spindle_speed(50.0), 550.0);
+    /// assert_eq!(range.get_spindle_speed(0.0), 0.0);
+    /// ```
+    pub fn get_spindle_speed(&self, percentage: f32) -> f32 {
+        let percentage = percentage.clamp(0.0, 100.0);
+        if percentage == 0.0 {
+            return 0.0;
+        }
+        self.min + (self.max - self.min) * percentage / 100.0
+    }
+
+    /// Returns the power in percent of the S value
+    pub fn get_percentage(&self, spindle_speed: f32) -> f32 {
+        if spindle_speed <= 0.0 || self.max <= self.min {
+            return 0.0;
+        }
+        ((spindle_speed - self.min) / (self.max - self.min) * 100.0).clamp(0.0, 100.0)
+    }
+
+    /// Get the S value of the lowest power ($31)
+    pub fn min(&self) -> f32 {
+        self.min
+    }
+
+    /// Get the S value of full power ($30)
+    pub fn max(&self) -> f32 {
+        self.max
+    }
+}
+
+/// Checks a program for common laser mistakes
+///
+/// Each cutting sequence without laser is reported once.
+///
+/// # Examples
+/// ```
+/// use grbli::job::laser::{check_laser_job, LaserWarning};
+///
+/// let warnings = check_laser_job("M3 S500\nG1 X10 F1000\nG0 X0 S500").unwrap();
+/// assert_eq!(warnings, vec![LaserWarning::ConstantPower(1), LaserWarning::RapidWithPower(3)]);
+/// ```
+///
+/// # Errors
+/// Returns an error with the line number if a line cannot be parsed
+pub fn check_laser_job(program: &str) -> Result<Vec<LaserWarning>, String> {
+    let mut warnings = Vec::new();
+    let mut laser_enabled = false;
+
+    // G0 is the default motion mode after power up
+    let mut rapid = true;
+    let mut laser_off_reported = false;
+
+    for (line_index, line) in program.lines().enumerate() {
+        let line_number = line_index + 1;
+        let words = match parse_gcode_line(line) {
+            Ok(words) => words,
+            Err(err) => return Err(format!("Line {}: {}", line_number, err)),
+        };
+
+        let mut power = None;
+        let mut has_axis_words = false;
+        for word in &words {
+            match word.letter() {
+                'G' if word.is_command('G', 0.0) => rapid = true,
+                'G' if word.is_command('G', 1.0) || word.is_command('G', 2.0) || word.is_command('G', 3.0) => rapid = false,
+                'M' if word.is_command('M', 3.0) => {
+                    laser_enabled = true;
+                    warnings.push(LaserWarning::ConstantPower(line_number));
+                }
+                'M' if word.is_command('M', 4.0) => laser_enabled = true,
+                'M' if word.is_command('M', 5.0) => laser_enabled = false,
+                'S' => power = Some(word.value()),
+                'X' | 'Y' | 'Z' => has_axis_words = true,
+                _ => (),
+            }
+        }
+
+        if !has_axis_words {
+            continue;
+        }
+        if rapid {
+            if power.unwrap_or(0.0) > 0.0 {
+                warnings.push(LaserWarning::RapidWithPower(line_number));
+            }
+        } else if !laser_enabled {
+            if !laser_off_reported {
+                warnings.push(LaserWarning::LaserOff(line_number));
+                laser_off_reported = true;
+            }
+        } else {
+            laser_off_reported = false;
+        }
+    }
+    Ok(warnings)
+}
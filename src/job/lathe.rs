@@ -0,0 +1,140 @@
+use std::result::Result;
+
+use crate::device::response::state::modes::ArcMode;
+
+/// Exponent of the pass depths, 2.0 removes the same area with each pass
+const DEFAULT_DEGRESSION: f32 = 2.0;
+
+/// Largest exponent of the pass depths, higher values only add passes near the full depth
+const MAX_DEGRESSION: f32 = 4.0;
+
+/// Maximum count of passes including spring passes
+const MAX_THREADING_PASSES: usize = 200;
+
+/// Single point threading with spindle synchronized passes (G33)
+///
+/// Pass depths follow `first cut depth * pass^(1 / degression)` until the full depth is reached,
+/// spring passes repeat the full depth.
+///
+/// # Examples
+/// ```
+/// use grbli::job::lathe::ThreadingPlan;
+///
+/// let plan = ThreadingPlan::new(1.5, 0.9, 0.3).unwrap();
+/// assert_eq!(plan.pass_depths().unwrap().last(), Some(&0.9));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThreadingPlan {
+    /// Distance per spindle revolution in mm
+    pitch: f32,
+
+    /// Full thread depth (radius) in mm
+    depth: f32,
+    first_cut_depth: f32,
+    degression: f32,
+    spring_passes: usize,
+}
+
+impl ThreadingPlan {
+
+    /// Creates a plan without spring passes
+    ///
+    /// # Errors
+    /// Returns an error if pitch, depth or first cut depth are not positive
+    pub fn new(pitch: f32, depth: f32, first_cut_depth: f32) -> Result<Self, String> {
+        if pitch <= 0.0 || depth <= 0.0 || first_cut_depth <= 0.0 {
+            return Err(format!("Invalid threading pitch {}, depth {} or first cut depth {}", pitch, depth, first_cut_depth));
+        }
+        Ok(ThreadingPlan { pitch, depth, first_cut_depth, degression: DEFAULT_DEGRESSION, spring_passes: 0 })
+    }
+
+    /// Set the exponent of the pass depths (1.0 cuts the same depth with each pass)
+    ///
+    /// # Errors
+    /// Returns an error if the degression is not within 1.0 to 4.0
+    pub fn set_degression(&mut self, degression: f32) -> Result<(), String> {
+        if !(1.0..=MAX_DEGRESSION).contains(&degression) {
+            return Err(format!("Invalid threading degression {}", degression));
+        }
+        self.degression = degression;
+        Ok(())
+    }
+
+    pub fn set_spring_passes(&mut self, spring_passes: usize) {
+        self.spring_passes = spring_passes;
+    }
+
+    /// Get the total depth of each pass including spring passes
+    ///
+    /// # Errors
+    /// Returns an error if the plan requires more than 200 passes
+    pub fn pass_depths(&self) -> Result<Vec<f32>, String> {
+        if self.spring_passes >= MAX_THREADING_PASSES {
+            return Err(format!("Threading requires more than {} passes", MAX_THREADING_PASSES));
+        }
+        let cut_passes = MAX_THREADING_PASSES - self.spring_passes - 1;
+        let mut depths = Vec::new();
+        let mut pass = 1;
+        loop {
+            let depth = self.first_cut_depth * (pass as f32).powf(1.0 / self.degression);
+            if depth >= self.depth {
+                break;
+            }
+            if depths.len() >= cut_passes {
+                return Err(format!("Threading requires more than {} passes", MAX_THREADING_PASSES));
+            }
+            depths.push(depth);
+            pass += 1;
+        }
+        for _ in 0..=self.spring_passes {
+            depths.push(self.depth);
+        }
+        Ok(depths)
+    }
+
+    /// Returns the program cutting the thread from the start to the end Z position
+    ///
+    /// The drive line is the X radius of the thread crest, external threads are cut towards the axis.
+    /// X words are written as diameter if the arc mode is [`ArcMode::Diameter`] (G7).
+    ///
+    /// # Errors
+    /// Returns an error if the plan requires more than 200 passes
+    ///
+    /// # Examples
+    /// ```
+    /// use grbli::{device::response::state::modes::ArcMode, job::lathe::ThreadingPlan};
+    ///
+    /// let plan = ThreadingPlan::new(1.0, 0.5, 0.5).unwrap();
+    /// let program = plan.get_program(10.0, 2.0, -20.0, true, ArcMode::Radius).unwrap();
+    /// assert_eq!(program, "G0 X10.000 Z2.000\nG0 X9.500\nG33 Z-20.000 K1.000\nG0 X10.000\nG0 Z2.000\n");
+    /// ```
+    pub fn get_program(&self, drive_line: f32, start_z: f32, end_z: f32, external: bool, arc_mode: ArcMode) -> Result<String, String> {
+        let x_scale = match arc_mode {
+            ArcMode::Diameter => 2.0,
+            ArcMode::Radius => 1.0,
+        };
+        let direction = match external {
+            true => -1.0,
+            false => 1.0,
+        };
+
+        let mut program = format!("G0 X{:.3} Z{:.3}\n", drive_line * x_scale, start_z);
+        for depth in self.pass_depths()? {
+            program.push_str(&format!("G0 X{:.3}\n", (drive_line + direction * depth) * x_scale));
+            program.push_str(&format!("G33 Z{:.3} K{:.3}\n", end_z, self.pitch));
+            program.push_str(&format!("G0 X{:.3}\n", drive_line * x_scale));
+            program.push_str(&format!("G0 Z{:.3}\n", start_z));
+        }
+        Ok(program)
+    }
+
+    /// Get the distance per spindle revolution in mm
+    pub fn pitch(&self) -> f32 {
+        self.pitch
+    }
+
+    /// Get the full thread depth in mm
+    pub fn depth(&self) -> f32 {
+        self.depth
+    }
+}
@@ -0,0 +1,210 @@
+use std::{fmt, result::Result};
+
+use crate::device::{response::state::compile::CompileOption, util::axis::{get_axis_by_index, get_axis_index, get_axis_name, is_axis_enabled, Axis, MAX_AXES}, DeviceInfo};
+
+use super::analyzer::BoundingBox;
+
+/// First max travel setting ($130 = X max travel in mm)
+const MAX_TRAVEL_SETTING: u32 = 130;
+
+/// Homing cycle enable, bit 0 ($22)
+const HOMING_ENABLE_SETTING: u32 = 22;
+
+/// Homing direction invert mask ($23)
+const HOMING_DIRECTION_SETTING: u32 = 23;
+
+/// Tolerance for rounding errors of reported positions in mm
+const TRAVEL_TOLERANCE: f32 = 0.001;
+
+/// Reason why a position is outside of the machine travel
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SoftLimitViolation {
+    /// Axis is not homed, therefore the machine position is unknown
+    NotHomed(Axis),
+
+    /// Axis falls below the travel minimum by the given distance in mm
+    BelowMinimum(Axis, f32),
+
+    /// Axis exceeds the travel maximum by the given distance in mm
+    AboveMaximum(Axis, f32),
+}
+
+/// Machine travel of all configured axes in machine coordinates
+#[derive(Clone, Debug, PartialEq)]
+pub struct TravelLimits {
+    min: [f32; MAX_AXES],
+    max: [f32; MAX_AXES],
+
+    /// Axes with a configured max travel
+    axes_mask: i32,
+
+    /// Machine positions are only referenced to the travel if homing is enabled
+    homing_enabled: bool,
+
+    /// Homing state was reported ("H:"), classic grbl never reports it
+    homing_state_known: bool,
+
+    /// Axes reported as homed
+    homed_axes_mask: i32,
+}
+
+impl fmt::Display for SoftLimitViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SoftLimitViolation::NotHomed(axis) => write!(f, "{} is not homed", get_axis_name(axis)),
+            SoftLimitViolation::BelowMinimum(axis, distance) => write!(f, "{} exceeds minimum travel by {:.3} mm", get_axis_name(axis), distance),
+            SoftLimitViolation::AboveMaximum(axis, distance) => write!(f, "{} exceeds maximum travel by {:.3} mm", get_axis_name(axis), distance),
+        }
+    }
+}
+
+/// Joins all violations to a single report e.g. "X exceeds maximum travel by 2.000 mm, Z is not homed"
+pub fn format_soft_limit_violations(violations: &[SoftLimitViolation]) -> String {
+    violations.iter().map(|violation| violation.to_string()).collect::<Vec<String>>().join(", ")
+}
+
+impl TravelLimits {
+
+    /// Creates travel limits for axes with a max travel setting ($130+)
+    ///
+    /// grbl places machine zero at the homing switch, the travel of an axis is
+    /// \[-max travel, 0\] unless the homing origin is forced (compile option "Z") and
+    /// the axis homes towards negative ($23), which results in \[0, max travel\].
+    /// Homed axes are read from the last reported homing state.
+    /// Without homing ($22) the machine position is arbitrary and no axis is checked.
+    pub fn from_device_info(device_info: &DeviceInfo) -> Self {
+        let settings = device_info.settings();
+        let homing_direction_mask = settings.get_setting_f32(&HOMING_DIRECTION_SETTING).unwrap_or(0.0) as i32;
+        let force_origin = match device_info.firmware_info().compile_options() {
+            Some(compile_options) => compile_options.options().contains(&CompileOption::HomingForceOriginEnabled),
+            None => false,
+        };
+
+        let homing_enabled = settings.get_setting_f32(&HOMING_ENABLE_SETTING).unwrap_or(0.0) as i32 & 1 == 1;
+
+        let mut limits = TravelLimits {
+            min: [0.0; MAX_AXES],
+            max: [0.0; MAX_AXES],
+            axes_mask: 0,
+            homing_enabled,
+            homing_state_known: false,
+            homed_axes_mask: 0,
+        };
+
+        for axis_index in 0..MAX_AXES {
+            let max_travel = match settings.get_setting_f32(&(MAX_TRAVEL_SETTING + axis_index as u32)) {
+                Some(max_travel) => max_travel.abs(),
+                None => continue,
+            };
+            let axis = get_axis_by_index(axis_index).unwrap();
+            limits.axes_mask |= 1 << axis_index;
+
+            if force_origin && is_axis_enabled(homing_direction_mask, &axis) {
+                limits.max[axis_index] = max_travel;
+            } else {
+                limits.min[axis_index] = -max_travel;
+            }
+        }
+
+        if let Some(homing_state) = device_info.machine_info().and_then(|info| info.homing_state()) {
+            limits.homing_state_known = true;
+            if homing_state.is_homed() {
+                for axis in homing_state.homed_axes() {
+                    limits.homed_axes_mask |= 1 << get_axis_index(axis);
+                }
+            }
+        }
+        limits
+    }
+
+    /// Get the travel minimum of the axis in machine coordinates
+    pub fn min(&self, axis: &Axis) -> Option<f32> {
+        match self.has_axis(axis) {
+            true => Some(self.min[get_axis_index(axis)]),
+            false => None,
+        }
+    }
+
+    /// Get the travel maximum of the axis in machine coordinates
+    pub fn max(&self, axis: &Axis) -> Option<f32> {
+        match self.has_axis(axis) {
+            true => Some(self.max[get_axis_index(axis)]),
+            false => None,
+        }
+    }
+
+    /// Indicates if a max travel is configured for the axis
+    pub fn has_axis(&self, axis: &Axis) -> bool {
+        is_axis_enabled(self.axes_mask, axis)
+    }
+
+    /// Indicates if the axis was reported as homed
+    pub fn is_homed(&self, axis: &Axis) -> bool {
+        is_axis_enabled(self.homed_axes_mask, axis)
+    }
+
+    /// Indicates if the homing cycle is enabled ($22)
+    pub fn is_homing_enabled(&self) -> bool {
+        self.homing_enabled
+    }
+
+    /// Indicates if the device reported its homing state ("H:")
+    ///
+    /// Without it axes are expected to be homed, grbl locks the machine until homing or unlock ($X)
+    pub fn is_homing_state_known(&self) -> bool {
+        self.homing_state_known
+    }
+
+    /// Checks a job bounding box in machine coordinates against the machine travel
+    ///
+    /// # Errors
+    /// Returns all violations if any axis leaves the machine travel or is reported as not homed
+    pub fn check_bounds(&self, bounds: &BoundingBox) -> Result<(), Vec<SoftLimitViolation>> {
+        let mut violations = Vec::new();
+        for axis_index in 0..MAX_AXES {
+            let axis = get_axis_by_index(axis_index).unwrap();
+            self.check_axis(&axis, bounds.min(&axis), bounds.max(&axis), &mut violations);
+        }
+
+        match violations.is_empty() {
+            true => Ok(()),
+            false => Err(violations),
+        }
+    }
+
+    /// Checks a target e.g. of a jog motion in machine coordinates
+    ///
+    /// Only the given axes are checked
+    ///
+    /// # Errors
+    /// Returns all violations if any axis leaves the machine travel or is reported as not homed
+    pub fn check_target(&self, target: &[(Axis, f32)]) -> Result<(), Vec<SoftLimitViolation>> {
+        let mut violations = Vec::new();
+        for (axis, position) in target {
+            self.check_axis(axis, *position, *position, &mut violations);
+        }
+
+        match violations.is_empty() {
+            true => Ok(()),
+            false => Err(violations),
+        }
+    }
+
+    fn check_axis(&self, axis: &Axis, min: f32, max: f32, violations: &mut Vec<SoftLimitViolation>) {
+        if !self.has_axis(axis) || !self.homing_enabled {
+            return;
+        }
+        if self.homing_state_known && !self.is_homed(axis) {
+            violations.push(SoftLimitViolation::NotHomed(*axis));
+            return;
+        }
+
+        let axis_index = get_axis_index(axis);
+        if min < self.min[axis_index] - TRAVEL_TOLERANCE {
+            violations.push(SoftLimitViolation::BelowMinimum(*axis, self.min[axis_index] - min));
+        }
+        if max > self.max[axis_index] + TRAVEL_TOLERANCE {
+            violations.push(SoftLimitViolation::AboveMaximum(*axis, max - self.max[axis_index]));
+        }
+    }
+}
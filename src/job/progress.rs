@@ -0,0 +1,166 @@
+use std::result::Result;
+
+/// State of a streamed job
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum JobState {
+    Running,
+    Completed,
+    Cancelled,
+
+    /// Job stopped with the given reason
+    Failed(String),
+}
+
+/// Progress of a job streamed line by line to the device
+///
+/// Each line is sent after the previous line was acknowledged by "ok" or "error:\<code\>"
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JobProgress {
+    total_lines: usize,
+    sent_lines: usize,
+    acknowledged_lines: usize,
+    state: JobState,
+}
+
+impl JobProgress {
+
+    /// Creates a running job with the given count of lines
+    pub fn new(total_lines: usize) -> Self {
+        let state = match total_lines {
+            0 => JobState::Completed,
+            _ => JobState::Running,
+        };
+        JobProgress { total_lines, sent_lines: 0, acknowledged_lines: 0, state }
+    }
+
+    /// Marks the next line as sent
+    pub fn put_line_sent(&mut self) {
+        self.sent_lines += 1;
+    }
+
+    /// Stores the acknowledgement of the oldest unacknowledged line
+    ///
+    /// An error response stops the job
+    pub fn put_response_status(&mut self, status: &Result<(), i32>) {
+        if self.state != JobState::Running || self.acknowledged_lines >= self.sent_lines {
+            return;
+        }
+
+        self.acknowledged_lines += 1;
+        if let Err(code) = status {
+            self.state = JobState::Failed(format!("Line {} failed with error:{}", self.acknowledged_lines, code));
+        } else if self.acknowledged_lines == self.total_lines {
+            self.state = JobState::Completed;
+        }
+    }
+
+    /// Stops a running job
+    pub fn cancel(&mut self) {
+        if self.state == JobState::Running {
+            self.state = JobState::Cancelled;
+        }
+    }
+
+    /// Stops a running job with the given reason
+    pub fn fail(&mut self, reason: &str) {
+        if self.state == JobState::Running {
+            self.state = JobState::Failed(reason.to_string());
+        }
+    }
+
+    /// Indicates if the job is running and all sent lines are acknowledged
+    pub fn is_ready_for_next_line(&self) -> bool {
+        self.state == JobState::Running && self.sent_lines == self.acknowledged_lines && self.sent_lines < self.total_lines
+    }
+
+    /// Indicates if the job is running and a sent line is not acknowledged yet
+    pub fn is_awaiting_acknowledgement(&self) -> bool {
+        self.state == JobState::Running && self.sent_lines > self.acknowledged_lines
+    }
+
+    /// Indicates if the job is still running
+    pub fn is_running(&self) -> bool {
+        self.state == JobState::Running
+    }
+
+    /// Get the job's total line count
+    pub fn total_lines(&self) -> usize {
+        self.total_lines
+    }
+
+    /// Get the job's count of sent lines
+    pub fn sent_lines(&self) -> usize {
+        self.sent_lines
+    }
+
+    /// Get the job's count of acknowledged lines
+    pub fn acknowledged_lines(&self) -> usize {
+        self.acknowledged_lines
+    }
+
+    /// Get a reference to the job's state
+    pub fn state(&self) -> &JobState {
+        &self.state
+    }
+}
+
+/// Progress of a file uploaded to the sd card of the device
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UploadProgress {
+    file_name: String,
+    total_bytes: usize,
+    sent_bytes: usize,
+    state: JobState,
+}
+
+impl UploadProgress {
+
+    /// Creates a running upload of the file with the given size
+    pub fn new(file_name: &str, total_bytes: usize) -> Self {
+        UploadProgress { file_name: file_name.to_string(), total_bytes, sent_bytes: 0, state: JobState::Running }
+    }
+
+    /// Stores the count of bytes acknowledged by the device
+    pub fn put_bytes_sent(&mut self, sent_bytes: usize) {
+        self.sent_bytes = sent_bytes.min(self.total_bytes);
+    }
+
+    /// Marks a running upload as completed
+    pub fn complete(&mut self) {
+        if self.state == JobState::Running {
+            self.state = JobState::Completed;
+        }
+    }
+
+    /// Stops a running upload with the given reason
+    pub fn fail(&mut self, reason: &str) {
+        if self.state == JobState::Running {
+            self.state = JobState::Failed(reason.to_string());
+        }
+    }
+
+    /// Indicates if the upload is still running
+    pub fn is_running(&self) -> bool {
+        self.state == JobState::Running
+    }
+
+    /// Get a reference to the uploaded file name
+    pub fn file_name(&self) -> &str {
+        self.file_name.as_ref()
+    }
+
+    /// Get the size of the file in bytes
+    pub fn total_bytes(&self) -> usize {
+        self.total_bytes
+    }
+
+    /// Get the count of bytes acknowledged by the device
+    pub fn sent_bytes(&self) -> usize {
+        self.sent_bytes
+    }
+
+    /// Get a reference to the upload's state
+    pub fn state(&self) -> &JobState {
+        &self.state
+    }
+}
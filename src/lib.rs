@@ -1,3 +1,4 @@
 pub mod device;
 pub mod endpoint;
+pub mod job;
 pub mod service;
\ No newline at end of file
@@ -1,11 +1,39 @@
-use std::{collections::HashMap, sync::{Arc, Mutex, mpsc::{Sender, self, Receiver}}, thread::{JoinHandle, self}, time::Duration};
+use std::{collections::{HashMap, VecDeque}, sync::{Arc, Mutex, mpsc::{Sender, self, Receiver}}, thread::{JoinHandle, self}, time::{Duration, Instant}};
 
 use log::{error, debug};
 
-use crate::{device::{DeviceInfo, response::read_response}, endpoint::{serial::SerialEndpoint, Endpoint}};
+use crate::{device::{DeviceInfo, response::firmware::capabilities::Capability, command::{self, realtime::{self, extended::FULL_STATUS_REPORT}, util::get_restore_modal_state_command}, event::DeviceEvent, response::{read_response, state::machine::state::MachineStateName}}, endpoint::{serial::SerialEndpoint, ymodem::YmodemSender, Endpoint}};
+
+mod accessory;
+mod coordinate;
+mod homing;
+mod io;
+mod job;
+mod laser;
+mod lathe;
+mod maintenance;
+mod metadata;
+mod overrides;
+mod pendant;
+mod probe;
+mod sdcard;
+mod startup;
+mod tool;
+
+/// Interval between status report requests while waiting for a device state
+const STATUS_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Dwell which is acknowledged after all previous motions are completed
+const SYNC_COMMAND: &str = "G4 P0\r";
+
+/// Maximum time until the device has to report its parser state ("$G")
+const PARSER_STATE_TIMEOUT: Duration = Duration::from_secs(5);
 
 type DeviceDescription = (String, DeviceEndpointType);
 
+/// File name and content of a file uploaded to the sd card
+type UploadRequest = (String, Vec<u8>);
+
 #[derive(Clone, Copy, Debug)]
 pub enum DeviceEndpointType {
     Serial,
@@ -17,7 +45,9 @@ struct DeviceHandle {
     device_info: Arc<Mutex<DeviceInfo>>,
     read_thread: Option<JoinHandle<()>>,
     tx_close: Option<Sender<bool>>,
-    tx_write: Option<Sender<String>>,
+    tx_write: Option<Sender<Vec<u8>>>,
+    tx_job: Option<Sender<Vec<String>>>,
+    tx_upload: Option<Sender<UploadRequest>>,
 }
 
 pub struct DeviceService {
@@ -33,7 +63,9 @@ impl DeviceHandle {
         };
 
         let (tx_close, rx_close) = mpsc::channel();
-        let (tx_write, rx_write): (Sender<String>, Receiver<String>) = mpsc::channel();
+        let (tx_write, rx_write): (Sender<Vec<u8>>, Receiver<Vec<u8>>) = mpsc::channel();
+        let (tx_job, rx_job): (Sender<Vec<String>>, Receiver<Vec<String>>) = mpsc::channel();
+        let (tx_upload, rx_upload): (Sender<UploadRequest>, Receiver<UploadRequest>) = mpsc::channel();
 
 
         let device_info = Arc::new(Mutex::new(device_info));
@@ -51,6 +83,9 @@ impl DeviceHandle {
                 return;
             }
 
+            // lines of the active job which are not sent yet
+            let mut job_lines: VecDeque<String> = VecDeque::new();
+
             // run until close signal received
             // check close -> read serial -> write serial -> check close -> ...
             loop {
@@ -71,13 +106,79 @@ impl DeviceHandle {
                             error!("{}: {}", device_description.0, err)
                         }
                     }
+
+                    // the tool number of a requested tool change is only part of the gcode state
+                    if current_device_info.is_ready_for_internal_command() && current_device_info.take_parser_state_request() {
+                        match endpoint.write(command::state::GET_PARSER_STATE) {
+                            Ok(_) => current_device_info.put_internal_command(),
+                            Err(err) => error!("{}: {}", device_description.0, err),
+                        }
+                    }
+
+                    // the pendant may have changed the machine state while in control
+                    if current_device_info.is_ready_for_internal_command() && current_device_info.take_resync_request() {
+                        let resync = endpoint.write_bytes(&[FULL_STATUS_REPORT])
+                            .and_then(|_| endpoint.write(command::state::GET_PARSER_STATE));
+                        match resync {
+                            Ok(_) => current_device_info.put_internal_command(),
+                            Err(err) => error!("{}: {}", device_description.0, err),
+                        }
+                    }
                 }
                 
                 // read the next command and write it to the device endpoint 
                 if let Ok(msg) = rx_write.recv_timeout(Duration::from_millis(1)) {
-                    debug!("Write: {}", &msg);
-                    if let Err(err) = endpoint.write(msg.as_str()) {
-                        error!("{}: {}", device_description.0, err)
+                    debug!("Write: {}", String::from_utf8_lossy(&msg));
+                    match endpoint.write_bytes(&msg) {
+                        // lines read before the dump command was written are no file content
+                        Ok(_) if msg.starts_with(command::sdcard::DUMP.as_bytes()) => device_info.lock().unwrap().start_sd_card_dump(),
+                        Ok(_) => (),
+                        Err(err) => error!("{}: {}", device_description.0, err),
+                    }
+                }
+
+                // stream the next job line after the previous line was acknowledged
+                if let Ok(lines) = rx_job.try_recv() {
+                    job_lines = VecDeque::from(lines);
+                }
+                if !job_lines.is_empty() {
+                    let mut current_device_info = device_info.lock().unwrap();
+                    let internal_response_pending = current_device_info.is_internal_response_pending();
+                    match current_device_info.job_progress_mut() {
+                        Some(progress) if progress.is_running() => {
+                            if progress.is_ready_for_next_line() && !internal_response_pending {
+                                let line = job_lines.pop_front().unwrap();
+                                debug!("Write job line: {}", &line);
+                                if let Err(err) = endpoint.write(line.as_str()) {
+                                    error!("{}: {}", device_description.0, err);
+                                    progress.fail(&err);
+                                }
+                                progress.put_line_sent();
+                            }
+                        }
+                        _ => job_lines.clear(),
+                    }
+                }
+
+                // the upload takes over the endpoint until the file is transferred
+                if let Ok((file_name, data)) = rx_upload.try_recv() {
+                    let upload_result = endpoint.set_raw_mode(true).and_then(|_| {
+                        let send_result = YmodemSender::new(&file_name, data).send(endpoint.as_mut(), |sent_bytes, _| {
+                            if let Some(progress) = device_info.lock().unwrap().upload_progress_mut() {
+                                progress.put_bytes_sent(sent_bytes);
+                            }
+                        });
+                        endpoint.set_raw_mode(false).and(send_result)
+                    });
+
+                    if let Some(progress) = device_info.lock().unwrap().upload_progress_mut() {
+                        match upload_result {
+                            Ok(_) => progress.complete(),
+                            Err(err) => {
+                                error!("{}: {}", device_description.0, err);
+                                progress.fail(&err);
+                            }
+                        }
                     }
                 }
 
@@ -91,6 +192,8 @@ impl DeviceHandle {
             read_thread,
             tx_close: Some(tx_close),
             tx_write: Some(tx_write),
+            tx_job: Some(tx_job),
+            tx_upload: Some(tx_upload),
         })
     }
 
@@ -115,9 +218,13 @@ impl DeviceHandle {
     }
 
     pub fn write(&self, command: &str) -> Result<(), String> {
+        self.write_bytes(command.as_bytes())
+    }
+
+    pub fn write_bytes(&self, bytes: &[u8]) -> Result<(), String> {
         match &self.tx_write {
             Some(tx) => {
-                match tx.send(command.to_string()) {
+                match tx.send(bytes.to_vec()) {
                     Ok(_) => Ok(()),
                     Err(_) => Err(format!("Cannot write command to \"{}\"", self.device_id).to_string())
                 }
@@ -129,6 +236,10 @@ impl DeviceHandle {
     pub fn get_device_info(&self) -> DeviceInfo {
         self.device_info.lock().unwrap().clone()
     }
+
+    pub fn take_device_events(&self) -> Vec<DeviceEvent> {
+        self.device_info.lock().unwrap().take_events()
+    }
 }
 
 impl DeviceService { 
@@ -180,6 +291,21 @@ impl DeviceService {
         }     
     }
 
+    fn get_device_handle(&self, device_id: &String) -> Result<&DeviceHandle, String> {
+        match self.device_handles.get(device_id) {
+            Some(handle) => Ok(handle),
+            None => Err("Device not found".to_string()),
+        }
+    }
+
+    /// Removes and returns all device events since the last call
+    pub fn take_device_events(&self, device_id: &String) -> Result<Vec<DeviceEvent>, String> {
+        match self.device_handles.get(device_id) {
+            Some(handler) => Ok(handler.take_device_events()),
+            None => Err("Device not found".to_string()),
+        }
+    }
+
     pub fn write_device_command(&mut self, device_id : &String, command: &str) -> Result<(), String> {
         match self.device_handles.get(device_id) {
             Some(handle) => handle.write(command),
@@ -187,6 +313,111 @@ impl DeviceService {
         }        
     }
 
+    /// Writes raw bytes e.g. extended realtime commands to the device
+    pub fn write_device_bytes(&mut self, device_id : &String, bytes: &[u8]) -> Result<(), String> {
+        match self.device_handles.get(device_id) {
+            Some(handle) => handle.write_bytes(bytes),
+            None => Err("Device not found".to_string()),
+        }
+    }
+
+    /// Requests status reports until the device info fulfills the condition
+    ///
+    /// Returns the matching device info or an error after the timeout
+    pub fn wait_for_device_info<F>(&mut self, device_id: &String, timeout: Duration, condition: F) -> Result<DeviceInfo, String>
+    where
+        F: Fn(&DeviceInfo) -> bool,
+    {
+        let wait_start = Instant::now();
+        loop {
+            self.write_device_command(device_id, realtime::STATUS_REPORT)?;
+            thread::sleep(STATUS_POLL_INTERVAL);
+
+            let device_info = self.get_device_info(device_id)?;
+            if condition(&device_info) {
+                return Ok(device_info);
+            }
+            if wait_start.elapsed() >= timeout {
+                return Err(format!("Timeout while waiting for \"{}\"", device_id));
+            }
+        }
+    }
+
+    /// Writes the commands and waits until the device executed all of them
+    ///
+    /// A dwell ("G4 P0") is appended, its "ok" is sent after all previous motions are completed.
+    /// Returns the device info of the first status report after completion.
+    ///
+    /// # Errors
+    /// Returns an error if any command is answered with "error:\<code\>", the device enters alarm state,
+    /// is reset or the timeout elapses
+    pub fn write_device_commands_sync(&mut self, device_id: &String, commands: Vec<String>, timeout: Duration) -> Result<DeviceInfo, String> {
+        let device_info = self.get_device_info(device_id)?;
+        let expected_responses = device_info.response_count() + commands.len() as u64 + 1;
+        let error_responses = device_info.error_response_count();
+        let reset_count = device_info.reset_count();
+
+        self.write_device_commands(device_id, commands)?;
+        self.write_device_command(device_id, SYNC_COMMAND)?;
+        let device_info = self.wait_for_device_info(device_id, timeout, |device_info| {
+            device_info.response_count() >= expected_responses || is_alarm(device_info) || device_info.reset_count() > reset_count
+        })?;
+        check_not_reset(device_id, &device_info, reset_count)?;
+        if device_info.error_response_count() > error_responses {
+            return Err(format!("Command was rejected by \"{}\"", device_id));
+        }
+        if is_alarm(&device_info) {
+            return Err(format!("\"{}\" entered alarm state", device_id));
+        }
+
+        let report_count = device_info.report_count();
+        self.wait_for_device_info(device_id, timeout, |device_info| device_info.report_count() > report_count)
+    }
+
+    /// Writes the command and waits for its response without waiting for motions
+    ///
+    /// # Errors
+    /// Returns an error if the command is answered with "error:\<code\>", the device is reset or the timeout elapses
+    pub fn write_device_command_acknowledged(&mut self, device_id: &String, command: &str, timeout: Duration) -> Result<DeviceInfo, String> {
+        let device_info = self.get_device_info(device_id)?;
+        let response_count = device_info.response_count();
+        let error_responses = device_info.error_response_count();
+        let reset_count = device_info.reset_count();
+
+        self.write_device_command(device_id, command)?;
+        let device_info = self.wait_for_device_info(device_id, timeout, |device_info| {
+            device_info.response_count() > response_count || device_info.reset_count() > reset_count
+        })?;
+        check_not_reset(device_id, &device_info, reset_count)?;
+        if device_info.error_response_count() > error_responses {
+            return Err(format!("Command was rejected by \"{}\"", device_id));
+        }
+        Ok(device_info)
+    }
+
+    /// Runs the routine and restores the modal state (G0/G1, G20/G21, G90/G91, F) afterwards
+    ///
+    /// The parser state is requested ("$G") before the routine starts, a cached state may be outdated
+    ///
+    /// # Errors
+    /// Returns the error of the routine or an error if the parser state could not be read or restored
+    fn run_restoring_modal_state<T, F>(&mut self, device_id: &String, timeout: Duration, routine: F) -> Result<T, String>
+    where
+        F: FnOnce(&mut DeviceService) -> Result<T, String>,
+    {
+        let device_info = self.write_device_command_acknowledged(device_id, command::state::GET_PARSER_STATE, PARSER_STATE_TIMEOUT)?;
+        let restore_command = match device_info.gcode_state() {
+            Some(gcode_state) => get_restore_modal_state_command(gcode_state),
+            None => return Err(format!("Parser state of \"{}\" is unknown", device_id)),
+        };
+
+        let result = routine(self);
+        let restored = self.write_device_commands_sync(device_id, vec![restore_command], timeout);
+        let value = result?;
+        restored?;
+        Ok(value)
+    }
+
     pub fn write_device_commands(&mut self, device_id : &String, commands: Vec<String>) -> Result<(), String> {
         for command in commands {
             if let Err(err) = self.write_device_command(device_id, &command) {
@@ -208,4 +439,32 @@ impl Drop for DeviceService {
             }
         }
     }
-}
\ No newline at end of file
+}
+
+fn is_alarm(device_info: &DeviceInfo) -> bool {
+    matches!(device_info.machine_state_name(), Some(MachineStateName::Alarm))
+}
+
+/// A reset discards all pending commands, their acknowledgements never arrive
+fn check_not_reset(device_id: &String, device_info: &DeviceInfo, reset_count: u64) -> Result<(), String> {
+    match device_info.reset_count() > reset_count {
+        true => Err(format!("\"{}\" was reset before acknowledging the command", device_id)),
+        false => Ok(()),
+    }
+}
+
+/// Checks that the firmware was compiled with the capability
+fn check_capability(device_id: &String, device_info: &DeviceInfo, capability: Capability) -> Result<(), String> {
+    match device_info.firmware_info().capabilities().supports(capability) {
+        true => Ok(()),
+        false => Err(format!("{} is unsupported by the firmware of \"{}\"", capability.name(), device_id)),
+    }
+}
+
+/// The host may only send realtime commands while the pendant is in control
+fn check_pendant_released(device_id: &String, device_info: &DeviceInfo) -> Result<(), String> {
+    match device_info.is_pendant_control_taken() {
+        true => Err(format!("Pendant has control of \"{}\"", device_id)),
+        false => Ok(()),
+    }
+}
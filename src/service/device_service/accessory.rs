@@ -0,0 +1,167 @@
+use std::time::Duration;
+
+use crate::device::{command::{accessory::{get_coolant_commands, get_spindle_start_command, get_spindle_stop_command, SpindleDirection}, general, realtime::accessory::{FLOOD_TOGGLE, MIST_TOGGLE, SPINDLE_STOP_TOGGLE}}, response::{firmware::capabilities::Capability, state::{accessory::AccessoryState, machine::state::MachineStateName}}, DeviceInfo};
+
+use super::{check_capability, DeviceService};
+
+/// Maximum time until a status report has to confirm the accessory state
+const ACCESSORY_CONFIRM_TIMEOUT: Duration = Duration::from_secs(1);
+
+impl DeviceService {
+
+    /// Requests the available spindles of a grblHAL device ("$SPINDLES")
+    pub fn load_spindles(&mut self, device_id: &String) -> Result<(), String> {
+        self.write_device_command(device_id, general::GET_SPINDLES)
+    }
+
+    /// Starts the spindle while the device is idle and waits until the accessory state confirms it
+    ///
+    /// The spindle number selects one of the spindles loaded by [`DeviceService::load_spindles`]
+    ///
+    /// # Errors
+    /// Returns an error if the device is not idle, the spindle is unknown or the device did not confirm the start
+    pub fn start_spindle(&mut self, device_id: &String, direction: SpindleDirection, rpm: f32, spindle: Option<u32>) -> Result<(), String> {
+        let device_info = self.get_device_info(device_id)?;
+        if let Some(number) = spindle {
+            if device_info.firmware_info().get_spindle_by_number(number).is_none() {
+                return Err(format!("Spindle {} is not enabled on \"{}\"", number, device_id));
+            }
+        }
+        if device_info.machine_state_name() != Some(MachineStateName::Idle) {
+            return Err(format!("Spindle of \"{}\" can only be started while idle", device_id));
+        }
+
+        self.write_device_command(device_id, &get_spindle_start_command(direction, rpm, spindle))?;
+        let expected_state = match direction {
+            SpindleDirection::Clockwise => AccessoryState::SpindleClockwise,
+            SpindleDirection::CounterClockwise => AccessoryState::SpindleCounterClockwise,
+        };
+        self.wait_for_accessory_state(device_id, |states| states.contains(&expected_state))
+    }
+
+    /// Stops the spindle with "M5" while idle or with the spindle stop override while on hold
+    ///
+    /// # Errors
+    /// Returns an error in any other state or if the device did not confirm the stop
+    pub fn stop_spindle(&mut self, device_id: &String, spindle: Option<u32>) -> Result<(), String> {
+        let device_info = self.get_device_info(device_id)?;
+        match device_info.machine_state_name() {
+            Some(MachineStateName::Idle) => self.write_device_command(device_id, &get_spindle_stop_command(spindle))?,
+            Some(MachineStateName::Hold) => {
+                if !is_spindle_running(&device_info) {
+                    return Ok(());
+                }
+                self.write_device_bytes(device_id, &[SPINDLE_STOP_TOGGLE])?
+            }
+            _ => return Err(format!("Spindle of \"{}\" can only be stopped while idle or on hold", device_id)),
+        }
+        self.wait_for_accessory_state(device_id, |states| {
+            !states.contains(&AccessoryState::SpindleClockwise) && !states.contains(&AccessoryState::SpindleCounterClockwise)
+        })
+    }
+
+    /// Turns the flood coolant on or off
+    ///
+    /// Uses M7/M8/M9 while idle and the realtime toggle while running, on hold or jogging
+    ///
+    /// # Errors
+    /// Returns an error if the coolant cannot be changed in the current state or the device did not confirm it
+    pub fn set_flood_coolant(&mut self, device_id: &String, enabled: bool) -> Result<(), String> {
+        self.set_coolant(device_id, AccessoryState::FloodCoolantEnabled, enabled)
+    }
+
+    /// Turns the mist coolant on or off
+    ///
+    /// Uses M7/M8/M9 while idle and the realtime toggle while running, on hold or jogging
+    ///
+    /// # Errors
+    /// Returns an error if the firmware has no mist coolant ("M" compile option),
+    /// the coolant cannot be changed in the current state or the device did not confirm it
+    pub fn set_mist_coolant(&mut self, device_id: &String, enabled: bool) -> Result<(), String> {
+        check_capability(device_id, &self.get_device_info(device_id)?, Capability::MistCoolant)?;
+        self.set_coolant(device_id, AccessoryState::MistCoolantEnabled, enabled)
+    }
+
+    /// Waits until the actual spindle speed ("FS:") is within the tolerance of the programmed speed
+    ///
+    /// The tolerance is a fraction of the programmed speed e.g. 0.05 for 5%
+    ///
+    /// # Errors
+    /// Returns an error if the device does not report the actual speed or the speed is not reached before the timeout
+    pub fn wait_for_spindle_speed(&mut self, device_id: &String, tolerance: f32, timeout: Duration) -> Result<(), String> {
+        let result = self.wait_for_device_info(device_id, timeout, |device_info| {
+            let machine_speed = match device_info.machine_info().and_then(|info| info.machine_speed()) {
+                Some(machine_speed) => machine_speed,
+                None => return false,
+            };
+            match (machine_speed.spindle_programmed_rpm(), machine_speed.spindle_actual_rpm()) {
+                (Some(programmed_rpm), Some(actual_rpm)) => (actual_rpm - programmed_rpm).abs() <= programmed_rpm * tolerance,
+                _ => false,
+            }
+        });
+        if result.is_ok() {
+            return Ok(());
+        }
+
+        let device_info = self.get_device_info(device_id)?;
+        match device_info.machine_info().and_then(|info| info.machine_speed()).and_then(|speed| speed.spindle_actual_rpm()) {
+            Some(actual_rpm) => Err(format!("Spindle of \"{}\" did not reach speed ({} rpm)", device_id, actual_rpm)),
+            None => Err(format!("Actual spindle speed of \"{}\" is not reported", device_id)),
+        }
+    }
+
+    fn set_coolant(&mut self, device_id: &String, coolant: AccessoryState, enabled: bool) -> Result<(), String> {
+        let device_info = self.get_device_info(device_id)?;
+        let accessory_state = device_info.machine_info().and_then(|info| info.accessory_state()).cloned();
+
+        match device_info.machine_state_name() {
+            Some(MachineStateName::Idle) => {
+                let states = accessory_state.unwrap_or_default();
+                let (flood, mist) = match coolant {
+                    AccessoryState::FloodCoolantEnabled => (enabled, states.contains(&AccessoryState::MistCoolantEnabled)),
+                    _ => (states.contains(&AccessoryState::FloodCoolantEnabled), enabled),
+                };
+                self.write_device_commands(device_id, get_coolant_commands(flood, mist))?;
+            }
+            Some(MachineStateName::Run) | Some(MachineStateName::Hold) | Some(MachineStateName::Jog) => {
+                let states = match accessory_state {
+                    Some(states) => states,
+                    None => return Err(format!("Accessory state of \"{}\" is unknown", device_id)),
+                };
+                if states.contains(&coolant) == enabled {
+                    return Ok(());
+                }
+                let toggle = match coolant {
+                    AccessoryState::FloodCoolantEnabled => FLOOD_TOGGLE,
+                    _ => MIST_TOGGLE,
+                };
+                self.write_device_bytes(device_id, &[toggle])?;
+            }
+            _ => return Err(format!("Coolant of \"{}\" cannot be changed in the current state", device_id)),
+        }
+        self.wait_for_accessory_state(device_id, |states| states.contains(&coolant) == enabled)
+    }
+
+    fn wait_for_accessory_state<F>(&mut self, device_id: &String, is_confirmed: F) -> Result<(), String>
+    where
+        F: Fn(&[AccessoryState]) -> bool,
+    {
+        let confirmation = self.wait_for_device_info(device_id, ACCESSORY_CONFIRM_TIMEOUT, |device_info: &DeviceInfo| {
+            match device_info.machine_info().and_then(|info| info.accessory_state()) {
+                Some(states) => is_confirmed(states),
+                None => false,
+            }
+        });
+        match confirmation {
+            Ok(_) => Ok(()),
+            Err(_) => Err(format!("Accessory change was not confirmed by \"{}\"", device_id)),
+        }
+    }
+}
+
+fn is_spindle_running(device_info: &DeviceInfo) -> bool {
+    match device_info.machine_info().and_then(|info| info.accessory_state()) {
+        Some(states) => states.contains(&AccessoryState::SpindleClockwise) || states.contains(&AccessoryState::SpindleCounterClockwise),
+        None => false,
+    }
+}
@@ -0,0 +1,167 @@
+use std::time::Duration;
+
+use crate::device::{command::{coordinate::{get_go_to_stored_position_command, get_select_coordinate_system_command, get_set_coordinate_offset_command, get_store_position_command, StoredPosition, CLEAR_COORDINATE_OFFSET}, general::GET_NGC_PARAMETERS, probe::get_set_work_position_command, state::GET_PARSER_STATE}, response::state::{machine::position::Position, ngc_parameters::{NgcParameterName, NgcParameters}}, util::{axis::Axis, coordinate::{get_coordinate_system_name, CoordinateSystem}}, DeviceInfo};
+
+use super::DeviceService;
+
+/// Maximum time until the device has to confirm a parameter change
+const COORDINATE_CONFIRM_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Maximum time of a move to a stored position
+const STORED_POSITION_MOVE_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Maximum deviation in mm between a requested and a reported coordinate
+const COORDINATE_TOLERANCE: f32 = 0.01;
+
+impl DeviceService {
+
+    /// Requests all coordinate system offsets and stored positions ("$#") and waits for the response
+    ///
+    /// # Errors
+    /// Returns an error if the device did not respond
+    pub fn load_ngc_parameters(&mut self, device_id: &String) -> Result<NgcParameters, String> {
+        let device_info = self.write_device_commands_sync(device_id, vec![GET_NGC_PARAMETERS.to_string()], COORDINATE_CONFIRM_TIMEOUT)?;
+        Ok(device_info.ngc_parameters().clone())
+    }
+
+    /// Makes the current position the zero of the selected axes in the coordinate system ("G10 L20")
+    ///
+    /// # Errors
+    /// Returns an error if the device rejected the command or "$#" does not confirm the new offset
+    pub fn set_work_zero(&mut self, device_id: &String, coordinate_system: CoordinateSystem, axes: &[Axis]) -> Result<(), String> {
+        let target: Vec<(Axis, f32)> = axes.iter().map(|axis| (*axis, 0.0)).collect();
+        let commands = vec![get_set_work_position_command(Some(&coordinate_system), &target), GET_NGC_PARAMETERS.to_string()];
+        let device_info = self.run_restoring_modal_state(device_id, COORDINATE_CONFIRM_TIMEOUT, |service| {
+            service.write_device_commands_sync(device_id, commands, COORDINATE_CONFIRM_TIMEOUT)
+        })?;
+
+        // G10 L20 stores the machine position without the G92 and tool length offsets
+        let machine_position = match device_info.machine_info().and_then(|info| info.global_position()) {
+            Some(position) => position.clone(),
+            None => return Err(format!("Machine position of \"{}\" is unknown", device_id)),
+        };
+        let parameters = device_info.ngc_parameters();
+        let expected: Vec<(Axis, f32)> = axes
+            .iter()
+            .map(|axis| {
+                let offset = get_parameter_value(parameters, &NgcParameterName::CoordinateOffset, axis)
+                    + get_parameter_value(parameters, &NgcParameterName::ToolLengthOffset, axis);
+                (*axis, machine_position.get(axis).unwrap_or_default() - offset)
+            })
+            .collect();
+        confirm_parameter(device_id, &device_info, &NgcParameterName::CoordinateSystem(coordinate_system), &expected)
+    }
+
+    /// Sets the offset of the coordinate system to absolute machine coordinates in mm ("G10 L2")
+    ///
+    /// # Errors
+    /// Returns an error if the device rejected the command or "$#" does not confirm the new offset
+    pub fn set_work_offset(&mut self, device_id: &String, coordinate_system: CoordinateSystem, offset: &[(Axis, f32)]) -> Result<(), String> {
+        let commands = vec![get_set_coordinate_offset_command(&coordinate_system, offset), GET_NGC_PARAMETERS.to_string()];
+        let device_info = self.run_restoring_modal_state(device_id, COORDINATE_CONFIRM_TIMEOUT, |service| {
+            service.write_device_commands_sync(device_id, commands, COORDINATE_CONFIRM_TIMEOUT)
+        })?;
+        confirm_parameter(device_id, &device_info, &NgcParameterName::CoordinateSystem(coordinate_system), offset)
+    }
+
+    /// Removes the G92 coordinate offset ("G92.1")
+    ///
+    /// # Errors
+    /// Returns an error if the device rejected the command or "$#" still reports an offset
+    pub fn clear_coordinate_offset(&mut self, device_id: &String) -> Result<(), String> {
+        let commands = vec![CLEAR_COORDINATE_OFFSET.to_string(), GET_NGC_PARAMETERS.to_string()];
+        let device_info = self.write_device_commands_sync(device_id, commands, COORDINATE_CONFIRM_TIMEOUT)?;
+        let expected: Vec<(Axis, f32)> = match device_info.ngc_parameters().get(&NgcParameterName::CoordinateOffset) {
+            Some(offset) => offset.axes().into_iter().map(|axis| (axis, 0.0)).collect(),
+            None => Vec::new(),
+        };
+        confirm_parameter(device_id, &device_info, &NgcParameterName::CoordinateOffset, &expected)
+    }
+
+    /// Stores the current machine position as G28 or G30 position
+    ///
+    /// # Errors
+    /// Returns an error if the device rejected the command or "$#" does not confirm the stored position
+    pub fn store_position(&mut self, device_id: &String, stored_position: StoredPosition) -> Result<(), String> {
+        let commands = vec![get_store_position_command(stored_position), GET_NGC_PARAMETERS.to_string()];
+        let device_info = self.write_device_commands_sync(device_id, commands, COORDINATE_CONFIRM_TIMEOUT)?;
+        let expected = match device_info.machine_info().and_then(|info| info.global_position()) {
+            Some(position) => get_axis_values(position),
+            None => return Err(format!("Machine position of \"{}\" is unknown", device_id)),
+        };
+        confirm_parameter(device_id, &device_info, &get_stored_position_parameter_name(stored_position), &expected)
+    }
+
+    /// Moves to the stored G28 or G30 position and waits until the machine arrived
+    ///
+    /// # Errors
+    /// Returns an error if the device rejected the command or the reported machine position differs from the stored position
+    pub fn go_to_stored_position(&mut self, device_id: &String, stored_position: StoredPosition) -> Result<(), String> {
+        let parameters = self.load_ngc_parameters(device_id)?;
+        let target = match parameters.get(&get_stored_position_parameter_name(stored_position)) {
+            Some(position) => position.clone(),
+            None => return Err(format!("Stored position of \"{}\" is unknown", device_id)),
+        };
+
+        let device_info = self.write_device_commands_sync(device_id, vec![get_go_to_stored_position_command(stored_position)], STORED_POSITION_MOVE_TIMEOUT)?;
+        let machine_position = match device_info.machine_info().and_then(|info| info.global_position()) {
+            Some(position) => position,
+            None => return Err(format!("Machine position of \"{}\" is unknown", device_id)),
+        };
+        for (axis, value) in get_axis_values(&target) {
+            if !is_within_tolerance(machine_position.get(&axis), value) {
+                return Err(format!("\"{}\" did not reach the stored position", device_id));
+            }
+        }
+        Ok(())
+    }
+
+    /// Activates the coordinate system and confirms it with the gcode state ("$G")
+    ///
+    /// # Errors
+    /// Returns an error if the device rejected the command or the gcode state does not confirm it
+    pub fn select_coordinate_system(&mut self, device_id: &String, coordinate_system: CoordinateSystem) -> Result<(), String> {
+        let commands = vec![get_select_coordinate_system_command(&coordinate_system), GET_PARSER_STATE.to_string()];
+        let device_info = self.write_device_commands_sync(device_id, commands, COORDINATE_CONFIRM_TIMEOUT)?;
+        match device_info.active_coordinate_system() {
+            Some(active) if active == coordinate_system => Ok(()),
+            _ => Err(format!("\"{}\" did not activate {}", device_id, get_coordinate_system_name(&coordinate_system))),
+        }
+    }
+}
+
+/// Checks that "$#" reported the expected values of the parameter
+fn confirm_parameter(device_id: &String, device_info: &DeviceInfo, name: &NgcParameterName, expected: &[(Axis, f32)]) -> Result<(), String> {
+    let position = match device_info.ngc_parameters().get(name) {
+        Some(position) => position,
+        None => return Err(format!("Parameter {:?} of \"{}\" is not reported", name, device_id)),
+    };
+    for (axis, value) in expected {
+        if !is_within_tolerance(position.get(axis), *value) {
+            return Err(format!("Parameter {:?} of \"{}\" was not updated", name, device_id));
+        }
+    }
+    Ok(())
+}
+
+fn get_parameter_value(parameters: &NgcParameters, name: &NgcParameterName, axis: &Axis) -> f32 {
+    parameters.get(name).and_then(|position| position.get(axis)).unwrap_or_default()
+}
+
+fn get_axis_values(position: &Position) -> Vec<(Axis, f32)> {
+    position.iter().collect()
+}
+
+fn get_stored_position_parameter_name(stored_position: StoredPosition) -> NgcParameterName {
+    match stored_position {
+        StoredPosition::Home => NgcParameterName::HomePosition,
+        StoredPosition::SecondaryHome => NgcParameterName::SecondaryHomePosition,
+    }
+}
+
+fn is_within_tolerance(value: Option<f32>, expected: f32) -> bool {
+    match value {
+        Some(value) => (value - expected).abs() <= COORDINATE_TOLERANCE,
+        None => false,
+    }
+}
@@ -0,0 +1,90 @@
+use std::{cell::Cell, time::Duration};
+
+use crate::device::{command::homing::get_home_commands, response::{firmware::capabilities::Capability, state::{homing::HomingResult, machine::state::MachineStateName}}, util::axis::Axis};
+
+use super::{check_capability, DeviceService};
+
+/// Maximum time of a single homing cycle
+const HOMING_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Maximum time until a status report has to confirm the homed axes
+const HOMING_CONFIRM_TIMEOUT: Duration = Duration::from_secs(1);
+
+impl DeviceService {
+
+    /// Homes the axes and waits until the status report confirms them as homed ("H:1,\<mask\>")
+    ///
+    /// Classic grbl does not report "H:", reaching idle state without alarm confirms all homed axes.
+    /// All axes are homed with "$H" if no axis is given. Homing single axes requires the
+    /// compile option "H" ([`Capability::SingleAxisHoming`]).
+    ///
+    /// # Errors
+    /// Returns an error if single axis homing is not supported, the device is busy,
+    /// rejected the command, entered alarm state or did not confirm the homed axes
+    pub fn home(&mut self, device_id: &String, axes: &[Axis]) -> Result<HomingResult, String> {
+        let device_info = self.get_device_info(device_id)?;
+        if !axes.is_empty() {
+            check_capability(device_id, &device_info, Capability::SingleAxisHoming)?;
+        }
+        let started_in_alarm = match device_info.machine_state_name() {
+            Some(MachineStateName::Idle) => false,
+            Some(MachineStateName::Alarm) => true,
+            _ => return Err(format!("\"{}\" can only home while idle or in alarm state", device_id)),
+        };
+
+        for command in get_home_commands(axes) {
+            self.run_homing_cycle(device_id, &command, started_in_alarm)?;
+        }
+
+        let device_info = self.wait_for_device_info(device_id, HOMING_CONFIRM_TIMEOUT, |device_info| {
+            match device_info.machine_info().and_then(|info| info.homing_state()) {
+                Some(homing_state) => homing_state.is_homed() && axes.iter().all(|axis| homing_state.homed_axes().contains(axis)),
+                None => device_info.machine_state_name() == Some(MachineStateName::Idle),
+            }
+        });
+        let device_info = match device_info {
+            Ok(device_info) => device_info,
+            Err(_) => return Err(format!("Homing was not confirmed by \"{}\"", device_id)),
+        };
+
+        let machine_info = device_info.machine_info().unwrap();
+        let position = match machine_info.global_position() {
+            Some(position) => position.clone(),
+            None => return Err(format!("Machine position of \"{}\" is unknown", device_id)),
+        };
+        let homed_axes = match machine_info.homing_state() {
+            Some(homing_state) => homing_state.homed_axes().to_vec(),
+            None if axes.is_empty() => position.axes(),
+            None => axes.to_vec(),
+        };
+        Ok(HomingResult::new(homed_axes, position))
+    }
+
+    /// Writes the homing command and waits for its response
+    ///
+    /// "$H" is acknowledged after the cycle finished, a failed cycle ends in alarm state without response
+    fn run_homing_cycle(&mut self, device_id: &String, command: &str, started_in_alarm: bool) -> Result<(), String> {
+        let device_info = self.get_device_info(device_id)?;
+        let response_count = device_info.response_count();
+        let error_response_count = device_info.error_response_count();
+
+        self.write_device_command(device_id, command)?;
+        let homing_seen = Cell::new(false);
+        let device_info = self.wait_for_device_info(device_id, HOMING_TIMEOUT, |device_info| {
+            match device_info.machine_state_name() {
+                Some(MachineStateName::Home) => homing_seen.set(true),
+                Some(MachineStateName::Alarm) if homing_seen.get() || !started_in_alarm => return true,
+                _ => (),
+            }
+            device_info.response_count() > response_count
+        })?;
+
+        if device_info.error_response_count() > error_response_count {
+            return Err(format!("Homing was rejected by \"{}\"", device_id));
+        }
+        if device_info.machine_state_name() == Some(MachineStateName::Alarm) && device_info.response_count() == response_count {
+            return Err(format!("Homing of \"{}\" failed", device_id));
+        }
+        Ok(())
+    }
+}
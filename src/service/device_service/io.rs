@@ -0,0 +1,122 @@
+use std::time::Duration;
+
+use crate::device::{command::io::{get_analog_output_command, get_digital_output_command, get_wait_for_input_command, AuxInput, InputWaitMode, OutputMode}, response::firmware::board::ports::AuxPorts, util::io::AuxOutputStates, DeviceInfo};
+
+use super::{check_not_reset, check_pendant_released, is_alarm, DeviceHandle, DeviceService};
+
+/// Maximum time until the device has to acknowledge an output command
+const IO_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Time added to the input wait timeout until the result has to be reported
+const INPUT_WAIT_MARGIN: Duration = Duration::from_secs(2);
+
+impl DeviceHandle {
+
+    fn put_digital_output(&self, port: u16, enabled: bool, mode: OutputMode) {
+        let mut device_info = self.device_info.lock().unwrap();
+        match mode {
+            OutputMode::Synchronized => device_info.put_pending_digital_output(port, enabled),
+            OutputMode::Immediate => device_info.put_digital_output(port, enabled),
+        }
+    }
+
+    fn put_analog_output(&self, port: u16, value: f32, mode: OutputMode) {
+        let mut device_info = self.device_info.lock().unwrap();
+        match mode {
+            OutputMode::Synchronized => device_info.put_pending_analog_output(port, value),
+            OutputMode::Immediate => device_info.put_analog_output(port, value),
+        }
+    }
+}
+
+impl DeviceService {
+
+    /// Switches the digital aux output, synchronized outputs (M62/M63) change with the next motion
+    ///
+    /// Synchronized states are reported as pending by [`DeviceService::get_aux_outputs`] until the motion is completed
+    ///
+    /// # Errors
+    /// Returns an error if the aux ports are unknown ("$I"), the port is out of range or the command was rejected
+    pub fn set_digital_output(&mut self, device_id: &String, port: u16, enabled: bool, mode: OutputMode) -> Result<(), String> {
+        let device_info = self.get_device_info(device_id)?;
+        check_pendant_released(device_id, &device_info)?;
+        check_aux_port(device_id, &device_info, "Digital output", port, AuxPorts::digital_out)?;
+
+        self.write_device_command_acknowledged(device_id, &get_digital_output_command(port, enabled, mode), IO_TIMEOUT)?;
+        self.get_device_handle(device_id)?.put_digital_output(port, enabled, mode);
+        Ok(())
+    }
+
+    /// Sets the analog aux output, synchronized outputs (M67) change with the next motion
+    ///
+    /// Synchronized values are reported as pending by [`DeviceService::get_aux_outputs`] until the motion is completed
+    ///
+    /// # Errors
+    /// Returns an error if the aux ports are unknown ("$I"), the port is out of range or the command was rejected
+    pub fn set_analog_output(&mut self, device_id: &String, port: u16, value: f32, mode: OutputMode) -> Result<(), String> {
+        let device_info = self.get_device_info(device_id)?;
+        check_pendant_released(device_id, &device_info)?;
+        check_aux_port(device_id, &device_info, "Analog output", port, AuxPorts::analog_out)?;
+
+        self.write_device_command_acknowledged(device_id, &get_analog_output_command(port, value, mode), IO_TIMEOUT)?;
+        self.get_device_handle(device_id)?.put_analog_output(port, value, mode);
+        Ok(())
+    }
+
+    /// Get the applied and pending aux output states commanded since the last reset
+    pub fn get_aux_outputs(&self, device_id: &String) -> Result<AuxOutputStates, String> {
+        Ok(self.get_device_info(device_id)?.aux_outputs().clone())
+    }
+
+    /// Waits for the input (M66) and returns if the condition was met before the timeout
+    ///
+    /// The result is read from the "In:" field of the following status report.
+    ///
+    /// # Errors
+    /// Returns an error if the aux ports are unknown ("$I"), the port is out of range,
+    /// the command was rejected or the device did not report a result
+    pub fn wait_for_input(&mut self, device_id: &String, input: AuxInput, mode: InputWaitMode, timeout: Duration) -> Result<bool, String> {
+        let command = get_wait_for_input_command(input, mode, timeout)?;
+        let device_info = self.get_device_info(device_id)?;
+        check_pendant_released(device_id, &device_info)?;
+        match input {
+            AuxInput::Digital(port) => check_aux_port(device_id, &device_info, "Digital input", port, AuxPorts::digital_in)?,
+            AuxInput::Analog(port) => check_aux_port(device_id, &device_info, "Analog input", port, AuxPorts::analog_in)?,
+        }
+
+        let wait_results = device_info.input_wait_result_count();
+        let error_responses = device_info.error_response_count();
+        let reset_count = device_info.reset_count();
+        self.write_device_command(device_id, &command)?;
+        let device_info = match self.wait_for_device_info(device_id, timeout + INPUT_WAIT_MARGIN, |device_info| {
+            device_info.input_wait_result_count() > wait_results
+                || device_info.error_response_count() > error_responses
+                || device_info.reset_count() > reset_count
+                || is_alarm(device_info)
+        }) {
+            Ok(device_info) => device_info,
+            Err(_) => return Err(format!("\"{}\" did not report the input wait result", device_id)),
+        };
+        check_not_reset(device_id, &device_info, reset_count)?;
+        if device_info.error_response_count() > error_responses {
+            return Err(format!("Command was rejected by \"{}\"", device_id));
+        }
+        if is_alarm(&device_info) {
+            return Err(format!("\"{}\" entered alarm state", device_id));
+        }
+        Ok(device_info.machine_info().and_then(|info| info.input_wait_result_succeeded()).unwrap_or(false))
+    }
+}
+
+/// Checks the port index against the aux ports reported by the board info
+fn check_aux_port(device_id: &String, device_info: &DeviceInfo, name: &str, port: u16, count: fn(&AuxPorts) -> u16) -> Result<(), String> {
+    let aux_ports = match device_info.firmware_info().board_info().aux() {
+        Some(aux_ports) => aux_ports,
+        None => return Err(format!("Aux ports of \"{}\" are unknown", device_id)),
+    };
+    let port_count = count(&aux_ports);
+    match port < port_count {
+        true => Ok(()),
+        false => Err(format!("{} {} is out of range of \"{}\" ({} available)", name, port, device_id, port_count)),
+    }
+}
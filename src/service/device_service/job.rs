@@ -0,0 +1,184 @@
+use crate::{device::{command::jog::get_jog_command, response::{firmware::capabilities::Capability, report::MachineInfo, state::{machine::position::Position, ngc_parameters::NgcParameters}}, util::{axis::Axis, coordinate::{get_coordinate_system, get_coordinate_system_by_index, CoordinateSystem, COORDINATE_SYSTEM_COUNT}}}, job::{analyzer::{JobAnalysis, JobAnalyzer}, gcode::strip_gcode_comments, limits::{format_soft_limit_violations, SoftLimitViolation, TravelLimits}, progress::JobProgress}};
+
+use super::{check_capability, check_pendant_released, DeviceHandle, DeviceService};
+
+impl DeviceHandle {
+
+    /// Starts streaming the lines, replaces any finished job
+    fn start_job(&self, lines: Vec<String>) -> Result<(), String> {
+        let mut device_info = self.device_info.lock().unwrap();
+        if let Some(progress) = device_info.job_progress() {
+            if progress.is_running() {
+                return Err(format!("A job is already running on \"{}\"", self.device_id));
+            }
+        }
+        device_info.set_job_progress(JobProgress::new(lines.len()));
+
+        match &self.tx_job {
+            Some(tx) => match tx.send(lines) {
+                Ok(_) => Ok(()),
+                Err(_) => Err(format!("Cannot send job to \"{}\"", self.device_id)),
+            },
+            None => panic!("Job channel to \"{}\" closed", self.device_id),
+        }
+    }
+
+    /// Stops streaming of the running job
+    fn cancel_job(&self) {
+        if let Some(progress) = self.device_info.lock().unwrap().job_progress_mut() {
+            progress.cancel();
+        }
+    }
+}
+
+impl DeviceService {
+
+    /// Analyzes the program with the device settings and checks its extents against the machine travel
+    ///
+    /// The analysis starts at the current work position in the active work coordinate system.
+    /// The offsets of the other work coordinate systems are taken from the last "$#" response.
+    ///
+    /// # Errors
+    /// Returns an error if the device or its position is unknown, the program cannot be analyzed
+    /// or uses lathe features (G7, G33, G76) unsupported by the firmware
+    pub fn check_job(&self, device_id: &String, program: &str) -> Result<(JobAnalysis, Vec<SoftLimitViolation>), String> {
+        let device_info = self.get_device_info(device_id)?;
+        let machine_info = match device_info.machine_info() {
+            Some(machine_info) => machine_info,
+            None => return Err(format!("Machine state of \"{}\" is unknown", device_id)),
+        };
+        let work_offset = get_work_offset(machine_info)?;
+        let coordinate_system = match machine_info.machine_coordinate_system() {
+            Some(coordinate_system) => get_coordinate_system(coordinate_system).unwrap_or(CoordinateSystem::G54),
+            None => CoordinateSystem::G54,
+        };
+
+        let mut analyzer = JobAnalyzer::from_settings(device_info.settings());
+        set_work_offsets(&mut analyzer, device_info.ngc_parameters(), &coordinate_system, &work_offset);
+        analyzer.set_coordinate_system(coordinate_system);
+        if let Some(work_position) = machine_info.local_position() {
+            analyzer.set_start_position(work_position.values());
+        }
+        if let Some(arc_mode) = machine_info.arc_mode() {
+            analyzer.set_arc_mode(*arc_mode);
+        }
+
+        let analysis = analyzer.analyze(program)?;
+        if analysis.uses_diameter_mode() {
+            check_capability(device_id, &device_info, Capability::LatheMode)?;
+        }
+        if analysis.uses_spindle_sync() {
+            check_capability(device_id, &device_info, Capability::SpindleSync)?;
+        }
+        let violations = match analysis.machine_bounds() {
+            Some(bounds) => TravelLimits::from_device_info(&device_info).check_bounds(bounds).err().unwrap_or_default(),
+            None => Vec::new(),
+        };
+        Ok((analysis, violations))
+    }
+
+    /// Checks the program against the machine travel and starts streaming it line by line
+    ///
+    /// Comments and empty lines are not sent.
+    /// While a job is running only realtime commands should be written to the device,
+    /// as every "ok" is counted as acknowledgement of the last job line.
+    ///
+    /// # Errors
+    /// Returns an error if the pendant has control or with all soft limit violations if the job exceeds the machine travel
+    pub fn start_job(&mut self, device_id: &String, program: &str) -> Result<JobAnalysis, String> {
+        check_pendant_released(device_id, &self.get_device_info(device_id)?)?;
+        let (analysis, violations) = self.check_job(device_id, program)?;
+        if !violations.is_empty() {
+            return Err(format!("Job exceeds machine travel: {}", format_soft_limit_violations(&violations)));
+        }
+
+        let lines: Vec<String> = program
+            .lines()
+            .map(|line| strip_gcode_comments(line).trim().to_string())
+            .filter(|line| !line.is_empty())
+            .map(|line| format!("{}\r", line))
+            .collect();
+
+        match self.device_handles.get(device_id) {
+            Some(handle) => handle.start_job(lines)?,
+            None => return Err("Device not found".to_string()),
+        }
+        Ok(analysis)
+    }
+
+    /// Stops streaming the running job
+    ///
+    /// Lines already sent to the device buffer are still executed
+    pub fn cancel_job(&mut self, device_id: &String) -> Result<(), String> {
+        match self.device_handles.get(device_id) {
+            Some(handle) => {
+                handle.cancel_job();
+                Ok(())
+            }
+            None => Err("Device not found".to_string()),
+        }
+    }
+
+    /// Jogs to the target after checking it against the machine travel
+    ///
+    /// Target values are in mm, either absolute work coordinates or relative to the current position
+    ///
+    /// # Errors
+    /// Returns an error if the pendant has control or with all soft limit violations if the target exceeds the machine travel
+    pub fn jog(&mut self, device_id: &String, target: &[(Axis, f32)], feed_rate: f32, incremental: bool) -> Result<(), String> {
+        let device_info = self.get_device_info(device_id)?;
+        check_pendant_released(device_id, &device_info)?;
+        let machine_info = match device_info.machine_info() {
+            Some(machine_info) => machine_info,
+            None => return Err(format!("Machine state of \"{}\" is unknown", device_id)),
+        };
+        let work_offset = get_work_offset(machine_info)?;
+        let machine_position = match machine_info.global_position() {
+            Some(machine_position) => machine_position,
+            None => return Err(format!("Machine position of \"{}\" is unknown", device_id)),
+        };
+
+        // convert the target to machine coordinates
+        let machine_target: Vec<(Axis, f32)> = target
+            .iter()
+            .map(|(axis, value)| {
+                match incremental {
+                    true => (*axis, machine_position.get(axis).unwrap_or(0.0) + value),
+                    false => (*axis, work_offset.get(axis).unwrap_or(0.0) + value),
+                }
+            })
+            .collect();
+
+        if let Err(violations) = TravelLimits::from_device_info(&device_info).check_target(&machine_target) {
+            return Err(format!("Jog target exceeds machine travel: {}", format_soft_limit_violations(&violations)));
+        }
+        self.write_device_command(device_id, &get_jog_command(target, feed_rate, incremental))
+    }
+}
+
+/// Returns the last reported work coordinate offset (WCO)
+fn get_work_offset(machine_info: &MachineInfo) -> Result<Position, String> {
+    match machine_info.local_offset() {
+        Some(offset) => Ok(offset.clone()),
+        None => Err(String::from("Work coordinate offset is unknown")),
+    }
+}
+
+/// Sets the offsets of all coordinate systems reported by "$#"
+///
+/// The active coordinate system uses the current WCO. Its difference to the "$#" offset
+/// (G92 and tool length offset) applies to all other coordinate systems as well.
+fn set_work_offsets(analyzer: &mut JobAnalyzer, parameters: &NgcParameters, active: &CoordinateSystem, work_offset: &Position) {
+    let additional_offset: Vec<f32> = match parameters.coordinate_system_offset(active) {
+        Some(offset) => work_offset.values().iter().enumerate().map(|(axis_index, value)| value - offset.values().get(axis_index).copied().unwrap_or(0.0)).collect(),
+        None => vec![0.0; work_offset.values().len()],
+    };
+    for index in 0..COORDINATE_SYSTEM_COUNT {
+        let coordinate_system = get_coordinate_system_by_index(index).unwrap();
+        if let Some(offset) = parameters.coordinate_system_offset(&coordinate_system) {
+            let offset: Vec<f32> = offset.values().iter().enumerate().map(|(axis_index, value)| value + additional_offset.get(axis_index).copied().unwrap_or(0.0)).collect();
+            analyzer.set_work_offset(&coordinate_system, &offset);
+        }
+    }
+    analyzer.set_work_offset(active, work_offset.values());
+}
@@ -0,0 +1,98 @@
+use std::time::Duration;
+
+use crate::{device::{command::laser::{get_frame_commands, get_test_fire_commands}, response::state::machine::state::MachineStateName, util::axis::Axis, DeviceInfo}, job::{laser::{check_laser_job, is_laser_mode, LaserPowerRange, LaserWarning}, limits::format_soft_limit_violations}};
+
+use super::{check_pendant_released, DeviceService};
+
+/// Maximum time to trace the frame of a job
+const FRAME_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Longest allowed test fire
+const MAX_TEST_FIRE_DURATION: Duration = Duration::from_secs(5);
+
+/// Maximum time until the test fire is completed
+const TEST_FIRE_TIMEOUT: Duration = Duration::from_secs(10);
+
+impl DeviceService {
+
+    /// Checks the program for laser mistakes, see [`check_laser_job`]
+    ///
+    /// # Errors
+    /// Returns an error if laser mode ($32) is disabled or the program cannot be parsed
+    pub fn check_laser_job(&self, device_id: &String, program: &str) -> Result<Vec<LaserWarning>, String> {
+        check_laser_mode(device_id, &self.get_device_info(device_id)?)?;
+        check_laser_job(program)
+    }
+
+    /// Traces the bounding box of the program with the laser at low power and waits until it is completed
+    ///
+    /// The power in percent is mapped to the spindle speed range ($30/$31), the modal state is restored afterwards.
+    /// The frame is traced in the active work coordinate system, in which the job bounds are reported.
+    ///
+    /// # Errors
+    /// Returns an error if laser mode is disabled, the device is not idle, the pendant has control,
+    /// the job exceeds the machine travel or has no motion
+    pub fn frame_laser_job(&mut self, device_id: &String, program: &str, power_percentage: f32, feed_rate: f32) -> Result<(), String> {
+        let device_info = self.get_device_info(device_id)?;
+        check_laser_idle(device_id, &device_info)?;
+
+        let (analysis, violations) = self.check_job(device_id, program)?;
+        if !violations.is_empty() {
+            return Err(format!("Job exceeds machine travel: {}", format_soft_limit_violations(&violations)));
+        }
+        let bounds = match analysis.bounds() {
+            Some(bounds) => bounds,
+            None => return Err(String::from("Job contains no motion")),
+        };
+
+        let spindle_speed = LaserPowerRange::from_settings(device_info.settings()).get_spindle_speed(power_percentage);
+        let commands = get_frame_commands(
+            (bounds.min(&Axis::X), bounds.min(&Axis::Y)),
+            (bounds.max(&Axis::X), bounds.max(&Axis::Y)),
+            spindle_speed,
+            feed_rate,
+        );
+        self.run_restoring_modal_state(device_id, FRAME_TIMEOUT, |service| {
+            service.write_device_commands_sync(device_id, commands, FRAME_TIMEOUT)
+        })?;
+        Ok(())
+    }
+
+    /// Fires the laser at the current position for the duration (at most 5 seconds)
+    ///
+    /// The modal state changed by the zero-length motion is restored afterwards
+    ///
+    /// # Errors
+    /// Returns an error if laser mode is disabled, the device is not idle, the pendant has control
+    /// or the duration is too long
+    pub fn test_fire_laser(&mut self, device_id: &String, power_percentage: f32, duration: Duration) -> Result<(), String> {
+        let device_info = self.get_device_info(device_id)?;
+        check_laser_idle(device_id, &device_info)?;
+        if duration > MAX_TEST_FIRE_DURATION {
+            return Err(format!("Test fire is limited to {} seconds", MAX_TEST_FIRE_DURATION.as_secs()));
+        }
+
+        let spindle_speed = LaserPowerRange::from_settings(device_info.settings()).get_spindle_speed(power_percentage);
+        self.run_restoring_modal_state(device_id, TEST_FIRE_TIMEOUT, |service| {
+            service.write_device_commands_sync(device_id, get_test_fire_commands(spindle_speed, duration), TEST_FIRE_TIMEOUT)
+        })?;
+        Ok(())
+    }
+}
+
+fn check_laser_mode(device_id: &String, device_info: &DeviceInfo) -> Result<(), String> {
+    match is_laser_mode(device_info.settings()) {
+        true => Ok(()),
+        false => Err(format!("Laser mode ($32) of \"{}\" is disabled", device_id)),
+    }
+}
+
+/// The laser may only be fired manually while idle
+fn check_laser_idle(device_id: &String, device_info: &DeviceInfo) -> Result<(), String> {
+    check_laser_mode(device_id, device_info)?;
+    check_pendant_released(device_id, device_info)?;
+    if device_info.machine_state_name() != Some(MachineStateName::Idle) {
+        return Err(format!("Laser of \"{}\" can only be fired while idle", device_id));
+    }
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use crate::{device::response::{firmware::capabilities::Capability, state::modes::ArcMode}, job::{analyzer::JobAnalysis, lathe::ThreadingPlan}};
+
+use super::{check_capability, DeviceService};
+
+impl DeviceService {
+
+    /// Starts cutting a thread from the current X position (drive line) and the start Z position to the end Z position
+    ///
+    /// X words follow the reported arc mode ("D:"), the passes are streamed as job
+    ///
+    /// # Errors
+    /// Returns an error if the firmware lacks lathe mode or spindle sync or the job cannot be started
+    pub fn start_threading(&mut self, device_id: &String, plan: &ThreadingPlan, start_z: f32, end_z: f32, external: bool) -> Result<JobAnalysis, String> {
+        let device_info = self.get_device_info(device_id)?;
+        check_capability(device_id, &device_info, Capability::LatheMode)?;
+        check_capability(device_id, &device_info, Capability::SpindleSync)?;
+
+        let machine_info = match device_info.machine_info() {
+            Some(machine_info) => machine_info,
+            None => return Err(format!("Machine state of \"{}\" is unknown", device_id)),
+        };
+        let drive_line = match machine_info.local_position().and_then(|position| position.values().first().copied()) {
+            Some(x) => x,
+            None => return Err(format!("Work position of \"{}\" is unknown", device_id)),
+        };
+        let arc_mode = machine_info.arc_mode().copied().unwrap_or(ArcMode::Radius);
+        let program = plan.get_program(drive_line, start_z, end_z, external, arc_mode)?;
+        self.start_job(device_id, &program)
+    }
+}
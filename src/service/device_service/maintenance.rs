@@ -0,0 +1,52 @@
+use std::time::Duration;
+
+use crate::device::{command::general::{GET_ODOMETERS, GET_PID_LOG}, response::{firmware::capabilities::Capability, state::{odometer::Odometer, pid_log::PidLog}}};
+
+use super::{check_capability, DeviceHandle, DeviceService};
+
+/// Maximum time until the device has to respond with the maintenance data
+const MAINTENANCE_TIMEOUT: Duration = Duration::from_secs(5);
+
+impl DeviceHandle {
+
+    /// Clears the odometer data, a device without response must not return the previous data
+    fn reset_odometer(&self) {
+        self.device_info.lock().unwrap().reset_odometer();
+    }
+
+    /// Clears the PID log, a device without response must not return the previous log
+    fn reset_pid_log(&self) {
+        self.device_info.lock().unwrap().reset_pid_log();
+    }
+}
+
+impl DeviceService {
+
+    /// Requests the odometer data ("$ODOMETERS") and waits for the response
+    ///
+    /// # Errors
+    /// Returns an error if the firmware has no odometer ("ODO" extended compile option) or did not respond with the data
+    pub fn load_odometer(&mut self, device_id: &String) -> Result<Odometer, String> {
+        check_capability(device_id, &self.get_device_info(device_id)?, Capability::Odometer)?;
+        self.get_device_handle(device_id)?.reset_odometer();
+        let device_info = self.write_device_command_acknowledged(device_id, GET_ODOMETERS, MAINTENANCE_TIMEOUT)?;
+        match device_info.odometer() {
+            Some(odometer) => Ok(odometer.clone()),
+            None => Err(format!("\"{}\" did not report odometer data", device_id)),
+        }
+    }
+
+    /// Requests the spindle sync PID log ("$PIDL") of the last synchronized motion and waits for the response
+    ///
+    /// # Errors
+    /// Returns an error if the firmware has no PID log ("PID" extended compile option) or did not respond with the log
+    pub fn load_pid_log(&mut self, device_id: &String) -> Result<PidLog, String> {
+        check_capability(device_id, &self.get_device_info(device_id)?, Capability::PIDLog)?;
+        self.get_device_handle(device_id)?.reset_pid_log();
+        let device_info = self.write_device_command_acknowledged(device_id, GET_PID_LOG, MAINTENANCE_TIMEOUT)?;
+        match device_info.pid_log() {
+            Some(pid_log) => Ok(pid_log.clone()),
+            None => Err(format!("\"{}\" did not report a PID log", device_id)),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use std::time::Duration;
+
+use crate::device::{command::{state::GET_INFO, util::load_device_metadata_commands}, DeviceInfo};
+
+use super::DeviceService;
+
+/// Maximum time until the device has to answer a metadata command, descriptions ("$ES") are long
+const METADATA_TIMEOUT: Duration = Duration::from_secs(10);
+
+impl DeviceService {
+
+    /// Requests settings, firmware info and the current state supported by the detected firmware dialect
+    ///
+    /// "$I" is answered by all dialects and sent first, the dialect is detected afterwards in case the welcome
+    /// message was missed. Realtime commands and the trailing sync of the metadata commands are skipped,
+    /// each line is awaited instead.
+    ///
+    /// # Errors
+    /// Returns an error if any command was rejected or the device did not respond
+    pub fn load_device_metadata(&mut self, device_id: &String) -> Result<DeviceInfo, String> {
+        let mut device_info = self.write_device_command_acknowledged(device_id, GET_INFO, METADATA_TIMEOUT)?;
+        let dialect = device_info.firmware_info().dialect();
+        for command in load_device_metadata_commands(&dialect).iter().filter(|command| command.ends_with('\r') && command.as_str() != GET_INFO) {
+            device_info = self.write_device_command_acknowledged(device_id, command, METADATA_TIMEOUT)?;
+        }
+        Ok(device_info)
+    }
+}
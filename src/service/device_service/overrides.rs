@@ -0,0 +1,76 @@
+use std::time::Duration;
+
+use crate::device::{command::overrides::{get_feed_override_commands, get_rapid_override_commands, get_spindle_override_commands}, response::state::overrides::Overrides, DeviceInfo};
+
+use super::DeviceService;
+
+/// Maximum time until a status report has to confirm the override
+const OVERRIDE_CONFIRM_TIMEOUT: Duration = Duration::from_secs(1);
+
+impl DeviceService {
+
+    /// Sets the feed override to the absolute percentage (10% - 200%)
+    ///
+    /// Sends the shortest sequence of realtime commands and waits until a status report confirms the override
+    ///
+    /// # Errors
+    /// Returns an error if the current override is unknown, the target is invalid or the device did not confirm it
+    pub fn set_feed_override(&mut self, device_id: &String, percentage: i32) -> Result<(), String> {
+        let overrides = self.get_override_values(device_id)?;
+        let commands = get_feed_override_commands(overrides.feed_rate_percentage(), percentage)?;
+        self.write_override_commands(device_id, &commands, |overrides| overrides.feed_rate_percentage() == percentage)
+    }
+
+    /// Sets the rapid override to 100%, 50% or 25%
+    ///
+    /// Waits until a status report confirms the override
+    ///
+    /// # Errors
+    /// Returns an error if the current override is unknown, the target is invalid or the device did not confirm it
+    pub fn set_rapid_override(&mut self, device_id: &String, percentage: i32) -> Result<(), String> {
+        let overrides = self.get_override_values(device_id)?;
+        let commands = get_rapid_override_commands(overrides.rapids_percentage(), percentage)?;
+        self.write_override_commands(device_id, &commands, |overrides| overrides.rapids_percentage() == percentage)
+    }
+
+    /// Sets the spindle override to the absolute percentage (10% - 200%)
+    ///
+    /// Sends the shortest sequence of realtime commands and waits until a status report confirms the override
+    ///
+    /// # Errors
+    /// Returns an error if the current override is unknown, the target is invalid or the device did not confirm it
+    pub fn set_spindle_override(&mut self, device_id: &String, percentage: i32) -> Result<(), String> {
+        let overrides = self.get_override_values(device_id)?;
+        let commands = get_spindle_override_commands(overrides.spindle_speed_percentage(), percentage)?;
+        self.write_override_commands(device_id, &commands, |overrides| overrides.spindle_speed_percentage() == percentage)
+    }
+
+    /// Returns the last reported override values
+    fn get_override_values(&self, device_id: &String) -> Result<Overrides, String> {
+        match self.get_device_info(device_id)?.machine_info().and_then(|info| info.override_values()) {
+            Some(overrides) => Ok(*overrides),
+            None => Err(format!("Override values of \"{}\" are unknown", device_id)),
+        }
+    }
+
+    fn write_override_commands<F>(&mut self, device_id: &String, commands: &[u8], is_confirmed: F) -> Result<(), String>
+    where
+        F: Fn(&Overrides) -> bool,
+    {
+        if commands.is_empty() {
+            return Ok(());
+        }
+        self.write_device_bytes(device_id, commands)?;
+
+        let confirmation = self.wait_for_device_info(device_id, OVERRIDE_CONFIRM_TIMEOUT, |device_info: &DeviceInfo| {
+            match device_info.machine_info().and_then(|info| info.override_values()) {
+                Some(overrides) => is_confirmed(overrides),
+                None => false,
+            }
+        });
+        match confirmation {
+            Ok(_) => Ok(()),
+            Err(_) => Err(format!("Override change was not confirmed by \"{}\"", device_id)),
+        }
+    }
+}
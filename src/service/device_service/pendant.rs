@@ -0,0 +1,55 @@
+use std::time::Duration;
+
+use crate::device::{command::realtime::extended::{FULL_STATUS_REPORT, MPG_MODE_TOGGLE}, response::{firmware::capabilities::Capability, state::pendant::PendantControl}, DeviceInfo};
+
+use super::{check_capability, DeviceService};
+
+/// Maximum time until a status report has to confirm the pendant control
+const PENDANT_CONFIRM_TIMEOUT: Duration = Duration::from_secs(1);
+
+impl DeviceService {
+
+    /// Requests (`taken`) or releases the MPG mode with the realtime toggle (0x8B)
+    ///
+    /// The current mode is requested by a full status report (0x87) if "MPG:" was not reported yet.
+    /// While the pendant has control, jobs, sd card files and jogging are rejected.
+    ///
+    /// # Errors
+    /// Returns an error if the firmware has no MPG mode ("MPG" extended compile option)
+    /// or the device did not confirm the change
+    pub fn set_pendant_control(&mut self, device_id: &String, taken: bool) -> Result<(), String> {
+        check_capability(device_id, &self.get_device_info(device_id)?, Capability::MPG)?;
+        let target = match taken {
+            true => PendantControl::Taken,
+            false => PendantControl::Released,
+        };
+
+        let current = match get_pendant_control(&self.get_device_info(device_id)?) {
+            Some(current) => current,
+            None => {
+                self.write_device_bytes(device_id, &[FULL_STATUS_REPORT])?;
+                match self.wait_for_device_info(device_id, PENDANT_CONFIRM_TIMEOUT, |device_info| get_pendant_control(device_info).is_some()) {
+                    Ok(device_info) => get_pendant_control(&device_info).unwrap(),
+                    Err(_) => return Err(format!("Pendant control of \"{}\" is unknown", device_id)),
+                }
+            }
+        };
+        if current == target {
+            return Ok(());
+        }
+
+        self.write_device_bytes(device_id, &[MPG_MODE_TOGGLE])?;
+        let confirmation = self.wait_for_device_info(device_id, PENDANT_CONFIRM_TIMEOUT, |device_info| {
+            get_pendant_control(device_info) == Some(target)
+        });
+        match confirmation {
+            Ok(_) => Ok(()),
+            Err(_) => Err(format!("Pendant control change was not confirmed by \"{}\"", device_id)),
+        }
+    }
+}
+
+/// Returns the last reported pendant control ("MPG:")
+fn get_pendant_control(device_info: &DeviceInfo) -> Option<PendantControl> {
+    device_info.machine_info().and_then(|info| info.pendant_control()).copied()
+}
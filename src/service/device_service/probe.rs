@@ -0,0 +1,211 @@
+use std::time::Duration;
+
+use crate::device::{command::probe::{get_machine_move_command, get_probe_command, get_rapid_move_command, get_set_work_position_command, ProbeCorner, ProbeMode, ProbeSettings}, response::state::{machine::state::MachineStateName, signal::MachineSignal}, util::axis::Axis, DeviceInfo};
+
+use super::DeviceService;
+
+/// Maximum time of a single probing or positioning step
+const PROBE_TIMEOUT: Duration = Duration::from_secs(60);
+
+impl DeviceService {
+
+    /// Probes down onto a touch plate and sets Z of the active work coordinate system
+    ///
+    /// The top of the workpiece becomes Z0 if the plate lies on top of it
+    ///
+    /// # Errors
+    /// Returns an error if the probe is not ready, no contact was made or the device rejected a command
+    pub fn probe_z_touch_plate(&mut self, device_id: &String, settings: &ProbeSettings, plate_thickness: f32) -> Result<(), String> {
+        self.run_restoring_modal_state(device_id, PROBE_TIMEOUT, |service| {
+            let contact = service.probe_contact(device_id, Axis::Z, -1.0, settings)?;
+            let current = get_machine_coordinate(&service.get_device_info(device_id)?, &Axis::Z)?;
+
+            let command = get_set_work_position_command(None, &[(Axis::Z, plate_thickness + current - contact)]);
+            service.write_device_commands_sync(device_id, vec![command], PROBE_TIMEOUT)?;
+            Ok(())
+        })
+    }
+
+    /// Finds an outside corner and makes it X0 Y0 Z0 of the active work coordinate system
+    ///
+    /// The tool has to be above the workpiece, about `clearance` away from both edges.
+    /// The top surface is probed first, the edges are probed `depth` below the top surface.
+    ///
+    /// # Errors
+    /// Returns an error if the probe is not ready, no contact was made or the device rejected a command
+    pub fn probe_outside_corner(
+        &mut self,
+        device_id: &String,
+        settings: &ProbeSettings,
+        corner: ProbeCorner,
+        tool_diameter: f32,
+        clearance: f32,
+        depth: f32,
+    ) -> Result<(), String> {
+        self.run_restoring_modal_state(device_id, PROBE_TIMEOUT, |service| {
+            let top = service.probe_contact(device_id, Axis::Z, -1.0, settings)?;
+            let (x_direction, y_direction) = corner.probe_directions();
+            let approach = clearance + tool_diameter;
+            let depth = depth + settings.retract_distance();
+            let x_edge = service.probe_edge(device_id, settings, Axis::X, x_direction, approach, depth)? + x_direction * tool_diameter / 2.0;
+            let y_edge = service.probe_edge(device_id, settings, Axis::Y, y_direction, approach, depth)? + y_direction * tool_diameter / 2.0;
+
+            let device_info = service.get_device_info(device_id)?;
+            let target = [
+                (Axis::X, get_machine_coordinate(&device_info, &Axis::X)? - x_edge),
+                (Axis::Y, get_machine_coordinate(&device_info, &Axis::Y)? - y_edge),
+                (Axis::Z, get_machine_coordinate(&device_info, &Axis::Z)? - top),
+            ];
+            service.write_device_commands_sync(device_id, vec![get_set_work_position_command(None, &target)], PROBE_TIMEOUT)?;
+            Ok(())
+        })
+    }
+
+    /// Finds the center of a bore and makes it X0 Y0 of the active work coordinate system
+    ///
+    /// The tool has to be lowered into the bore near its center.
+    /// Returns the measured diameter of the bore.
+    ///
+    /// # Errors
+    /// Returns an error if the probe is not ready, no contact was made or the device rejected a command
+    pub fn probe_bore_center(&mut self, device_id: &String, settings: &ProbeSettings, tool_diameter: f32) -> Result<f32, String> {
+        self.run_restoring_modal_state(device_id, PROBE_TIMEOUT, |service| {
+            let mut diameter = 0.0;
+            for axis in [Axis::X, Axis::Y] {
+                let positive = service.probe_contact(device_id, axis, 1.0, settings)?;
+                let negative = service.probe_contact(device_id, axis, -1.0, settings)?;
+                service.write_device_commands_sync(device_id, vec![get_machine_move_command(&[(axis, (positive + negative) / 2.0)])], PROBE_TIMEOUT)?;
+                diameter = positive - negative + tool_diameter;
+            }
+
+            let command = get_set_work_position_command(None, &[(Axis::X, 0.0), (Axis::Y, 0.0)]);
+            service.write_device_commands_sync(device_id, vec![command], PROBE_TIMEOUT)?;
+            Ok(diameter)
+        })
+    }
+
+    /// Finds the center of a boss and makes it X0 Y0 of the active work coordinate system
+    ///
+    /// The tool has to be above the center of the boss, each side is probed `depth` below the tool.
+    /// Returns the measured diameter of the boss.
+    ///
+    /// # Errors
+    /// Returns an error if the probe is not ready, no contact was made or the device rejected a command
+    pub fn probe_boss_center(
+        &mut self,
+        device_id: &String,
+        settings: &ProbeSettings,
+        approximate_diameter: f32,
+        tool_diameter: f32,
+        clearance: f32,
+        depth: f32,
+    ) -> Result<f32, String> {
+        self.run_restoring_modal_state(device_id, PROBE_TIMEOUT, |service| {
+            let approach = approximate_diameter / 2.0 + clearance + tool_diameter / 2.0;
+            let mut diameter = 0.0;
+            for axis in [Axis::X, Axis::Y] {
+                let negative = service.probe_edge(device_id, settings, axis, 1.0, approach, depth)?;
+                let positive = service.probe_edge(device_id, settings, axis, -1.0, approach, depth)?;
+                service.write_device_commands_sync(device_id, vec![get_machine_move_command(&[(axis, (positive + negative) / 2.0)])], PROBE_TIMEOUT)?;
+                diameter = positive - negative - tool_diameter;
+            }
+
+            let command = get_set_work_position_command(None, &[(Axis::X, 0.0), (Axis::Y, 0.0)]);
+            service.write_device_commands_sync(device_id, vec![command], PROBE_TIMEOUT)?;
+            Ok(diameter)
+        })
+    }
+
+    /// Probes along the axis with a fast and a slow pass and returns the machine coordinate of the contact
+    ///
+    /// The tool is retracted from the contact afterwards and the modal state (units, distance mode, motion mode) is restored
+    ///
+    /// # Errors
+    /// Returns an error if the probe is not ready, no contact was made or the device rejected a command
+    pub fn probe_axis(&mut self, device_id: &String, axis: Axis, direction: f32, settings: &ProbeSettings) -> Result<f32, String> {
+        self.run_restoring_modal_state(device_id, PROBE_TIMEOUT, |service| service.probe_contact(device_id, axis, direction, settings))
+    }
+
+    /// Probes along the axis after checking the probe, without restoring the modal state
+    fn probe_contact(&mut self, device_id: &String, axis: Axis, direction: f32, settings: &ProbeSettings) -> Result<f32, String> {
+        check_probe_ready(device_id, &self.get_device_info(device_id)?)?;
+        self.probe_axis_passes(device_id, axis, direction.signum(), settings)
+    }
+
+    fn probe_axis_passes(&mut self, device_id: &String, axis: Axis, direction: f32, settings: &ProbeSettings) -> Result<f32, String> {
+        let retract = get_rapid_move_command(&[(axis, -direction * settings.retract_distance())]);
+        let fast_probe = get_probe_command(ProbeMode::Toward, &axis, direction * settings.max_distance(), settings.fast_feed_rate());
+        let slow_probe = get_probe_command(ProbeMode::Toward, &axis, direction * 2.0 * settings.retract_distance(), settings.slow_feed_rate());
+
+        let device_info = self.write_device_commands_sync(device_id, vec![fast_probe], PROBE_TIMEOUT)?;
+        get_probe_contact(device_id, &device_info, &axis)?;
+        self.write_device_commands_sync(device_id, vec![retract.clone()], PROBE_TIMEOUT)?;
+
+        let device_info = self.write_device_commands_sync(device_id, vec![slow_probe], PROBE_TIMEOUT)?;
+        let contact = get_probe_contact(device_id, &device_info, &axis)?;
+        self.write_device_commands_sync(device_id, vec![retract], PROBE_TIMEOUT)?;
+        Ok(contact)
+    }
+
+    /// Moves sideways by the approach distance, lowers the tool and probes back towards the start
+    ///
+    /// Returns the machine coordinate of the contact, the tool is moved back to the start afterwards
+    fn probe_edge(&mut self, device_id: &String, settings: &ProbeSettings, axis: Axis, direction: f32, approach: f32, depth: f32) -> Result<f32, String> {
+        let device_info = self.get_device_info(device_id)?;
+        let start = [
+            (Axis::X, get_machine_coordinate(&device_info, &Axis::X)?),
+            (Axis::Y, get_machine_coordinate(&device_info, &Axis::Y)?),
+            (Axis::Z, get_machine_coordinate(&device_info, &Axis::Z)?),
+        ];
+
+        self.write_device_commands_sync(device_id, vec![get_rapid_move_command(&[(axis, -direction * approach)])], PROBE_TIMEOUT)?;
+
+        // lower the tool with a probing move to stop on unexpected contact
+        let lower = get_probe_command(ProbeMode::TowardNoError, &Axis::Z, -depth.abs(), settings.fast_feed_rate());
+        let device_info = self.write_device_commands_sync(device_id, vec![lower], PROBE_TIMEOUT)?;
+        if device_info.probe_result().map(|result| result.succeeded()).unwrap_or(false) {
+            self.write_device_commands_sync(device_id, vec![get_machine_move_command(&start[2..])], PROBE_TIMEOUT)?;
+            return Err(format!("Probe of \"{}\" made contact while lowering", device_id));
+        }
+
+        let contact = self.probe_contact(device_id, axis, direction, settings);
+        self.write_device_commands_sync(device_id, vec![get_machine_move_command(&start[2..])], PROBE_TIMEOUT)?;
+        self.write_device_commands_sync(device_id, vec![get_machine_move_command(&start[..2])], PROBE_TIMEOUT)?;
+        contact
+    }
+}
+
+/// Checks that the device is idle and the probe is connected and not triggered
+fn check_probe_ready(device_id: &String, device_info: &DeviceInfo) -> Result<(), String> {
+    match device_info.machine_state_name() {
+        Some(MachineStateName::Idle) => (),
+        Some(_) => return Err(format!("\"{}\" can only probe while idle", device_id)),
+        None => return Err(format!("Machine state of \"{}\" is unknown", device_id)),
+    }
+
+    let signals = device_info.machine_info().and_then(|info| info.signal_set()).unwrap_or_default();
+    if signals.contains(&MachineSignal::ProbeDisconnected) {
+        return Err(format!("Probe of \"{}\" is disconnected", device_id));
+    }
+    if signals.contains(&MachineSignal::ProbeTriggered) {
+        return Err(format!("Probe of \"{}\" is already triggered", device_id));
+    }
+    Ok(())
+}
+
+fn get_probe_contact(device_id: &String, device_info: &DeviceInfo, axis: &Axis) -> Result<f32, String> {
+    match device_info.probe_result() {
+        Some(result) if result.succeeded() => match result.position().get(axis) {
+            Some(contact) => Ok(contact),
+            None => Err(format!("Probe result of \"{}\" misses the probed axis", device_id)),
+        },
+        _ => Err(format!("Probe of \"{}\" made no contact", device_id)),
+    }
+}
+
+fn get_machine_coordinate(device_info: &DeviceInfo, axis: &Axis) -> Result<f32, String> {
+    match device_info.machine_info().and_then(|info| info.global_position()).and_then(|position| position.get(axis)) {
+        Some(coordinate) => Ok(coordinate),
+        None => Err(format!("Machine position of \"{}\" is unknown", device_info.id())),
+    }
+}
@@ -0,0 +1,193 @@
+use std::{cell::Cell, thread, time::{Duration, Instant}};
+
+use crate::device::{command::sdcard::{get_file_command, DIR, DUMP, MOUNT, RUN, UNLINK}, response::{firmware::capabilities::Capability, state::{machine::state::MachineStateName, sdcard::{SdCardListing, SdCardProgress}}}, DeviceInfo};
+use crate::job::progress::{JobState, UploadProgress};
+
+use super::{check_capability, check_pendant_released, DeviceHandle, DeviceService};
+
+/// Maximum time until the device has to acknowledge a sd card command
+const SD_CARD_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Maximum time to list a directory or dump a file
+const SD_CARD_TRANSFER_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Interval between checks of the upload progress
+///
+/// Status reports are not requested while uploading, the device would read them as file content
+const UPLOAD_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+impl DeviceHandle {
+
+    /// Clears the listing before the files of a new listing are received
+    fn reset_sd_card_listing(&self) {
+        self.device_info.lock().unwrap().reset_sd_card_listing();
+    }
+
+    /// Hands the file over to the device thread which sends it with YMODEM
+    fn start_upload(&self, file_name: &str, data: Vec<u8>) -> Result<(), String> {
+        let mut device_info = self.device_info.lock().unwrap();
+        if let Some(progress) = device_info.upload_progress() {
+            if progress.is_running() {
+                return Err(format!("An upload is already running on \"{}\"", self.device_id));
+            }
+        }
+        device_info.set_upload_progress(UploadProgress::new(file_name, data.len()));
+
+        match &self.tx_upload {
+            Some(tx) => match tx.send((file_name.to_string(), data)) {
+                Ok(_) => Ok(()),
+                Err(_) => Err(format!("Cannot send upload to \"{}\"", self.device_id)),
+            },
+            None => panic!("Upload channel to \"{}\" closed", self.device_id),
+        }
+    }
+}
+
+impl DeviceService {
+
+    /// Mounts the sd card ("$FM")
+    ///
+    /// # Errors
+    /// Returns an error if the device does not support sd card streaming or rejected the command
+    pub fn mount_sd_card(&mut self, device_id: &String) -> Result<(), String> {
+        check_sd_card_enabled(device_id, &self.get_device_info(device_id)?)?;
+        self.write_device_command_acknowledged(device_id, MOUNT, SD_CARD_TIMEOUT)?;
+        Ok(())
+    }
+
+    /// Lists the files of the mounted sd card ("$F")
+    ///
+    /// # Errors
+    /// Returns an error if the device does not support sd card streaming or rejected the command
+    pub fn list_sd_card_files(&mut self, device_id: &String) -> Result<SdCardListing, String> {
+        check_sd_card_enabled(device_id, &self.get_device_info(device_id)?)?;
+        self.get_device_handle(device_id)?.reset_sd_card_listing();
+        let device_info = self.write_device_command_acknowledged(device_id, DIR, SD_CARD_TRANSFER_TIMEOUT)?;
+        Ok(device_info.sd_card_listing().clone())
+    }
+
+    /// Starts the file while idle ("$F=\<file\>"), the progress is reported by [`DeviceService::get_sd_card_progress`]
+    ///
+    /// # Errors
+    /// Returns an error if the device does not support sd card streaming, the pendant has control,
+    /// the device is not idle or rejected the file
+    pub fn run_sd_card_file(&mut self, device_id: &String, file_name: &str) -> Result<(), String> {
+        let device_info = self.get_device_info(device_id)?;
+        check_sd_card_enabled(device_id, &device_info)?;
+        check_pendant_released(device_id, &device_info)?;
+        if device_info.machine_state_name() != Some(MachineStateName::Idle) {
+            return Err(format!("Sd card file can only be started while \"{}\" is idle", device_id));
+        }
+        self.write_device_command_acknowledged(device_id, &get_file_command(RUN, file_name), SD_CARD_TIMEOUT)?;
+        Ok(())
+    }
+
+    /// Returns the progress of the running sd card file or `None` if no file is running
+    pub fn get_sd_card_progress(&self, device_id: &String) -> Result<Option<SdCardProgress>, String> {
+        let device_info = self.get_device_info(device_id)?;
+        Ok(device_info.machine_info().and_then(|info| info.sd_card_progress()).cloned())
+    }
+
+    /// Waits until the running sd card file is finished
+    ///
+    /// Has to be called while the file is running, the file has to be reported with "SD:" before it is finished
+    ///
+    /// # Errors
+    /// Returns an error if the device entered alarm state or the file is still running after the timeout
+    pub fn wait_for_sd_card_file(&mut self, device_id: &String, timeout: Duration) -> Result<(), String> {
+        // the file may not be reported as running in the first reports after it was started
+        let running_seen = Cell::new(false);
+        let device_info = self.wait_for_device_info(device_id, timeout, |device_info| {
+            match device_info.machine_info() {
+                Some(machine_info) => {
+                    if machine_info.sd_card_progress().is_some() {
+                        running_seen.set(true);
+                    }
+                    match machine_info.machine_state().status() {
+                        MachineStateName::Alarm => true,
+                        MachineStateName::Idle => running_seen.get() && machine_info.sd_card_progress().is_none(),
+                        _ => false,
+                    }
+                }
+                None => false,
+            }
+        })?;
+        match device_info.machine_state_name() {
+            Some(MachineStateName::Alarm) => Err(format!("\"{}\" entered alarm state while running the sd card file", device_id)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Uploads the file to the sd card with YMODEM and verifies its size in the directory listing
+    ///
+    /// The progress is reported by [`DeviceService::get_upload_progress`] while the upload is running,
+    /// the uploaded file can be started with [`DeviceService::run_sd_card_file`]
+    ///
+    /// # Errors
+    /// Returns an error if the device does not support sd card streaming, is not idle,
+    /// the transfer failed or the listed file size differs
+    pub fn upload_sd_card_file(&mut self, device_id: &String, file_name: &str, data: Vec<u8>, timeout: Duration) -> Result<(), String> {
+        let device_info = self.get_device_info(device_id)?;
+        check_sd_card_enabled(device_id, &device_info)?;
+        if device_info.machine_state_name() != Some(MachineStateName::Idle) {
+            return Err(format!("Sd card file can only be uploaded while \"{}\" is idle", device_id));
+        }
+
+        let size = data.len() as u64;
+        self.get_device_handle(device_id)?.start_upload(file_name, data)?;
+        let upload_start = Instant::now();
+        let progress = loop {
+            thread::sleep(UPLOAD_POLL_INTERVAL);
+            match self.get_upload_progress(device_id)? {
+                Some(progress) if progress.is_running() => (),
+                Some(progress) => break progress,
+                None => return Err(format!("Upload to \"{}\" was not started", device_id)),
+            }
+            if upload_start.elapsed() >= timeout {
+                return Err(format!("Timeout while uploading \"{}\" to \"{}\"", file_name, device_id));
+            }
+        };
+        if let JobState::Failed(reason) = progress.state() {
+            return Err(format!("Upload of \"{}\" to \"{}\" failed: {}", file_name, device_id, reason));
+        }
+
+        let listing = self.list_sd_card_files(device_id)?;
+        match listing.get_file(file_name) {
+            Some(file) if file.size() == size => Ok(()),
+            Some(file) => Err(format!("\"{}\" has {} instead of {} bytes on \"{}\"", file_name, file.size(), size, device_id)),
+            None => Err(format!("\"{}\" is not listed on \"{}\" after the upload", file_name, device_id)),
+        }
+    }
+
+    /// Returns the progress of the last sd card upload or `None` if no file was uploaded
+    pub fn get_upload_progress(&self, device_id: &String) -> Result<Option<UploadProgress>, String> {
+        Ok(self.get_device_info(device_id)?.upload_progress().cloned())
+    }
+
+    /// Deletes the file from the sd card ("$FD=\<file\>")
+    ///
+    /// # Errors
+    /// Returns an error if the device does not support sd card streaming or rejected the command
+    pub fn delete_sd_card_file(&mut self, device_id: &String, file_name: &str) -> Result<(), String> {
+        check_sd_card_enabled(device_id, &self.get_device_info(device_id)?)?;
+        self.write_device_command_acknowledged(device_id, &get_file_command(UNLINK, file_name), SD_CARD_TIMEOUT)?;
+        Ok(())
+    }
+
+    /// Returns the lines of the file on the sd card ("$F<=\<file\>")
+    ///
+    /// The dump is collected by the device thread after the command was written, push messages ("[MSG:]") are skipped
+    ///
+    /// # Errors
+    /// Returns an error if the device does not support sd card streaming or rejected the command
+    pub fn dump_sd_card_file(&mut self, device_id: &String, file_name: &str) -> Result<Vec<String>, String> {
+        check_sd_card_enabled(device_id, &self.get_device_info(device_id)?)?;
+        let device_info = self.write_device_command_acknowledged(device_id, &get_file_command(DUMP, file_name), SD_CARD_TRANSFER_TIMEOUT)?;
+        Ok(device_info.sd_card_dump().cloned().unwrap_or_default())
+    }
+}
+
+/// Checks for the extended compile option "SD" ([`Capability::SDCard`])
+fn check_sd_card_enabled(device_id: &String, device_info: &DeviceInfo) -> Result<(), String> {
+    check_capability(device_id, device_info, Capability::SDCard)
+}
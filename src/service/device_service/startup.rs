@@ -0,0 +1,62 @@
+use std::time::Duration;
+
+use crate::device::{command::{general::GET_STARTUP_LINES, startup::{get_clear_startup_line_command, get_set_startup_line_command}}, response::{firmware::startup::StartupLines, state::machine::state::MachineStateName}};
+
+use super::DeviceService;
+
+/// Maximum time until the device has to acknowledge a startup line command
+const STARTUP_LINE_TIMEOUT: Duration = Duration::from_secs(5);
+
+impl DeviceService {
+
+    /// Requests the startup lines ("$N") and waits for the response
+    ///
+    /// # Errors
+    /// Returns an error if the device did not respond
+    pub fn load_startup_lines(&mut self, device_id: &String) -> Result<StartupLines, String> {
+        let device_info = self.write_device_command_acknowledged(device_id, GET_STARTUP_LINES, STARTUP_LINE_TIMEOUT)?;
+        Ok(device_info.startup_lines().clone())
+    }
+
+    /// Stores the line as startup line ("$N\<index\>=\<line\>") and confirms it with "$N"
+    ///
+    /// The device executes the line once to validate it, therefore it has to be idle or in alarm state
+    ///
+    /// # Errors
+    /// Returns an error if the line is invalid, the device is busy, rejected the line or "$N" does not confirm it
+    pub fn set_startup_line(&mut self, device_id: &String, index: u32, line: &str) -> Result<(), String> {
+        let command = get_set_startup_line_command(index, line)?;
+        self.write_startup_line(device_id, index, &command, line)
+    }
+
+    /// Clears the startup line ("$N\<index\>=") and confirms it with "$N"
+    ///
+    /// # Errors
+    /// Returns an error if the index is invalid, the device is busy, rejected the command or "$N" does not confirm it
+    pub fn clear_startup_line(&mut self, device_id: &String, index: u32) -> Result<(), String> {
+        let command = get_clear_startup_line_command(index)?;
+        self.write_startup_line(device_id, index, &command, "")
+    }
+
+    fn write_startup_line(&mut self, device_id: &String, index: u32, command: &str, line: &str) -> Result<(), String> {
+        let device_info = self.get_device_info(device_id)?;
+        match device_info.machine_state_name() {
+            Some(MachineStateName::Idle) | Some(MachineStateName::Alarm) => (),
+            _ => return Err(format!("Startup lines of \"{}\" can only be changed while idle or in alarm state", device_id)),
+        }
+
+        self.write_device_command_acknowledged(device_id, command, STARTUP_LINE_TIMEOUT)?;
+        let startup_lines = self.load_startup_lines(device_id)?;
+
+        // the device stores the line without spaces
+        let expected_line = get_normalized_line(line);
+        match startup_lines.get(index) {
+            Some(startup_line) if get_normalized_line(startup_line.line()) == expected_line => Ok(()),
+            _ => Err(format!("Startup line {} of \"{}\" was not updated", index, device_id)),
+        }
+    }
+}
+
+fn get_normalized_line(line: &str) -> String {
+    line.chars().filter(|character| !character.is_whitespace()).collect::<String>().to_uppercase()
+}
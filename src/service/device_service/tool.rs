@@ -0,0 +1,205 @@
+use std::time::Duration;
+
+use crate::device::{command::{general::GET_NGC_PARAMETERS, realtime::CYCLE_START, tool::{get_set_tool_length_offset_command, get_set_tool_offset_command, get_set_tool_offset_from_position_command, get_tool_change_command, PROBE_TOOL_LENGTH}}, response::{firmware::capabilities::Capability, state::{machine::state::MachineStateName, tool_table::{ToolTable, ToolTableEntry}}}, util::axis::Axis, DeviceInfo};
+
+use super::{check_capability, DeviceHandle, DeviceService};
+
+/// Maximum time until the device has to acknowledge a tool length command
+const TOOL_LENGTH_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Maximum time of probing the tool length or an automatic tool change
+const TOOL_CHANGE_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Maximum time until a status report has to confirm the end of the tool change
+const TOOL_CHANGE_CONFIRM_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Maximum time until "$#" has to confirm a tool table change
+const TOOL_TABLE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Maximum deviation in mm between a written and a reported tool offset
+const TOOL_OFFSET_TOLERANCE: f32 = 0.01;
+
+impl DeviceHandle {
+
+    /// Clears the tool table before the entries of a new "$#" response are received
+    fn reset_tool_table(&self) {
+        self.device_info.lock().unwrap().reset_tool_table();
+    }
+}
+
+impl DeviceService {
+
+    /// Sets the dynamic tool length offset in mm ("G43.1")
+    ///
+    /// Can be used while idle or during a manual tool change ("Tool" state), the modal state is restored afterwards
+    ///
+    /// # Errors
+    /// Returns an error if the device rejected the command
+    pub fn set_tool_length_offset(&mut self, device_id: &String, offset: f32) -> Result<(), String> {
+        let command = get_set_tool_length_offset_command(offset);
+        self.run_restoring_modal_state(device_id, TOOL_LENGTH_TIMEOUT, |service| {
+            service.write_device_command_acknowledged(device_id, &command, TOOL_LENGTH_TIMEOUT)
+        })?;
+        Ok(())
+    }
+
+    /// Probes the tool length with the tool setter ("$TPW") and waits until it is applied
+    ///
+    /// # Errors
+    /// Returns an error if the device is not idle or in "Tool" state or the probing failed
+    pub fn probe_tool_length(&mut self, device_id: &String) -> Result<(), String> {
+        let device_info = self.get_device_info(device_id)?;
+        match device_info.machine_state_name() {
+            Some(MachineStateName::Idle) | Some(MachineStateName::Tool) => (),
+            _ => return Err(format!("Tool length of \"{}\" can only be probed while idle or changing tools", device_id)),
+        }
+        self.write_device_command_acknowledged(device_id, PROBE_TOOL_LENGTH, TOOL_CHANGE_TIMEOUT)?;
+        Ok(())
+    }
+
+    /// Finishes a manual tool change with cycle start and waits until the device left the "Tool" state
+    ///
+    /// # Errors
+    /// Returns an error if no tool change is active or the device did not resume
+    pub fn resume_after_tool_change(&mut self, device_id: &String) -> Result<(), String> {
+        let device_info = self.get_device_info(device_id)?;
+        if device_info.machine_state_name() != Some(MachineStateName::Tool) {
+            return Err(format!("\"{}\" is not changing tools", device_id));
+        }
+
+        self.write_device_command(device_id, CYCLE_START)?;
+        let confirmation = self.wait_for_device_info(device_id, TOOL_CHANGE_CONFIRM_TIMEOUT, |device_info| {
+            device_info.machine_state_name() != Some(MachineStateName::Tool)
+        });
+        match confirmation {
+            Ok(_) => Ok(()),
+            Err(_) => Err(format!("\"{}\" did not resume after the tool change", device_id)),
+        }
+    }
+
+    /// Changes the tool with the automatic tool changer ("T\<tool\> M6") and waits until it is done
+    ///
+    /// Requires the extended compile option "ATC" ([`Capability::AutomaticToolChange`]),
+    /// manual tool changes are reported with [`crate::device::event::DeviceEvent::ToolChangeRequested`]
+    ///
+    /// # Errors
+    /// Returns an error if the device has no tool changer, is not idle, rejected the command or reports another tool
+    pub fn change_tool(&mut self, device_id: &String, tool: u32) -> Result<(), String> {
+        let device_info = self.get_device_info(device_id)?;
+        check_capability(device_id, &device_info, Capability::AutomaticToolChange)?;
+        if device_info.machine_state_name() != Some(MachineStateName::Idle) {
+            return Err(format!("Tool of \"{}\" can only be changed while idle", device_id));
+        }
+
+        let device_info = self.write_device_commands_sync(device_id, vec![get_tool_change_command(tool)], TOOL_CHANGE_TIMEOUT)?;
+        match device_info.machine_info().and_then(|info| info.current_tool()) {
+            Some(current_tool) if current_tool != tool => Err(format!("\"{}\" reports tool {} after changing to tool {}", device_id, current_tool, tool)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Requests the tool table ("$#") and waits for the response
+    ///
+    /// Previous entries are removed, tools deleted on the device are not reported anymore
+    /// # Errors
+    /// Returns an error if the device did not respond
+    pub fn load_tool_table(&mut self, device_id: &String) -> Result<ToolTable, String> {
+        self.get_device_handle(device_id)?.reset_tool_table();
+        let device_info = self.write_device_commands_sync(device_id, vec![GET_NGC_PARAMETERS.to_string()], TOOL_TABLE_TIMEOUT)?;
+        Ok(device_info.tool_table().clone())
+    }
+
+    /// Sets offset and radius of the tool table entry ("G10 L1") and confirms it with "$#"
+    ///
+    /// # Errors
+    /// Returns an error if the tool is not in the tool table, the device rejected the command or "$#" does not confirm it
+    pub fn set_tool_offset(&mut self, device_id: &String, tool: u32, offset: &[(Axis, f32)], radius: Option<f32>) -> Result<(), String> {
+        check_tool_number(device_id, &self.get_device_info(device_id)?, tool)?;
+        let commands = vec![get_set_tool_offset_command(tool, offset, radius), GET_NGC_PARAMETERS.to_string()];
+        let device_info = self.run_restoring_modal_state(device_id, TOOL_TABLE_TIMEOUT, |service| {
+            service.write_device_commands_sync(device_id, commands, TOOL_TABLE_TIMEOUT)
+        })?;
+        confirm_tool_table_entry(device_id, &device_info, tool, offset, radius)
+    }
+
+    /// Sets the tool offset so that the current position gets the target coordinates ("G10 L10")
+    ///
+    /// # Errors
+    /// Returns an error if the tool is not in the tool table, the device rejected the command or "$#" does not report the tool
+    pub fn set_tool_offset_from_position(&mut self, device_id: &String, tool: u32, target: &[(Axis, f32)]) -> Result<(), String> {
+        check_tool_number(device_id, &self.get_device_info(device_id)?, tool)?;
+        let commands = vec![get_set_tool_offset_from_position_command(tool, target), GET_NGC_PARAMETERS.to_string()];
+        let device_info = self.run_restoring_modal_state(device_id, TOOL_TABLE_TIMEOUT, |service| {
+            service.write_device_commands_sync(device_id, commands, TOOL_TABLE_TIMEOUT)
+        })?;
+        confirm_tool_table_entry(device_id, &device_info, tool, &[], None)
+    }
+
+    /// Loads the tool table and returns it as CSV
+    ///
+    /// # Errors
+    /// Returns an error if the device did not respond
+    pub fn export_tool_table(&mut self, device_id: &String) -> Result<String, String> {
+        Ok(self.load_tool_table(device_id)?.to_csv())
+    }
+
+    /// Writes all entries of the CSV tool table to the device and confirms them with "$#"
+    ///
+    /// # Errors
+    /// Returns an error if the CSV cannot be read, a tool is not in the tool table of the device,
+    /// the device rejected a command or "$#" does not confirm all entries
+    pub fn import_tool_table(&mut self, device_id: &String, csv: &str) -> Result<(), String> {
+        let tool_table = ToolTable::from_csv(csv)?;
+        let device_info = self.get_device_info(device_id)?;
+        let mut commands = Vec::new();
+        for entry in tool_table.entries() {
+            check_tool_number(device_id, &device_info, entry.tool())?;
+            commands.push(get_set_tool_offset_command(entry.tool(), &get_offset_values(entry), Some(entry.radius())));
+        }
+        commands.push(GET_NGC_PARAMETERS.to_string());
+
+        let device_info = self.run_restoring_modal_state(device_id, TOOL_TABLE_TIMEOUT, |service| {
+            service.write_device_commands_sync(device_id, commands, TOOL_TABLE_TIMEOUT)
+        })?;
+        for entry in tool_table.entries() {
+            confirm_tool_table_entry(device_id, &device_info, entry.tool(), &get_offset_values(entry), Some(entry.radius()))?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks the tool number against the tool table size of the compile options ("[OPT:...]")
+fn check_tool_number(device_id: &String, device_info: &DeviceInfo, tool: u32) -> Result<(), String> {
+    let entries_count = device_info.firmware_info().compile_options().and_then(|options| options.tool_table_entries_count());
+    match entries_count {
+        Some(entries_count) if tool >= 1 && tool as i32 <= entries_count => Ok(()),
+        Some(entries_count) => Err(format!("Tool {} is not in the tool table of \"{}\" (1 - {})", tool, device_id, entries_count)),
+        None => Err(format!("\"{}\" has no tool table", device_id)),
+    }
+}
+
+/// Checks that "$#" reported the tool with the expected values
+///
+/// Entries are written in mm (G21) and the tool table is stored in mm regardless of the report units ($13)
+fn confirm_tool_table_entry(device_id: &String, device_info: &DeviceInfo, tool: u32, offset: &[(Axis, f32)], radius: Option<f32>) -> Result<(), String> {
+    let entry = match device_info.tool_table().get(tool) {
+        Some(entry) => entry,
+        None => return Err(format!("Tool {} of \"{}\" is not reported", tool, device_id)),
+    };
+    let offset_confirmed = offset.iter().all(|(axis, value)| match entry.offset().get(axis) {
+        Some(reported) => (reported - value).abs() <= TOOL_OFFSET_TOLERANCE,
+        None => false,
+    });
+    let radius_confirmed = match radius {
+        Some(radius) => (entry.radius() - radius).abs() <= TOOL_OFFSET_TOLERANCE,
+        None => true,
+    };
+    match offset_confirmed && radius_confirmed {
+        true => Ok(()),
+        false => Err(format!("Tool {} of \"{}\" was not updated", tool, device_id)),
+    }
+}
+
+fn get_offset_values(entry: &ToolTableEntry) -> Vec<(Axis, f32)> {
+    entry.offset().iter().collect()
+}
@@ -0,0 +1,20 @@
+use grbli::device::command::accessory::{get_coolant_commands, get_spindle_start_command, get_spindle_stop_command, SpindleDirection};
+
+
+
+#[test]
+fn get_spindle_start_command_selects_spindle() {
+    assert_eq!("M3 S1000 $2\r", get_spindle_start_command(SpindleDirection::Clockwise, 1000.0, Some(2)));
+    assert_eq!("M4 S250.5\r", get_spindle_start_command(SpindleDirection::CounterClockwise, 250.5, None));
+}
+
+#[test]
+fn get_spindle_stop_command_selects_spindle() {
+    assert_eq!("M5\r", get_spindle_stop_command(None));
+    assert_eq!("M5 $1\r", get_spindle_stop_command(Some(1)));
+}
+
+#[test]
+fn get_coolant_commands_sets_each_coolant_in_own_line() {
+    assert_eq!(vec!["M9\r", "M7\r"], get_coolant_commands(false, true));
+}
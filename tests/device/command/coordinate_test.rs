@@ -0,0 +1,22 @@
+use grbli::device::{command::coordinate::{get_go_to_stored_position_command, get_select_coordinate_system_command, get_set_coordinate_offset_command, get_store_position_command, StoredPosition}, util::{axis::Axis, coordinate::CoordinateSystem}};
+
+
+
+#[test]
+fn get_set_coordinate_offset_command_uses_parameter_number() {
+    assert_eq!("G21 G10 L2 P1 Z-12.250\r", get_set_coordinate_offset_command(&CoordinateSystem::G54, &[(Axis::Z, -12.25)]));
+    assert_eq!("G21 G10 L2 P9 X1.000\r", get_set_coordinate_offset_command(&CoordinateSystem::G59_3, &[(Axis::X, 1.0)]));
+}
+
+#[test]
+fn get_select_coordinate_system_command_uses_name() {
+    assert_eq!("G59.2\r", get_select_coordinate_system_command(&CoordinateSystem::G59_2));
+}
+
+#[test]
+fn stored_position_commands() {
+    assert_eq!("G28.1\r", get_store_position_command(StoredPosition::Home));
+    assert_eq!("G30.1\r", get_store_position_command(StoredPosition::SecondaryHome));
+    assert_eq!("G28\r", get_go_to_stored_position_command(StoredPosition::Home));
+    assert_eq!("G30\r", get_go_to_stored_position_command(StoredPosition::SecondaryHome));
+}
@@ -0,0 +1,8 @@
+use grbli::device::{command::homing::get_home_commands, util::axis::Axis};
+
+
+
+#[test]
+fn get_home_commands_homes_each_axis_separately() {
+    assert_eq!(vec!["$HA\r", "$HB\r", "$HC\r", "$HY\r"], get_home_commands(&[Axis::A, Axis::B, Axis::C, Axis::Y]));
+}
@@ -0,0 +1,27 @@
+use std::time::Duration;
+
+use grbli::device::command::io::{get_analog_output_command, get_digital_output_command, get_wait_for_input_command, AuxInput, InputWaitMode, OutputMode};
+
+
+
+#[test]
+fn get_digital_output_command_selects_synchronized_or_immediate_code() {
+    assert_eq!("M62 P1\r", get_digital_output_command(1, true, OutputMode::Synchronized));
+    assert_eq!("M63 P1\r", get_digital_output_command(1, false, OutputMode::Synchronized));
+    assert_eq!("M64 P3\r", get_digital_output_command(3, true, OutputMode::Immediate));
+    assert_eq!("M65 P3\r", get_digital_output_command(3, false, OutputMode::Immediate));
+}
+
+#[test]
+fn get_analog_output_command_selects_synchronized_or_immediate_code() {
+    assert_eq!("M67 E0 Q50\r", get_analog_output_command(0, 50.0, OutputMode::Synchronized));
+    assert_eq!("M68 E2 Q0.5\r", get_analog_output_command(2, 0.5, OutputMode::Immediate));
+}
+
+#[test]
+fn get_wait_for_input_command_rejects_waiting_on_analog_input() {
+    let timeout = Duration::from_millis(1500);
+    assert_eq!("M66 P2 L1 Q1.500\r", get_wait_for_input_command(AuxInput::Digital(2), InputWaitMode::Rise, timeout).unwrap());
+    assert_eq!("M66 E0 L0 Q1.500\r", get_wait_for_input_command(AuxInput::Analog(0), InputWaitMode::Immediate, timeout).unwrap());
+    assert!(get_wait_for_input_command(AuxInput::Analog(0), InputWaitMode::High, timeout).is_err());
+}
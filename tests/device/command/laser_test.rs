@@ -0,0 +1,27 @@
+use std::time::Duration;
+
+use grbli::device::command::laser::{get_frame_commands, get_test_fire_commands};
+
+
+
+#[test]
+fn get_frame_commands_traces_rectangle_with_dynamic_power() {
+    let commands = get_frame_commands((-5.0, 0.0), (5.0, 2.5), 25.0, 1500.0);
+    assert_eq!(
+        vec![
+            "G90 G21 G0 X-5.000 Y0.000\r",
+            "M4 S25\r",
+            "G1 X5.000 Y0.000 F1500.000\r",
+            "G1 X5.000 Y2.500 F1500.000\r",
+            "G1 X-5.000 Y2.500 F1500.000\r",
+            "G1 X-5.000 Y0.000 F1500.000\r",
+            "M5\r",
+        ],
+        commands
+    );
+}
+
+#[test]
+fn get_test_fire_commands_uses_constant_power_with_zero_length_motion() {
+    assert_eq!(vec!["G91 G1 X0 F100 M3 S127.5\r", "G4 P2.000\r", "M5\r"], get_test_fire_commands(127.5, Duration::from_secs(2)));
+}
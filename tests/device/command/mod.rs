@@ -0,0 +1,10 @@
+pub mod accessory_test;
+pub mod coordinate_test;
+pub mod homing_test;
+pub mod io_test;
+pub mod laser_test;
+pub mod overrides_test;
+pub mod probe_test;
+pub mod startup_test;
+pub mod tool_test;
+pub mod util_test;
\ No newline at end of file
@@ -0,0 +1,58 @@
+use grbli::device::command::{overrides::{get_feed_override_commands, get_rapid_override_commands, get_spindle_override_commands}, realtime::overrides::*};
+
+
+
+#[test]
+fn get_feed_override_commands_uses_coarse_and_fine_steps() {
+    let commands = get_feed_override_commands(100, 123).unwrap();
+    assert_eq!(vec![FEED_COARSE_PLUS, FEED_COARSE_PLUS, FEED_FINE_PLUS, FEED_FINE_PLUS, FEED_FINE_PLUS], commands);
+}
+
+#[test]
+fn get_feed_override_commands_uses_direct_sequence_when_shorter() {
+    let commands = get_feed_override_commands(100, 88).unwrap();
+    assert_eq!(vec![FEED_COARSE_MINUS, FEED_FINE_MINUS, FEED_FINE_MINUS], commands);
+}
+
+#[test]
+fn get_feed_override_commands_overshoots_when_shorter() {
+    let commands = get_feed_override_commands(100, 82).unwrap();
+    assert_eq!(vec![FEED_COARSE_MINUS, FEED_COARSE_MINUS, FEED_FINE_PLUS, FEED_FINE_PLUS], commands);
+}
+
+#[test]
+fn get_feed_override_commands_does_not_overshoot_limit() {
+    let commands = get_feed_override_commands(191, 199).unwrap();
+    assert_eq!(vec![FEED_FINE_PLUS; 8], commands);
+}
+
+#[test]
+fn get_feed_override_commands_resets_when_shorter() {
+    let commands = get_feed_override_commands(163, 101).unwrap();
+    assert_eq!(vec![FEED_RESET, FEED_FINE_PLUS], commands);
+}
+
+#[test]
+fn get_feed_override_commands_returns_nothing_for_current_value() {
+    assert!(get_feed_override_commands(75, 75).unwrap().is_empty());
+}
+
+#[test]
+fn get_feed_override_commands_fails_outside_range() {
+    let error = get_feed_override_commands(100, 201).err().unwrap();
+    assert_eq!("Feed override 201% is outside of 10% - 200%", error);
+}
+
+#[test]
+fn get_spindle_override_commands_uses_spindle_bytes() {
+    let commands = get_spindle_override_commands(50, 30).unwrap();
+    assert_eq!(vec![SPINDLE_COARSE_MINUS, SPINDLE_COARSE_MINUS], commands);
+}
+
+#[test]
+fn get_rapid_override_commands_selects_level() {
+    assert_eq!(vec![RAPID_LOW], get_rapid_override_commands(100, 25).unwrap());
+    assert!(get_rapid_override_commands(50, 50).unwrap().is_empty());
+    let error = get_rapid_override_commands(100, 75).err().unwrap();
+    assert_eq!("Rapid override 75% is not one of 100%, 50% or 25%", error);
+}
@@ -0,0 +1,27 @@
+use grbli::device::{command::probe::{get_machine_move_command, get_probe_command, get_rapid_move_command, get_set_work_position_command, ProbeCorner, ProbeMode}, util::axis::Axis};
+
+
+
+#[test]
+fn get_probe_command_uses_mode_word() {
+    assert_eq!("G21 G91 G38.3 X5.000 F50.000\r", get_probe_command(ProbeMode::TowardNoError, &Axis::X, 5.0, 50.0));
+    assert_eq!("G21 G91 G38.4 Y-2.500 F25.000\r", get_probe_command(ProbeMode::Away, &Axis::Y, -2.5, 25.0));
+    assert_eq!("G21 G91 G38.5 Z1.000 F10.000\r", get_probe_command(ProbeMode::AwayNoError, &Axis::Z, 1.0, 10.0));
+}
+
+#[test]
+fn get_move_commands_write_all_axes() {
+    assert_eq!("G21 G91 G0 Z2.000\r", get_rapid_move_command(&[(Axis::Z, 2.0)]));
+    assert_eq!("G21 G90 G53 G0 X-10.000 Y-20.000\r", get_machine_move_command(&[(Axis::X, -10.0), (Axis::Y, -20.0)]));
+}
+
+#[test]
+fn get_set_work_position_command_uses_active_system_by_default() {
+    assert_eq!("G21 G10 L20 P0 Z15.000\r", get_set_work_position_command(None, &[(Axis::Z, 15.0)]));
+}
+
+#[test]
+fn probe_directions_point_towards_corner_edges() {
+    assert_eq!((1.0, 1.0), ProbeCorner::FrontLeft.probe_directions());
+    assert_eq!((-1.0, -1.0), ProbeCorner::BackRight.probe_directions());
+}
@@ -0,0 +1,15 @@
+use grbli::device::command::startup::{get_clear_startup_line_command, get_set_startup_line_command};
+
+
+
+#[test]
+fn get_set_startup_line_command_validates_index() {
+    assert_eq!(Err(String::from("Startup line 2 does not exist (0 - 1)")), get_set_startup_line_command(2, "G21"));
+    assert_eq!(Ok(String::from("$N1=\r")), get_clear_startup_line_command(1));
+}
+
+#[test]
+fn get_set_startup_line_command_rejects_long_and_multiple_lines() {
+    assert!(get_set_startup_line_command(0, &"G21".repeat(30)).is_err());
+    assert!(get_set_startup_line_command(0, "G21\nG54").is_err());
+}
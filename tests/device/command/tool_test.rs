@@ -0,0 +1,13 @@
+use grbli::device::{command::tool::{get_set_tool_offset_from_position_command, get_tool_change_command}, util::axis::Axis};
+
+
+
+#[test]
+fn get_tool_change_command_selects_tool_before_change() {
+    assert_eq!("T3 M6\r", get_tool_change_command(3));
+}
+
+#[test]
+fn get_set_tool_offset_from_position_command_uses_l10() {
+    assert_eq!("G21 G10 L10 P2 Z0.000\r", get_set_tool_offset_from_position_command(2, &[(Axis::Z, 0.0)]));
+}
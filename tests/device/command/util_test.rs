@@ -0,0 +1,30 @@
+use grbli::device::{command::util::{get_restore_modal_state_command, load_device_metadata_commands}, response::{firmware::dialect::FirmwareDialect, read_response, state::gcode_state::GCodeState}, DeviceInfo};
+
+
+
+#[test]
+fn load_device_metadata_commands_skips_descriptions_for_classic_grbl() {
+    let commands = load_device_metadata_commands(&FirmwareDialect::Grbl1_1);
+    assert!(commands.contains(&String::from("$$\r")));
+    assert!(!commands.iter().any(|command| command.starts_with("$E") || command == "$I+\r"));
+    assert!(load_device_metadata_commands(&FirmwareDialect::GrblHAL).contains(&String::from("$ES\r")));
+}
+
+#[test]
+fn load_device_metadata_commands_follow_welcome_message_dialect() {
+    let mut device_info = DeviceInfo::from("test").unwrap();
+    assert!(load_device_metadata_commands(&device_info.firmware_info().dialect()).contains(&String::from("$EA\r")));
+    read_response("Grbl 1.1h ['$' for help]", &mut device_info).unwrap();
+    assert!(!load_device_metadata_commands(&device_info.firmware_info().dialect()).contains(&String::from("$EA\r")));
+
+    // grblHAL may announce itself as grbl 1.1, the "$I" response is definitive
+    read_response("[VER:1.1f.20230129:]", &mut device_info).unwrap();
+    read_response("[NEWOPT:ENUMS,RT+,SD]", &mut device_info).unwrap();
+    assert!(load_device_metadata_commands(&device_info.firmware_info().dialect()).contains(&String::from("$EA\r")));
+}
+
+#[test]
+fn get_restore_modal_state_command_skips_motion_requiring_axis_words() {
+    let gcode_state = GCodeState::from("[GC:G38.2 G54 G17 G21 G90 G94 M5 M9 T0 F100 S0]").unwrap();
+    assert_eq!("G21 G90 F100\r", get_restore_modal_state_command(&gcode_state));
+}
@@ -1,2 +1,4 @@
+pub mod command;
 pub mod response;
-pub mod response_test;
\ No newline at end of file
+pub mod response_test;
+pub mod util;
\ No newline at end of file
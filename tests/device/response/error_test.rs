@@ -28,4 +28,23 @@ fn alarm_code_stored_correctly() {
     let mut status_codes = StatusCodes::new();
     status_codes.put_alarm_code(alarm.clone());
     assert_eq!(alarm, *status_codes.alarm_codes().get(&alarm.code()).unwrap())
-}
\ No newline at end of file
+}
+
+#[test]
+fn reported_error_code_replaces_default() {
+    let mut status_codes = StatusCodes::new();
+    status_codes.put_error_code(ErrorCode::from("[ERRORCODE:22||Undefined feed rate]").unwrap());
+    assert_eq!("Undefined feed rate", status_codes.error_codes().get(&22).unwrap().description());
+    assert_eq!("Emergency stop active.", status_codes.error_codes().get(&50).unwrap().description());
+    assert!(status_codes.error_codes().get(&99).is_none());
+}
+
+#[test]
+fn default_alarm_codes_are_merged_with_reported_codes() {
+    let mut status_codes = StatusCodes::new();
+    status_codes.put_alarm_code(AlarmCode::from("[ALARMCODE:17||Motor fault on X.]").unwrap());
+    let alarm_codes = status_codes.alarm_codes();
+    assert!(alarm_codes.get(&1).unwrap().description().starts_with("Hard limit triggered."));
+    assert_eq!("Spindle at speed timeout. Clear before continuing.", alarm_codes.get(&14).unwrap().description());
+    assert_eq!("Motor fault on X.", alarm_codes.get(&17).unwrap().description());
+}
@@ -0,0 +1,51 @@
+use grbli::device::response::{firmware::{board::ports::AuxPorts, capabilities::{Capabilities, Capability}, compile_option::CompileOptions, welcome::WelcomeMessage, FirmwareInfo}, state::compile::ExtendedCompileOption};
+
+
+
+#[test]
+fn from_is_empty_without_compile_options() {
+    let capabilities = Capabilities::from(&FirmwareInfo::new());
+    assert!(capabilities.capabilities().is_empty());
+    assert!(!capabilities.supports(Capability::Probing));
+}
+
+#[test]
+fn from_reads_extended_compile_options() {
+    let mut firmware_info = FirmwareInfo::new();
+    firmware_info.set_compile_options(Some(CompileOptions::from("[OPT:VNMH,35,1024,3,0]").unwrap()));
+    firmware_info.set_extended_compile_options(Some(vec![
+        ExtendedCompileOption::SDCardStreaming,
+        ExtendedCompileOption::AutomaticToolChange,
+        ExtendedCompileOption::NoProbeInput,
+    ]));
+    let capabilities = Capabilities::from(&firmware_info);
+    for capability in [Capability::LaserMode, Capability::MistCoolant, Capability::SingleAxisHoming, Capability::SDCard, Capability::AutomaticToolChange] {
+        assert!(capabilities.supports(capability), "{:?}", capability);
+    }
+    assert!(!capabilities.supports(Capability::Probing));
+    assert!(!capabilities.supports(Capability::LatheMode));
+    assert_eq!(3, capabilities.axes_count());
+}
+
+#[test]
+fn from_adds_probing_for_classic_grbl() {
+    let mut firmware_info = FirmwareInfo::new();
+    firmware_info.set_welcome_message(Some(WelcomeMessage::from("Grbl 1.1h ['$' for help]").unwrap()));
+    firmware_info.set_compile_options(Some(CompileOptions::from("[OPT:V,15,128]").unwrap()));
+    let capabilities = Capabilities::from(&firmware_info);
+    assert!(capabilities.supports(Capability::Probing));
+    assert!(!capabilities.supports(Capability::MistCoolant));
+    assert_eq!(3, capabilities.axes_count());
+}
+
+#[test]
+fn from_reads_aux_ports_and_driver_options() {
+    let mut firmware_info = FirmwareInfo::new();
+    firmware_info.board_info_mut().set_aux(Some(AuxPorts::from("[AUX IO:2,4,0,1]").unwrap()));
+    firmware_info.driver_info_mut().set_options(Some(vec!["TMC2209".to_string()]));
+    let capabilities = Capabilities::from(&firmware_info);
+    assert_eq!(4, capabilities.digital_outputs());
+    assert_eq!(1, capabilities.analog_outputs());
+    assert!(capabilities.has_driver_option("TMC2209"));
+    assert!(!capabilities.has_driver_option("TMC2130"));
+}
@@ -0,0 +1,42 @@
+use grbli::device::response::firmware::{dialect::FirmwareDialect, version::FirmwareVersion, welcome::WelcomeMessage, FirmwareInfo};
+
+
+
+#[test]
+fn from_welcome_message_detects_all_dialects() {
+    let dialects: Vec<FirmwareDialect> = ["Grbl 0.9j ['$' for help]", "Grbl 1.1h ['$' for help]", "GrblHAL 1.1f ['$' or '$HELP' for help]"]
+        .iter()
+        .map(|message| FirmwareDialect::from_welcome_message(&WelcomeMessage::from(message).unwrap()))
+        .collect();
+    assert_eq!(vec![FirmwareDialect::Grbl0_9, FirmwareDialect::Grbl1_1, FirmwareDialect::GrblHAL], dialects);
+}
+
+#[test]
+fn from_version_requires_extended_compile_options_for_grbl_hal() {
+    let version = FirmwareVersion::from("[VER:1.1f.20230129:]").unwrap();
+    assert_eq!(FirmwareDialect::Grbl1_1, FirmwareDialect::from_version(&version, false));
+    assert_eq!(FirmwareDialect::GrblHAL, FirmwareDialect::from_version(&version, true));
+
+    let version = FirmwareVersion::from("[0.9j.20160726:]").unwrap();
+    assert_eq!(FirmwareDialect::Grbl0_9, FirmwareDialect::from_version(&version, false));
+}
+
+#[test]
+fn firmware_info_prefers_welcome_message_over_version() {
+    let mut firmware_info = FirmwareInfo::new();
+    assert_eq!(FirmwareDialect::GrblHAL, firmware_info.dialect());
+    firmware_info.set_version(Some(FirmwareVersion::from("[VER:1.1f.20230129:]").unwrap()));
+    assert_eq!(FirmwareDialect::Grbl1_1, firmware_info.dialect());
+    firmware_info.set_welcome_message(Some(WelcomeMessage::from("GrblHAL 1.1f ['$' or '$HELP' for help]").unwrap()));
+    assert_eq!(FirmwareDialect::GrblHAL, firmware_info.dialect());
+}
+
+#[test]
+fn firmware_info_prefers_extended_compile_options_over_welcome_message() {
+    let mut firmware_info = FirmwareInfo::new();
+    firmware_info.set_welcome_message(Some(WelcomeMessage::from("Grbl 1.1f ['$' for help]").unwrap()));
+    assert_eq!(FirmwareDialect::Grbl1_1, firmware_info.dialect());
+    assert_eq!(3, firmware_info.dialect().max_axes_count());
+    firmware_info.set_extended_compile_options(Some(Vec::new()));
+    assert_eq!(FirmwareDialect::GrblHAL, firmware_info.dialect());
+}
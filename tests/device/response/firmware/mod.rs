@@ -1,3 +1,7 @@
+pub mod capabilities_test;
 pub mod compile_option_test;
+pub mod dialect_test;
+pub mod spindle_test;
 pub mod startup_test;
-pub mod version_test;
\ No newline at end of file
+pub mod version_test;
+pub mod welcome_test;
\ No newline at end of file
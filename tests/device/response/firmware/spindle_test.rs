@@ -0,0 +1,33 @@
+use grbli::device::response::firmware::spindle::SpindleInfo;
+
+
+
+#[test]
+fn from_reads_active_spindle() {
+    let spindle = SpindleInfo::from("0 - PWM, active").unwrap();
+    assert_eq!(0, spindle.id());
+    assert_eq!("PWM", spindle.name());
+    assert_eq!(Some(0), spindle.number());
+    assert!(spindle.is_active());
+}
+
+#[test]
+fn from_reads_disabled_spindle() {
+    let spindle = SpindleInfo::from("11 - Modbus VFD").unwrap();
+    assert_eq!(11, spindle.id());
+    assert_eq!(None, spindle.number());
+    assert!(!spindle.is_active());
+}
+
+#[test]
+fn from_fails_on_invalid_number() {
+    let error = SpindleInfo::from("1 - PWM2, enabled as spindle x").err().unwrap();
+    assert_eq!("Cannot read spindle number \"x\"", error);
+}
+
+#[test]
+fn is_response_requires_numeric_id() {
+    assert!(SpindleInfo::is_response("2 - PWM"));
+    assert!(!SpindleInfo::is_response("A - PWM"));
+    assert!(!SpindleInfo::is_response("$2=3"));
+}
@@ -1,4 +1,4 @@
-use grbli::device::response::firmware::startup::{FirmwareStartupResult, StartupResult};
+use grbli::device::response::firmware::startup::{FirmwareStartupResult, StartupLine, StartupLines, StartupResult};
 
 
 
@@ -72,4 +72,27 @@ fn from_fails_on_missing_result_section() {
     assert!(message.is_err());
     let message_error = message.err().unwrap();
     assert_eq!("Invalid count of startup segments \"G66\"", &message_error[..])
-}
\ No newline at end of file
+}
+
+#[test]
+fn startup_line_keeps_equal_signs_of_line() {
+    let startup_line = StartupLine::from("$N0=G1F=100").unwrap();
+    assert_eq!(0, startup_line.index());
+    assert_eq!("G1F=100", startup_line.line());
+}
+
+#[test]
+fn startup_line_fails_on_invalid_index() {
+    assert_eq!(Err(String::from("Cannot read startup line index \"A\"")), StartupLine::from("$NA=G21"));
+    assert!(!StartupLine::is_response("$N"));
+}
+
+#[test]
+fn startup_lines_are_replaced_by_index() {
+    let mut startup_lines = StartupLines::default();
+    startup_lines.put(StartupLine::from("$N1=G21").unwrap());
+    startup_lines.put(StartupLine::from("$N0=G54").unwrap());
+    startup_lines.put(StartupLine::from("$N1=").unwrap());
+    let lines: Vec<&str> = startup_lines.lines().iter().map(|line| line.line()).collect();
+    assert_eq!(vec!["G54", ""], lines);
+}
@@ -64,4 +64,11 @@ fn from_fails_on_missing_name_separator() {
     assert!(message.is_err());
     let message_error = message.err().unwrap();
     assert_eq!("Invalid count of version strings \"0.1223d.234f\"", &message_error[..])
-}
\ No newline at end of file
+}
+#[test]
+fn from_parses_grbl_0_9_version() {
+    let message = FirmwareVersion::from("[0.9j.20160726:]").unwrap();
+    assert_eq!(String::from("0.9j.20160726"), *message.version());
+    assert_eq!(String::from(""), *message.name());
+    assert!(!FirmwareVersion::is_response("[MSG:0.9]"));
+}
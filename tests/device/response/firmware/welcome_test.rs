@@ -0,0 +1,24 @@
+use grbli::device::response::firmware::welcome::{FirmwareFlavor, WelcomeMessage};
+
+
+
+#[test]
+fn from_reads_classic_grbl_versions() {
+    let welcome = WelcomeMessage::from("Grbl 0.9j ['$' for help]").unwrap();
+    assert_eq!(FirmwareFlavor::Grbl, *welcome.flavor());
+    assert_eq!("0.9j", welcome.version());
+}
+
+#[test]
+fn from_reads_grbl_hal_welcome_message() {
+    let welcome = WelcomeMessage::from("GrblHAL 1.1f ['$' or '$HELP' for help]").unwrap();
+    assert_eq!(FirmwareFlavor::GrblHAL, *welcome.flavor());
+    assert_eq!("1.1f", welcome.version());
+}
+
+#[test]
+fn is_response_requires_version() {
+    assert!(!WelcomeMessage::is_response("Grbl ['$' for help]"));
+    assert!(!WelcomeMessage::is_response("GrblHALX 1.1f"));
+    assert!(!WelcomeMessage::is_response("[MSG:Grbl 1.1f]"));
+}
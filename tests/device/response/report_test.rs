@@ -14,8 +14,8 @@ pub fn from_parses_all_attributes_correctly() {
     
     assert!(matches!(report.global_position(), None));
     let machine_pos = report.local_position().unwrap();
-    assert_eq!(3.32, machine_pos[0]);
-    assert_eq!(67f32, machine_pos[1]);
+    assert_eq!(Some(3.32), machine_pos.get(&Axis::X));
+    assert_eq!(Some(67f32), machine_pos.get(&Axis::Y));
 
     let buffer_state = report.buffer_state().unwrap();
     assert_eq!(100, buffer_state.block_buffers_free());
@@ -24,9 +24,9 @@ pub fn from_parses_all_attributes_correctly() {
     assert_eq!(9, report.line_number().unwrap());
 
     let machine_speed = report.machine_speed().unwrap();
-    assert_eq!(100, machine_speed.feed_rate());
-    assert_eq!(23, machine_speed.spindle_programmed_rpm());
-    assert_eq!(20, machine_speed.spindle_actual_rpm().unwrap());
+    assert_eq!(100f32, machine_speed.feed_rate());
+    assert_eq!(Some(23f32), machine_speed.spindle_programmed_rpm());
+    assert_eq!(20f32, machine_speed.spindle_actual_rpm().unwrap());
 
     let machine_signals = report.machine_signals().unwrap();
     assert!(matches!(machine_signals[0], MachineSignal::DoorSwitchAsserted));
@@ -34,8 +34,8 @@ pub fn from_parses_all_attributes_correctly() {
     assert!(matches!(machine_signals[2], MachineSignal::FeedHoldSwitchAsserted));
 
     let local_pos_offset = report.local_offset().unwrap();
-    assert_eq!(23.2, local_pos_offset[0]);
-    assert_eq!(0f32, local_pos_offset[1]);
+    assert_eq!(Some(23.2), local_pos_offset.get(&Axis::X));
+    assert_eq!(Some(0f32), local_pos_offset.get(&Axis::Y));
 
     assert_eq!("G55", report.machine_coordinate_system().unwrap());
 
@@ -71,4 +71,54 @@ pub fn from_parses_all_attributes_correctly() {
     assert_eq!("test", report.firmware().unwrap());
 
     assert!(report.input_wait_result_succeeded().unwrap() == false);
-}
\ No newline at end of file
+}
+
+#[test]
+pub fn from_parses_grblhal_fields() {
+    let message = "<Run|MPos:1,2,3|F:500|T:4|SD:42.5,/part.nc|THC:120.5,AE>";
+    let report = MachineInfo::from(message).unwrap();
+    assert_eq!(500f32, report.machine_speed().unwrap().feed_rate());
+    assert_eq!(Some(4), report.current_tool());
+    assert_eq!(42.5, report.sd_card_progress().unwrap().percentage());
+    assert_eq!(Some("/part.nc"), report.sd_card_progress().unwrap().file_name());
+    assert_eq!(120.5, report.thc_state().unwrap().arc_voltage());
+    assert!(report.thc_state().unwrap().is_enabled());
+    assert!(report.unknown_fields().is_empty());
+}
+
+#[test]
+pub fn from_keeps_unknown_fields() {
+    let message = "<Idle|MPos:1,2,3|XY:1,2|Q>";
+    let report = MachineInfo::from(message).unwrap();
+    assert_eq!("1,2", report.unknown_fields().get("XY").unwrap());
+    assert_eq!("", report.unknown_fields().get("Q").unwrap());
+}
+
+#[test]
+pub fn from_parses_grbl_0_9_report() {
+    let report = MachineInfo::from("<Run,MPos:10.000,5.000,-1.000,WPos:0.000,0.000,-1.000,Buf:3,RX:12,Ln:42,F:500.,Pin:000|0|0000>").unwrap();
+    assert!(matches!(report.machine_state().status(), MachineStateName::Run));
+    assert_eq!(Some(5.0), report.global_position().unwrap().get(&Axis::Y));
+    assert_eq!(Some(-1.0), report.local_position().unwrap().get(&Axis::Z));
+    assert_eq!(Some(42), report.line_number());
+    assert_eq!(500.0, report.machine_speed().unwrap().feed_rate());
+    assert_eq!(Some(&String::from("000|0|0000")), report.unknown_fields().get("Pin"));
+}
+
+#[test]
+pub fn from_parses_grbl_0_9_report_with_single_position() {
+    let report = MachineInfo::from("<Idle,WPos:1.000,2.000,3.000>").unwrap();
+    assert!(report.global_position().is_none());
+    assert_eq!(Some(3.0), report.local_position().unwrap().get(&Axis::Z));
+}
+
+#[test]
+pub fn display_positions_show_diameter_in_lathe_mode() {
+    let report = MachineInfo::from("<Idle|MPos:5.000,0.000,-2.000|D:1>").unwrap();
+    assert_eq!(Some(10.0), report.display_global_position().unwrap().get(&Axis::X));
+    assert_eq!(Some(-2.0), report.display_global_position().unwrap().get(&Axis::Z));
+
+    let report = MachineInfo::from("<Idle|MPos:5.000,0.000,-2.000|D:0>").unwrap();
+    assert_eq!(Some(5.0), report.display_global_position().unwrap().get(&Axis::X));
+    assert!(report.display_local_position().is_none());
+}
@@ -1,4 +1,4 @@
-use grbli::device::response::setting::DeviceSetting;
+use grbli::device::response::setting::{description::DeviceSettingDescription, DeviceSetting, DeviceSettings};
 
 
 
@@ -19,4 +19,14 @@ fn setting_fails_on_invalid_message() {
 fn setting_fails_on_invalid_index() {
     let error = DeviceSetting::from("$a12=ab0.1").err().unwrap();
     assert_eq!(error, "Cannot read setting index: \"a12\"");
-}
\ No newline at end of file
+}
+
+#[test]
+fn reported_setting_description_replaces_default() {
+    let mut settings = DeviceSettings::new();
+    assert_eq!("Junction deviation", settings.get_setting_description(&11).unwrap().description().unwrap());
+    settings.put_setting_description(DeviceSettingDescription::from("[SETTING:11|5|Junction deviation HAL|mm|6|#0.000||]").unwrap());
+    assert_eq!("Junction deviation HAL", settings.get_setting_description(&11).unwrap().description().unwrap());
+    assert_eq!(&5, settings.setting_descriptions().get(&11).unwrap().group_index());
+    assert!(settings.get_setting_description(&7).is_none());
+}
@@ -49,4 +49,10 @@ fn from_fails_on_missing_suffix() {
     assert!(message.is_err());
     let message_error = message.err().unwrap();
     assert_eq!("Cannot read gcode state message \"[GC:AB CD F ' $H 'YO\"", &message_error[..])
-}
\ No newline at end of file
+}
+#[test]
+fn tool_reads_selected_tool() {
+    let message = GCodeState::from("[GC:G0 G54 G17 G21 G90 G94 G49 G98 G50 M5 M9 T12 F0 S0]").unwrap();
+    assert_eq!(Some(12), message.tool());
+    assert_eq!(None, GCodeState::from("[GC:G0 G54]").unwrap().tool());
+}
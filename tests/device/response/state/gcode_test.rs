@@ -20,4 +20,9 @@ fn parsing_fails_on_invalid_message_syntax() {
     let message_str = "Ln32";
     let error_message = parse_line_number(message_str).err().unwrap();
     assert_eq!("Cannot read line number \"Ln32\"", error_message);
-}
\ No newline at end of file
+}
+
+#[test]
+fn parsing_ignores_appended_values() {
+    assert_eq!(12, parse_line_number("Ln:12,3").unwrap());
+}
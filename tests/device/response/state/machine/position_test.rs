@@ -1,4 +1,4 @@
-use grbli::device::{response::state::machine::position::{parse_position, parse_local_position, parse_global_position, parse_local_position_offset, parse_coordinate_system, parse_scaled_axes}, util::axis::Axis};
+use grbli::device::{response::state::machine::position::{Position, parse_position, parse_local_position, parse_global_position, parse_local_position_offset, parse_coordinate_system, parse_scaled_axes}, util::axis::Axis};
 
 
 
@@ -8,12 +8,12 @@ use grbli::device::{response::state::machine::position::{parse_position, parse_l
 fn parse_position_returns_all_axis() {
     let message_str = "3,000.1,23.11,7,12.5,18";
     let position = parse_position(message_str).unwrap();
-    assert_eq!(3f32, position[0]);
-    assert_eq!(0.1, position[1]);
-    assert_eq!(23.11, position[2]);
-    assert_eq!(7f32, position[3]);
-    assert_eq!(12.5, position[4]);
-    assert_eq!(18f32, position[5]);
+    assert_eq!(Some(3f32), position.get(&Axis::X));
+    assert_eq!(Some(0.1), position.get(&Axis::Y));
+    assert_eq!(Some(23.11), position.get(&Axis::Z));
+    assert_eq!(Some(7f32), position.get(&Axis::A));
+    assert_eq!(Some(12.5), position.get(&Axis::B));
+    assert_eq!(Some(18f32), position.get(&Axis::C));
 }
 
 #[test]
@@ -41,12 +41,12 @@ fn parse_position_accept_max_six_axes() {
 fn parse_local_position_returns_position() {
     let message_str = "WPos:3,000.1,23.11,7,12.5,18";
     let position = parse_local_position(message_str).unwrap();
-    assert_eq!(3f32, position[0]);
-    assert_eq!(0.1, position[1]);
-    assert_eq!(23.11, position[2]);
-    assert_eq!(7f32, position[3]);
-    assert_eq!(12.5, position[4]);
-    assert_eq!(18f32, position[5]);
+    assert_eq!(Some(3f32), position.get(&Axis::X));
+    assert_eq!(Some(0.1), position.get(&Axis::Y));
+    assert_eq!(Some(23.11), position.get(&Axis::Z));
+    assert_eq!(Some(7f32), position.get(&Axis::A));
+    assert_eq!(Some(12.5), position.get(&Axis::B));
+    assert_eq!(Some(18f32), position.get(&Axis::C));
 }
 
 #[test]
@@ -60,12 +60,12 @@ fn parse_local_position_fails_on_wrong_prefix() {
 fn parse_global_position_returns_position() {
     let message_str = "MPos:3,000.1,23.11,7,12.5,18";
     let position = parse_global_position(message_str).unwrap();
-    assert_eq!(3f32, position[0]);
-    assert_eq!(0.1, position[1]);
-    assert_eq!(23.11, position[2]);
-    assert_eq!(7f32, position[3]);
-    assert_eq!(12.5, position[4]);
-    assert_eq!(18f32, position[5]);
+    assert_eq!(Some(3f32), position.get(&Axis::X));
+    assert_eq!(Some(0.1), position.get(&Axis::Y));
+    assert_eq!(Some(23.11), position.get(&Axis::Z));
+    assert_eq!(Some(7f32), position.get(&Axis::A));
+    assert_eq!(Some(12.5), position.get(&Axis::B));
+    assert_eq!(Some(18f32), position.get(&Axis::C));
 }
 
 #[test]
@@ -79,12 +79,12 @@ fn parse_global_position_fails_on_wrong_prefix() {
 fn parse_local_position_offset_returns_position() {
     let message_str = "WCO:3,000.1,23.11,7,12.5,18";
     let position = parse_local_position_offset(message_str).unwrap();
-    assert_eq!(3f32, position[0]);
-    assert_eq!(0.1, position[1]);
-    assert_eq!(23.11, position[2]);
-    assert_eq!(7f32, position[3]);
-    assert_eq!(12.5, position[4]);
-    assert_eq!(18f32, position[5]);
+    assert_eq!(Some(3f32), position.get(&Axis::X));
+    assert_eq!(Some(0.1), position.get(&Axis::Y));
+    assert_eq!(Some(23.11), position.get(&Axis::Z));
+    assert_eq!(Some(7f32), position.get(&Axis::A));
+    assert_eq!(Some(12.5), position.get(&Axis::B));
+    assert_eq!(Some(18f32), position.get(&Axis::C));
 }
 
 #[test]
@@ -124,4 +124,28 @@ fn parse_scaled_axes_fails_on_wrong_prefix() {
     let message_str = "Sc";
     let error = parse_scaled_axes(message_str).err().unwrap();
     assert_eq!("Cannot read scaled axes message \"Sc\"", error);
-}
\ No newline at end of file
+}
+
+#[test]
+fn position_get_returns_reported_axes_only() {
+    let position = parse_position("1,2,3").unwrap();
+    assert_eq!(Some(2f32), position.get(&Axis::Y));
+    assert_eq!(None, position.get(&Axis::A));
+    assert_eq!(vec![Axis::X, Axis::Y, Axis::Z], position.axes());
+}
+
+#[test]
+fn position_offset_converts_frames() {
+    let machine_position = Position::from_values(&[-10.0, -20.0, -5.0]);
+    let offset = Position::from_values(&[-15.0, -15.0]);
+    let work_position = machine_position.without_offset(&offset);
+    assert_eq!(&[5.0, -5.0, -5.0], work_position.values());
+    assert_eq!(machine_position, work_position.with_offset(&offset));
+}
+
+#[test]
+fn position_set_extends_axes() {
+    let mut position = Position::from_values(&[1.0]);
+    position.set(&Axis::Z, 4.0);
+    assert_eq!(&[1.0, 0.0, 4.0], position.values());
+}
@@ -6,17 +6,17 @@ use grbli::device::response::state::machine::speed::MachineSpeed;
 fn from_reads_full_machine_speed() {
     let msg = "FS:100,3000,1677";
     let speed = MachineSpeed::from(msg).unwrap();
-    assert_eq!(100, speed.feed_rate());
-    assert_eq!(3000, speed.spindle_programmed_rpm());
-    assert_eq!(1677, speed.spindle_actual_rpm().unwrap());
+    assert_eq!(100f32, speed.feed_rate());
+    assert_eq!(Some(3000f32), speed.spindle_programmed_rpm());
+    assert_eq!(1677f32, speed.spindle_actual_rpm().unwrap());
 }
 
 #[test]
 fn from_ignore_actual_spindle_rpm() {
     let msg = "FS:100,3000";
     let speed = MachineSpeed::from(msg).unwrap();
-    assert_eq!(100, speed.feed_rate());
-    assert_eq!(3000, speed.spindle_programmed_rpm());
+    assert_eq!(100f32, speed.feed_rate());
+    assert_eq!(Some(3000f32), speed.spindle_programmed_rpm());
     assert!(speed.spindle_actual_rpm().is_none());
 }
 
@@ -46,4 +46,28 @@ fn from_fails_on_invalid_spindle_act_rpm() {
     let msg = "FS:0,0,vb";
     let error = MachineSpeed::from(msg).err().unwrap();
     assert_eq!("Cannot read spindle actual rpm \"vb\"", error)
-}
\ No newline at end of file
+}
+
+#[test]
+fn from_reads_fractional_machine_speed() {
+    let msg = "FS:1250.5,12000";
+    let speed = MachineSpeed::from(msg).unwrap();
+    assert_eq!(1250.5, speed.feed_rate());
+    assert_eq!(Some(12000f32), speed.spindle_programmed_rpm());
+}
+
+#[test]
+fn from_reads_feed_rate_without_spindle() {
+    let msg = "F:500";
+    let speed = MachineSpeed::from(msg).unwrap();
+    assert_eq!(500f32, speed.feed_rate());
+    assert!(speed.spindle_programmed_rpm().is_none());
+    assert!(speed.spindle_actual_rpm().is_none());
+}
+
+#[test]
+fn from_fails_on_invalid_feed_rate_without_spindle() {
+    let msg = "F:x";
+    let error = MachineSpeed::from(msg).err().unwrap();
+    assert_eq!("Cannot read feed rate \"x\"", error)
+}
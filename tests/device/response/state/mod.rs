@@ -9,4 +9,11 @@ pub mod modes_test;
 pub mod input_test;
 pub mod homing_test;
 pub mod gcode_test;
-pub mod gcode_state_test;
\ No newline at end of file
+pub mod gcode_state_test;
+pub mod sdcard_test;
+pub mod probe_test;
+pub mod ngc_parameters_test;
+pub mod tool_table_test;
+pub mod thc_test;
+pub mod odometer_test;
+pub mod pid_log_test;
\ No newline at end of file
@@ -0,0 +1,30 @@
+use grbli::device::{response::state::ngc_parameters::{NgcParameter, NgcParameterName, NgcParameters}, util::{axis::Axis, coordinate::CoordinateSystem}};
+
+
+
+#[test]
+fn from_reads_stored_positions_and_offsets() {
+    assert_eq!(&NgcParameterName::HomePosition, NgcParameter::from("[G28:1.000,2.000,3.000]").unwrap().name());
+    assert_eq!(&NgcParameterName::SecondaryHomePosition, NgcParameter::from("[G30:1.000,2.000,3.000]").unwrap().name());
+    assert_eq!(&NgcParameterName::CoordinateOffset, NgcParameter::from("[G92:0.000,0.000,0.000]").unwrap().name());
+    let parameter = NgcParameter::from("[G59.3:0.000,0.000,-7.500,45.000]").unwrap();
+    assert_eq!(&NgcParameterName::CoordinateSystem(CoordinateSystem::G59_3), parameter.name());
+    assert_eq!(Some(45.0), parameter.position().get(&Axis::A));
+}
+
+#[test]
+fn is_response_ignores_other_bracket_messages() {
+    assert!(NgcParameter::is_response("[TLO:0.000]"));
+    assert!(!NgcParameter::is_response("[GC:G0 G54 G17]"));
+    assert!(!NgcParameter::is_response("[PRB:0.000,0.000,0.000:0]"));
+    assert!(!NgcParameter::is_response("[MSG:Pgm End]"));
+}
+
+#[test]
+fn put_replaces_parameter() {
+    let mut parameters = NgcParameters::default();
+    parameters.put(NgcParameter::from("[G55:1.000,2.000,3.000]").unwrap());
+    parameters.put(NgcParameter::from("[G55:4.000,5.000,6.000]").unwrap());
+    assert_eq!(&[4.0, 5.0, 6.0], parameters.coordinate_system_offset(&CoordinateSystem::G55).unwrap().values());
+    assert!(parameters.coordinate_system_offset(&CoordinateSystem::G54).is_none());
+}
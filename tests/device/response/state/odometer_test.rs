@@ -0,0 +1,36 @@
+use grbli::device::{response::state::odometer::Odometer, util::axis::Axis};
+
+
+
+#[test]
+fn from_reads_distances_and_hours() {
+    let odometer = Odometer::from("[ODOMETER:X=1234.5,Y=800.0,Z=120.3,A=2.0|SPINDLE=12.50|MOTORS=40.25]").unwrap();
+    assert_eq!(vec![(Axis::X, 1234.5), (Axis::Y, 800.0), (Axis::Z, 120.3), (Axis::A, 2.0)], odometer.axis_distances().to_vec());
+    assert_eq!(Some(12.5), odometer.spindle_hours());
+    assert_eq!(Some(40.25), odometer.motor_hours());
+    assert!(odometer.is_distance_exceeded(&Axis::X, 1000.0));
+    assert!(!odometer.is_distance_exceeded(&Axis::B, 0.0));
+}
+
+#[test]
+fn from_ignores_unknown_fields() {
+    let odometer = Odometer::from("[ODOMETER:X=1.0|LASER=3.0|SPINDLE=0.5]").unwrap();
+    assert_eq!(Some(0.5), odometer.spindle_hours());
+    assert_eq!(None, odometer.motor_hours());
+}
+
+#[test]
+fn from_reads_distances_without_hours() {
+    let odometer = Odometer::from("[ODOMETER:X=10.0,Y=2.5]").unwrap();
+    assert_eq!(Some(2.5), odometer.distance(&Axis::Y));
+    assert_eq!(None, odometer.spindle_hours());
+    assert_eq!(None, odometer.motor_hours());
+}
+
+#[test]
+fn from_fails_on_invalid_values() {
+    assert_eq!("Invalid odometer distance \"X1.0\"", Odometer::from("[ODOMETER:X1.0]").unwrap_err());
+    assert_eq!("Cannot read odometer distance of Y \"a\"", Odometer::from("[ODOMETER:Y=a]").unwrap_err());
+    assert_eq!("Cannot read odometer hours \"SPINDLE=1h\"", Odometer::from("[ODOMETER:X=1.0|SPINDLE=1h]").unwrap_err());
+    assert!(Odometer::from("[MSG:ODOMETER]").is_err());
+}
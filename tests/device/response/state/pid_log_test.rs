@@ -0,0 +1,36 @@
+use std::time::Duration;
+
+use grbli::device::response::state::pid_log::{PidLog, PidSample};
+
+
+
+#[test]
+fn from_reads_samples_as_time_series() {
+    let log = PidLog::from("[PID:1000.0,0.5,2|990.0,985.0,1000.0,1002.0,1000.0,1000.0]").unwrap();
+    assert_eq!(1000.0, log.setpoint());
+    assert_eq!(Duration::from_millis(500), log.sample_time());
+    let series = log.time_series();
+    assert_eq!((1.0, PidSample::new(1000.0, 1000.0)), series[2]);
+    assert_eq!(Some(5.0), log.max_error());
+}
+
+#[test]
+fn from_reads_log_with_fixed_decimals() {
+    let log = PidLog::from("[PID:1200.000,0.010,2|1195.000,1190.500,1200.000,1199.000]").unwrap();
+    assert_eq!(Duration::from_millis(10), log.sample_time());
+    assert_eq!(Some(4.5), log.max_error());
+}
+
+#[test]
+fn from_reads_empty_log() {
+    let log = PidLog::from("[PID:0.0,0.01,2|]").unwrap();
+    assert!(log.samples().is_empty());
+    assert_eq!(None, log.max_error());
+}
+
+#[test]
+fn from_fails_on_incomplete_samples() {
+    assert_eq!("Incomplete PID log sample in \"[PID:1.0,0.1,2|1.0,2.0,3.0]\"", PidLog::from("[PID:1.0,0.1,2|1.0,2.0,3.0]").unwrap_err());
+    assert_eq!("Invalid PID log header \"1.0,0.1\"", PidLog::from("[PID:1.0,0.1|1.0,2.0]").unwrap_err());
+    assert_eq!("Cannot read PID log value \"x\"", PidLog::from("[PID:1.0,0.1,2|x,2.0]").unwrap_err());
+}
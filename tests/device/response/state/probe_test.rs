@@ -0,0 +1,16 @@
+use grbli::device::{response::state::probe::ProbeResult, util::axis::Axis};
+
+
+
+#[test]
+fn from_reads_failed_probe_with_rotary_axis() {
+    let result = ProbeResult::from("[PRB:1.000,-2.500,3.000,90.000:0]").unwrap();
+    assert!(!result.succeeded());
+    assert_eq!(Some(90.0), result.position().get(&Axis::A));
+}
+
+#[test]
+fn from_fails_without_success_flag() {
+    assert!(ProbeResult::from("[PRB:1.000,-2.500,3.000]").is_err());
+    assert!(ProbeResult::from("[PRB:1.000,-2.500,3.000:2]").is_err());
+}
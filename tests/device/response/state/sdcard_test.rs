@@ -0,0 +1,29 @@
+use grbli::device::response::state::sdcard::{SdCardFile, SdCardProgress};
+
+
+
+#[test]
+fn from_reads_progress_without_file_name() {
+    let progress = SdCardProgress::from("SD:99.9").unwrap();
+    assert_eq!(99.9, progress.percentage());
+    assert!(progress.file_name().is_none());
+}
+
+#[test]
+fn from_fails_on_invalid_percentage() {
+    let error = SdCardProgress::from("SD:abc,/file.nc").err().unwrap();
+    assert_eq!("Cannot read sd card progress \"abc\"", error);
+}
+
+#[test]
+fn file_from_reads_name_with_separator() {
+    let file = SdCardFile::from("[FILE:/a|b.nc|SIZE:12]").unwrap();
+    assert_eq!("/a|b.nc", file.name());
+    assert_eq!(12, file.size());
+}
+
+#[test]
+fn file_from_fails_without_size() {
+    let error = SdCardFile::from("[FILE:/a.nc]").err().unwrap();
+    assert_eq!("Sd card file requires a size \"[FILE:/a.nc]\"", error);
+}
@@ -31,3 +31,9 @@ fn parse_signals_is_false_on_invalid_value() {
     let error = parse_machine_signal(message_str).err().unwrap();
     assert_eq!("Unknown machine signal \"m\"", error);
 }
+
+#[test]
+fn parses_grbl_prefix() {
+    let signals = parse_machine_signal("Pn:D").unwrap();
+    assert_eq!(vec![MachineSignal::DoorSwitchAsserted], signals);
+}
@@ -0,0 +1,37 @@
+use grbli::device::{response::state::thc::{parse_thc_state, ThcState}, util::signals::{ThcSignal, ThcSignalSet}};
+
+
+
+#[test]
+fn parses_voltage_and_signals() {
+    let state = parse_thc_state("THC:98.2,ERTVHU").unwrap();
+    assert_eq!(98.2, state.arc_voltage());
+    assert!(state.is_enabled() && state.is_active() && state.is_torch_on());
+    assert!(state.is_velocity_locked() && state.is_void_locked());
+    assert!(state.is_moving_up() && !state.is_moving_down());
+    assert!(!state.is_arc_ok());
+    assert_eq!(vec![ThcSignal::Enabled, ThcSignal::Active, ThcSignal::TorchOn, ThcSignal::VelocityLock, ThcSignal::VoidLock, ThcSignal::Up], state.signals().signals());
+}
+
+#[test]
+fn parses_state_without_signals() {
+    assert!(parse_thc_state("THC:0.0,").unwrap().signals().signals().is_empty());
+    assert_eq!(0.0, parse_thc_state("THC:0").unwrap().arc_voltage());
+}
+
+#[test]
+fn parsing_fails_on_invalid_values() {
+    assert_eq!("Cannot read arc voltage \"x\"", parse_thc_state("THC:x,AE").unwrap_err());
+    assert_eq!("Cannot read thc signals \"AQ\"", parse_thc_state("THC:1.0,AQ").unwrap_err());
+    assert_eq!("Cannot read thc state \"TH:1.0\"", parse_thc_state("TH:1.0").unwrap_err());
+}
+
+#[test]
+fn is_arc_lost_requires_torch_on() {
+    let cutting = ThcState::new(120.0, ThcSignalSet::from_letters("AET").unwrap());
+    let arc_lost = ThcState::new(0.0, ThcSignalSet::from_letters("ET").unwrap());
+    let torch_off = ThcState::new(0.0, ThcSignalSet::from_letters("E").unwrap());
+    assert!(arc_lost.is_arc_lost(&cutting));
+    assert!(!torch_off.is_arc_lost(&cutting));
+    assert!(!arc_lost.is_arc_lost(&arc_lost));
+}
@@ -0,0 +1,43 @@
+use grbli::device::{response::state::tool_table::{ToolTable, ToolTableEntry}, util::axis::Axis};
+
+
+
+#[test]
+fn from_reads_entry_without_radius() {
+    let entry = ToolTableEntry::from("[T:2|1.000,2.000,3.000]").unwrap();
+    assert_eq!(2, entry.tool());
+    assert_eq!(0.0, entry.radius());
+}
+
+#[test]
+fn from_fails_on_invalid_tool_number() {
+    assert!(ToolTableEntry::from("[T:x|1.000,2.000,3.000|0.000]").is_err());
+}
+
+#[test]
+fn csv_round_trip_keeps_entries() {
+    let mut table = ToolTable::default();
+    table.put(ToolTableEntry::from("[T:1|0.000,0.000,-12.500,0.000|1.500]").unwrap());
+    table.put(ToolTableEntry::from("[T:4|0.500,0.000,-3.250|3.000]").unwrap());
+    let csv = table.to_csv();
+    assert!(csv.starts_with("tool,X,Y,Z,A,radius\n"));
+
+    let imported = ToolTable::from_csv(&csv).unwrap();
+    assert_eq!(2, imported.entries().len());
+    assert_eq!(Some(-3.25), imported.get(4).unwrap().offset().get(&Axis::Z));
+    assert_eq!(3.0, imported.get(4).unwrap().radius());
+}
+
+#[test]
+fn from_csv_matches_columns_by_name() {
+    let table = ToolTable::from_csv("tool,radius,z\n7,2.5,-40\n").unwrap();
+    let entry = table.get(7).unwrap();
+    assert_eq!(Some(-40.0), entry.offset().get(&Axis::Z));
+    assert_eq!(2.5, entry.radius());
+}
+
+#[test]
+fn from_csv_reports_line_of_invalid_value() {
+    let error = ToolTable::from_csv("tool,X,Y,Z,radius\n1,0,0,0,0\n2,0,a,0,0\n").err().unwrap();
+    assert_eq!("Cannot read value \"a\" in line 3", error);
+}
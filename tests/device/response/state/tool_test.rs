@@ -1,4 +1,4 @@
-use grbli::device::response::state::tool::parse_tool_length_reference;
+use grbli::device::response::state::tool::{parse_tool_length_reference, parse_current_tool};
 
 
 
@@ -36,3 +36,15 @@ fn parse_tlr_is_false_on_invalid_value() {
     let error = parse_tool_length_reference(message_str).err().unwrap();
     assert_eq!("Cannot interpret tool reference length offset set value \"a\"", error);
 }
+
+
+#[test]
+fn parse_current_tool_returns_tool_number() {
+    assert_eq!(12, parse_current_tool("T:12").unwrap());
+}
+
+#[test]
+fn parse_current_tool_fails_on_invalid_value() {
+    let error = parse_current_tool("T:x").err().unwrap();
+    assert_eq!("Cannot read current tool \"x\"", error);
+}
@@ -1,4 +1,4 @@
-use grbli::device::{response::{read_response, error::ErrorCode}, DeviceInfo};
+use grbli::{device::{event::DeviceEvent, response::{read_response, error::ErrorCode, state::{machine::state::MachineStateName, ngc_parameters::NgcParameterName, signal::MachineSignal}}, util::{axis::Axis, coordinate::CoordinateSystem}, DeviceInfo}, job::progress::{JobProgress, JobState}};
 
 
 
@@ -30,4 +30,336 @@ pub fn read_stores_device_status() {
     let mut device_info = DeviceInfo::from("test").unwrap();
     read_response(error_str, &mut device_info).unwrap();
     assert_eq!(*device_info.status_codes().error_codes().get(&54).unwrap(), error)
-}
\ No newline at end of file
+}
+#[test]
+pub fn read_stores_response_status() {
+    let mut device_info = DeviceInfo::from("test").unwrap();
+    read_response("error:20", &mut device_info).unwrap();
+    assert_eq!(Err(20), *device_info.last_response_status().unwrap());
+    read_response("ok", &mut device_info).unwrap();
+    assert!(device_info.last_response_status().unwrap().is_ok());
+}
+
+#[test]
+pub fn read_derives_work_position_from_cached_offset() {
+    let mut device_info = DeviceInfo::from("test").unwrap();
+    read_response("<Idle|MPos:-10.000,-20.000,-5.000|WCO:-15.000,-15.000,0.000>", &mut device_info).unwrap();
+    read_response("<Idle|MPos:-11.000,-20.000,-5.000>", &mut device_info).unwrap();
+    let machine_info = device_info.machine_info().unwrap();
+    assert_eq!(Some(-11f32), machine_info.global_position().unwrap().get(&Axis::X));
+    assert_eq!(Some(4f32), machine_info.local_position().unwrap().get(&Axis::X));
+}
+
+#[test]
+pub fn read_derives_machine_position_from_work_position() {
+    let mut device_info = DeviceInfo::from("test").unwrap();
+    read_response("<Idle|WPos:5.000,-5.000,0.000|WCO:-15.000,-15.000,0.000>", &mut device_info).unwrap();
+    let machine_position = device_info.machine_info().unwrap().global_position().unwrap();
+    assert_eq!(&[-10.0, -20.0, 0.0], machine_position.values());
+}
+
+#[test]
+pub fn read_drops_outdated_frame_without_offset() {
+    let mut device_info = DeviceInfo::from("test").unwrap();
+    read_response("<Idle|WPos:5.000,-5.000,0.000>", &mut device_info).unwrap();
+    read_response("<Idle|MPos:1.000,2.000,3.000>", &mut device_info).unwrap();
+    assert!(device_info.machine_info().unwrap().local_position().is_none());
+}
+
+#[test]
+pub fn read_converts_inch_reports_to_mm() {
+    let mut device_info = DeviceInfo::from("test").unwrap();
+    read_response("$13=1", &mut device_info).unwrap();
+    read_response("<Idle|MPos:1.0000,-2.0000,0.5000,90.000|WCO:1.0000,0.0000,0.0000,0.000>", &mut device_info).unwrap();
+    let machine_info = device_info.machine_info().unwrap();
+    assert_eq!(&[25.4, -50.8, 12.7, 90.0], machine_info.global_position().unwrap().values());
+    assert_eq!(&[0.0, -50.8, 12.7, 90.0], machine_info.local_position().unwrap().values());
+}
+
+#[test]
+pub fn read_clears_accessory_state_with_override_report() {
+    let mut device_info = DeviceInfo::from("test").unwrap();
+    read_response("<Run|MPos:0,0,0|Ov:100,100,100|A:SF>", &mut device_info).unwrap();
+    assert_eq!(2, device_info.machine_info().unwrap().accessory_state().unwrap().len());
+    read_response("<Run|MPos:0,0,0>", &mut device_info).unwrap();
+    assert_eq!(2, device_info.machine_info().unwrap().accessory_state().unwrap().len());
+    read_response("<Run|MPos:0,0,0|Ov:100,100,100>", &mut device_info).unwrap();
+    assert!(device_info.machine_info().unwrap().accessory_state().unwrap().is_empty());
+}
+
+#[test]
+pub fn read_stores_spindles() {
+    let mut device_info = DeviceInfo::from("test").unwrap();
+    read_response("0 - PWM, active", &mut device_info).unwrap();
+    read_response("1 - Huanyang VFD, enabled as spindle 1", &mut device_info).unwrap();
+    assert_eq!(2, device_info.firmware_info().spindles().len());
+    assert_eq!("Huanyang VFD", device_info.firmware_info().get_spindle_by_number(1).unwrap().name());
+}
+
+#[test]
+pub fn read_replaces_unknown_fields_and_drops_finished_sd_progress() {
+    let mut device_info = DeviceInfo::from("test").unwrap();
+    read_response("<Run|MPos:0,0,0|SD:10,/a.nc|XY:1>", &mut device_info).unwrap();
+    read_response("<Run|MPos:0,0,0|ZZ:2>", &mut device_info).unwrap();
+    let machine_info = device_info.machine_info().unwrap();
+    assert!(machine_info.sd_card_progress().is_none());
+    assert_eq!(vec!["ZZ"], machine_info.unknown_fields().keys().collect::<Vec<&String>>());
+}
+
+#[test]
+pub fn read_records_signal_edges() {
+    let mut device_info = DeviceInfo::from("test").unwrap();
+    read_response("<Idle|MPos:0,0,0|Pn:D>", &mut device_info).unwrap();
+    read_response("<Idle|MPos:0,0,0|Pn:PX>", &mut device_info).unwrap();
+    read_response("<Idle|MPos:0,0,0>", &mut device_info).unwrap();
+    assert_eq!(vec![
+        DeviceEvent::SignalAsserted(MachineSignal::DoorSwitchAsserted),
+        DeviceEvent::SignalAsserted(MachineSignal::XLimitSwitchAsserted),
+        DeviceEvent::SignalAsserted(MachineSignal::ProbeTriggered),
+        DeviceEvent::SignalReleased(MachineSignal::DoorSwitchAsserted),
+        DeviceEvent::SignalReleased(MachineSignal::XLimitSwitchAsserted),
+        DeviceEvent::SignalReleased(MachineSignal::ProbeTriggered),
+    ], device_info.take_events());
+    assert!(device_info.events().is_empty());
+    assert!(device_info.machine_info().unwrap().signal_set().unwrap().is_empty());
+}
+
+#[test]
+pub fn read_stores_probe_result_in_mm() {
+    let mut device_info = DeviceInfo::from("test").unwrap();
+    read_response("[PRB:0.000,0.000,-1.5000:1]", &mut device_info).unwrap();
+    assert_eq!(Some(-1.5), device_info.probe_result().unwrap().position().get(&Axis::Z));
+    read_response("$13=1", &mut device_info).unwrap();
+    read_response("[PRB:0.000,0.000,-1.0000:1]", &mut device_info).unwrap();
+    assert_eq!(Some(-25.4), device_info.probe_result().unwrap().position().get(&Axis::Z));
+}
+
+#[test]
+pub fn read_counts_responses_and_reports() {
+    let mut device_info = DeviceInfo::from("test").unwrap();
+    read_response("ok", &mut device_info).unwrap();
+    read_response("error:20", &mut device_info).unwrap();
+    read_response("<Idle|MPos:0,0,0>", &mut device_info).unwrap();
+    assert_eq!(2, device_info.response_count());
+    assert_eq!(1, device_info.error_response_count());
+    assert_eq!(1, device_info.report_count());
+}
+
+#[test]
+pub fn read_stores_ngc_parameters_in_mm() {
+    let mut device_info = DeviceInfo::from("test").unwrap();
+    read_response("$13=1", &mut device_info).unwrap();
+    read_response("[G54:1.0000,0.0000,0.0000]", &mut device_info).unwrap();
+    read_response("[TLO:0.5000]", &mut device_info).unwrap();
+    let parameters = device_info.ngc_parameters();
+    assert_eq!(&[25.4, 0.0, 0.0], parameters.coordinate_system_offset(&CoordinateSystem::G54).unwrap().values());
+    assert_eq!(&[0.0, 0.0, 12.7], parameters.get(&NgcParameterName::ToolLengthOffset).unwrap().values());
+}
+
+#[test]
+pub fn read_updates_active_coordinate_system_from_gcode_state() {
+    let mut device_info = DeviceInfo::from("test").unwrap();
+    read_response("<Idle|MPos:0,0,0|WCS:G54>", &mut device_info).unwrap();
+    assert_eq!(Some(CoordinateSystem::G54), device_info.active_coordinate_system());
+    read_response("[GC:G0 G59.1 G17 G21 G90 G94 M5 M9 T0 F0 S0]", &mut device_info).unwrap();
+    assert_eq!(Some(CoordinateSystem::G59_1), device_info.active_coordinate_system());
+}
+
+#[test]
+pub fn read_requests_tool_number_on_tool_change() {
+    let mut device_info = DeviceInfo::from("test").unwrap();
+    read_response("<Run|MPos:0,0,0>", &mut device_info).unwrap();
+    read_response("<Tool|MPos:0,0,0>", &mut device_info).unwrap();
+    assert!(device_info.take_parser_state_request());
+    assert!(!device_info.take_parser_state_request());
+    read_response("[GC:G0 G54 G17 G21 G90 G94 M5 M9 T2 F0 S0]", &mut device_info).unwrap();
+    assert_eq!(Some(2), device_info.requested_tool());
+    read_response("<Run|MPos:0,0,0>", &mut device_info).unwrap();
+    assert_eq!(None, device_info.requested_tool());
+    assert_eq!(vec![DeviceEvent::ToolChangeRequested(2), DeviceEvent::ToolChangeCompleted], device_info.take_events());
+}
+
+#[test]
+pub fn read_completes_tool_change_without_tool_number() {
+    let mut device_info = DeviceInfo::from("test").unwrap();
+    read_response("<Tool|MPos:0,0,0>", &mut device_info).unwrap();
+    read_response("<Idle|MPos:0,0,0>", &mut device_info).unwrap();
+    assert_eq!(vec![DeviceEvent::ToolChangeCompleted], device_info.take_events());
+}
+
+#[test]
+pub fn read_does_not_acknowledge_job_line_with_internal_response() {
+    let mut device_info = DeviceInfo::from("test").unwrap();
+    device_info.set_job_progress(JobProgress::new(2));
+    device_info.job_progress_mut().unwrap().put_line_sent();
+    assert!(!device_info.is_ready_for_internal_command());
+    read_response("ok", &mut device_info).unwrap();
+
+    assert!(device_info.is_ready_for_internal_command());
+    device_info.put_internal_command();
+    device_info.job_progress_mut().unwrap().put_line_sent();
+    read_response("ok", &mut device_info).unwrap();
+    assert_eq!(1, device_info.job_progress().unwrap().acknowledged_lines());
+    assert_eq!(1, device_info.response_count());
+
+    read_response("ok", &mut device_info).unwrap();
+    assert_eq!(JobState::Completed, *device_info.job_progress().unwrap().state());
+}
+
+#[test]
+pub fn read_stores_tool_table_entries() {
+    let mut device_info = DeviceInfo::from("test").unwrap();
+    read_response("[T:1|0.000,0.000,-10.000|1.000]", &mut device_info).unwrap();
+    read_response("[T:1|0.000,0.000,-11.000|1.000]", &mut device_info).unwrap();
+    assert_eq!(1, device_info.tool_table().entries().len());
+    assert_eq!(Some(-11.0), device_info.tool_table().get(1).unwrap().offset().get(&Axis::Z));
+
+    device_info.reset_tool_table();
+    read_response("[T:2|0.000,0.000,-5.000|1.000]", &mut device_info).unwrap();
+    assert!(device_info.tool_table().get(1).is_none());
+    assert_eq!(1, device_info.tool_table().entries().len());
+}
+
+#[test]
+pub fn read_stores_sd_card_listing() {
+    let mut device_info = DeviceInfo::from("test").unwrap();
+    read_response("[FILE:/a.nc|SIZE:10]", &mut device_info).unwrap();
+    read_response("[FILE:/jobs/b.nc|SIZE:2048]", &mut device_info).unwrap();
+    read_response("ok", &mut device_info).unwrap();
+    assert_eq!(2, device_info.sd_card_listing().files().len());
+    assert_eq!(2048, device_info.sd_card_listing().get_file("/jobs/b.nc").unwrap().size());
+}
+
+#[test]
+pub fn read_collects_sd_card_dump_until_ok() {
+    let mut device_info = DeviceInfo::from("test").unwrap();
+    device_info.start_sd_card_dump();
+    read_response("G0 X10", &mut device_info).unwrap();
+    read_response("<Idle|MPos:0,0,0>", &mut device_info).unwrap();
+    read_response("[MSG:Pgm End]", &mut device_info).unwrap();
+    read_response("ok", &mut device_info).unwrap();
+    assert!(read_response("M30", &mut device_info).is_err());
+    assert_eq!(&vec!["G0 X10"], device_info.sd_card_dump().unwrap());
+}
+
+#[test]
+pub fn read_stores_startup_lines_instead_of_settings() {
+    let mut device_info = DeviceInfo::from("test").unwrap();
+    read_response("$N0=G54G21", &mut device_info).unwrap();
+    read_response("$N1=", &mut device_info).unwrap();
+    assert_eq!("G54G21", device_info.startup_lines().get(0).unwrap().line());
+    assert!(device_info.startup_lines().get(1).unwrap().is_empty());
+    assert!(device_info.settings().get_setting(&0).is_none());
+}
+
+#[test]
+pub fn read_keeps_history_of_startup_results() {
+    let mut device_info = DeviceInfo::from("test").unwrap();
+    read_response(">G54G21:ok", &mut device_info).unwrap();
+    read_response(">G5:error:20", &mut device_info).unwrap();
+    let history = device_info.firmware_info().startup_history();
+    assert_eq!(2, history.len());
+    assert_eq!("G54G21", history[0].executed_line());
+    assert_eq!(Some(&history[1]), device_info.firmware_info().startup_result());
+}
+
+#[test]
+pub fn read_welcome_message_resets_device_state() {
+    let mut device_info = DeviceInfo::from("test").unwrap();
+    read_response("[GC:G0 G54 G17 G21 G90 G94 M5 M9 T0 F0 S0]", &mut device_info).unwrap();
+    device_info.set_job_progress(JobProgress::new(10));
+    device_info.job_progress_mut().unwrap().put_line_sent();
+
+    read_response("Grbl 1.1f ['$' for help]", &mut device_info).unwrap();
+    assert_eq!(1, device_info.reset_count());
+    assert!(device_info.gcode_state().is_none());
+    assert_eq!(JobState::Failed(String::from("Device was reset")), *device_info.job_progress().unwrap().state());
+    assert_eq!(vec![DeviceEvent::Reset], device_info.take_events());
+    assert_eq!("1.1f", device_info.firmware_info().welcome_message().unwrap().version());
+}
+
+#[test]
+pub fn read_tracks_pendant_control() {
+    let mut device_info = DeviceInfo::from("test").unwrap();
+    read_response("<Idle|MPos:0.000,0.000,0.000|FS:0,0|MPG:0>", &mut device_info).unwrap();
+    assert!(device_info.take_events().is_empty());
+    device_info.set_job_progress(JobProgress::new(10));
+
+    read_response("<Idle|MPos:0.000,0.000,0.000|FS:0,0|MPG:1>", &mut device_info).unwrap();
+    read_response("<Jog|MPos:1.000,0.000,0.000|FS:500,0>", &mut device_info).unwrap();
+    assert!(device_info.is_pendant_control_taken());
+    assert_eq!(JobState::Failed(String::from("Pendant took control")), *device_info.job_progress().unwrap().state());
+    assert!(!device_info.take_resync_request());
+
+    read_response("<Idle|MPos:1.000,0.000,0.000|FS:0,0|MPG:0>", &mut device_info).unwrap();
+    assert!(!device_info.is_pendant_control_taken());
+    assert!(device_info.take_resync_request());
+    assert!(!device_info.take_resync_request());
+    assert_eq!(vec![DeviceEvent::PendantControlTaken, DeviceEvent::PendantControlReleased], device_info.take_events());
+}
+
+#[test]
+pub fn read_reports_arc_loss_during_cut() {
+    let mut device_info = DeviceInfo::from("test").unwrap();
+    for report in ["<Run|MPos:0,0,0|THC:0.0,E>", "<Run|MPos:0,0,0|THC:120.5,AET>", "<Run|MPos:1,0,0|THC:0.0,ET>", "<Run|MPos:2,0,0|THC:0.0,E>"] {
+        read_response(report, &mut device_info).unwrap();
+    }
+    assert_eq!(vec![DeviceEvent::ArcLost], device_info.take_events());
+    assert!(!device_info.machine_info().unwrap().thc_state().unwrap().is_torch_on());
+}
+
+#[test]
+pub fn read_stores_odometer_and_pid_log() {
+    let mut device_info = DeviceInfo::from("test").unwrap();
+    read_response("[ODOMETER:X=10.0,Y=5.0,Z=1.0|SPINDLE=2.5]", &mut device_info).unwrap();
+    read_response("[PID:500.0,0.01,2|490.0,480.0]", &mut device_info).unwrap();
+    assert_eq!(Some(5.0), device_info.odometer().unwrap().distance(&Axis::Y));
+    assert_eq!(1, device_info.pid_log().unwrap().samples().len());
+    assert!(device_info.last_message().is_none());
+
+    device_info.reset_odometer();
+    device_info.reset_pid_log();
+    assert!(device_info.odometer().is_none());
+    assert!(device_info.pid_log().is_none());
+}
+
+#[test]
+pub fn read_counts_input_wait_results_and_resets_aux_outputs() {
+    let mut device_info = DeviceInfo::from("test").unwrap();
+    device_info.put_digital_output(1, true);
+    device_info.put_analog_output(0, 2.5);
+    read_response("<Idle|MPos:0.000,0.000,0.000|FS:0,0>", &mut device_info).unwrap();
+    read_response("<Idle|MPos:0.000,0.000,0.000|FS:0,0|In:1>", &mut device_info).unwrap();
+    assert_eq!(1, device_info.input_wait_result_count());
+    assert_eq!(Some(true), device_info.aux_outputs().digital(1));
+    assert_eq!(Some(2.5), device_info.aux_outputs().analog(0));
+
+    read_response("Grbl 1.1f ['$' for help]", &mut device_info).unwrap();
+    assert!(device_info.aux_outputs().digital_outputs().is_empty());
+}
+
+#[test]
+pub fn read_applies_synchronized_outputs_after_motion() {
+    let mut device_info = DeviceInfo::from("test").unwrap();
+    device_info.put_digital_output(0, false);
+    device_info.put_pending_digital_output(0, true);
+    device_info.put_pending_analog_output(1, 5.0);
+    read_response("<Idle|MPos:0.000,0.000,0.000|FS:0,0>", &mut device_info).unwrap();
+    assert_eq!(Some(false), device_info.aux_outputs().digital(0));
+    assert_eq!(Some(true), device_info.aux_outputs().pending_digital(0));
+
+    read_response("<Run|MPos:1.000,0.000,0.000|FS:500,0>", &mut device_info).unwrap();
+    assert_eq!(None, device_info.aux_outputs().analog(1));
+    read_response("<Idle|MPos:2.000,0.000,0.000|FS:0,0>", &mut device_info).unwrap();
+    assert_eq!(Some(true), device_info.aux_outputs().digital(0));
+    assert_eq!(Some(5.0), device_info.aux_outputs().analog(1));
+    assert!(!device_info.aux_outputs().has_pending());
+}
+
+#[test]
+pub fn machine_state_name_follows_status_reports() {
+    let mut device_info = DeviceInfo::from("test").unwrap();
+    assert_eq!(None, device_info.machine_state_name());
+    read_response("<Hold:0|MPos:0,0,0>", &mut device_info).unwrap();
+    assert_eq!(Some(MachineStateName::Hold), device_info.machine_state_name());
+}
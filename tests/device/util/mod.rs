@@ -0,0 +1 @@
+pub mod signals_test;
\ No newline at end of file
@@ -0,0 +1,38 @@
+use grbli::device::{response::state::signal::MachineSignal, util::signals::{mask, SignalSet, ThcSignal, ThcSignalSet}};
+
+
+
+#[test]
+fn from_letters_fails_on_unknown_letter() {
+    let error = SignalSet::from_letters("PQ").err().unwrap();
+    assert_eq!("Unknown machine signal \"Q\"", error);
+}
+
+#[test]
+fn from_mask_returns_signals_in_bit_order() {
+    let signals = SignalSet::from_mask(mask::SAFETY_DOOR | mask::LIMIT_Z | mask::MOTOR_FAULT);
+    assert_eq!(vec![MachineSignal::ZLimitSwitchAsserted, MachineSignal::DoorSwitchAsserted, MachineSignal::MotorFault], signals.signals());
+    assert_eq!("ZDM", signals.letters());
+}
+
+#[test]
+fn from_signals_matches_letters() {
+    let signals = SignalSet::from_signals(&[MachineSignal::ProbeTriggered, MachineSignal::BlockDeleteSwitchAsserted]);
+    assert_eq!(SignalSet::from_letters("LP").unwrap(), signals);
+}
+
+#[test]
+fn edges_compare_with_previous_set() {
+    let previous = SignalSet::from_letters("XD").unwrap();
+    let current = SignalSet::from_letters("XP").unwrap();
+    assert_eq!(vec![MachineSignal::ProbeTriggered], current.rising_edges(&previous).signals());
+    assert_eq!(vec![MachineSignal::DoorSwitchAsserted], current.falling_edges(&previous).signals());
+}
+
+#[test]
+fn thc_signal_set_reads_letters() {
+    let signals = ThcSignalSet::from_letters("TAE").unwrap();
+    assert_eq!(vec![ThcSignal::ArcOk, ThcSignal::Enabled, ThcSignal::TorchOn], signals.signals());
+    assert_eq!("AET", signals.letters());
+    assert!(ThcSignalSet::from_letters("X").is_err());
+}
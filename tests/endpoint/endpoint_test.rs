@@ -0,0 +1,45 @@
+use std::time::Duration;
+
+use grbli::endpoint::Endpoint;
+
+
+
+/// Endpoint implementing only the required line based methods
+#[derive(Default)]
+struct LineEndpoint {
+    written: Vec<String>,
+}
+
+impl Endpoint for LineEndpoint {
+    fn open(&mut self) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn close(&mut self) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn write(&mut self, message: &str) -> Result<(), String> {
+        self.written.push(message.to_string());
+        Ok(())
+    }
+
+    fn read_new_messages(&self, _timeout: Duration) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+#[test]
+fn write_bytes_defaults_to_write_for_ascii() {
+    let mut endpoint = LineEndpoint::default();
+    endpoint.write_bytes(b"?").unwrap();
+    assert_eq!(vec![String::from("?")], endpoint.written);
+    assert!(endpoint.write_bytes(&[0x87]).is_err());
+}
+
+#[test]
+fn raw_mode_is_unsupported_by_default() {
+    let mut endpoint = LineEndpoint::default();
+    assert!(endpoint.set_raw_mode(true).is_err());
+    assert!(endpoint.read_raw_bytes(Duration::from_millis(1)).is_err());
+}
@@ -0,0 +1,2 @@
+pub mod endpoint_test;
+pub mod ymodem_test;
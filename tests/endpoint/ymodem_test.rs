@@ -0,0 +1,198 @@
+use std::{cell::RefCell, collections::VecDeque, time::Duration};
+
+use grbli::endpoint::{ymodem::{get_crc16, get_header_block, YmodemSender, ACK, CAN, CRC_REQUEST, EOT, NAK, SOH, STX}, Endpoint};
+
+
+
+/// Stand-in for the YMODEM receiver of the grblHAL sd card plugin
+struct YmodemReceiver {
+    responses: RefCell<VecDeque<u8>>,
+    file_name: Option<String>,
+    file_size: usize,
+    data: Vec<u8>,
+    next_block: u8,
+    eot_count: usize,
+    finished: bool,
+
+    /// Block which is rejected once with NAK
+    reject_block: Option<u8>,
+
+    /// Block which is answered with a cancel request
+    cancel_block: Option<u8>,
+
+    /// Block whose acknowledgement is preceded by a stray 'C'
+    stray_crc_block: Option<u8>,
+}
+
+impl YmodemReceiver {
+
+    fn new() -> Self {
+        YmodemReceiver {
+            responses: RefCell::new(VecDeque::from([CRC_REQUEST])),
+            file_name: None,
+            file_size: 0,
+            data: Vec::new(),
+            next_block: 0,
+            eot_count: 0,
+            finished: false,
+            reject_block: None,
+            cancel_block: None,
+            stray_crc_block: None,
+        }
+    }
+
+    fn received_file(&self) -> Vec<u8> {
+        self.data[..self.file_size].to_vec()
+    }
+
+    fn receive_packet(&mut self, packet: &[u8]) {
+        let block_size = match packet[0] {
+            SOH => 128,
+            STX => 1024,
+            _ => panic!("Unexpected packet start 0x{:02X}", packet[0]),
+        };
+        assert_eq!(block_size + 5, packet.len());
+        assert_eq!(255 - packet[1], packet[2]);
+        let block = &packet[3..block_size + 3];
+        let crc = u16::from_be_bytes([packet[block_size + 3], packet[block_size + 4]]);
+        if crc != get_crc16(block) || packet[1] != self.next_block {
+            self.responses.borrow_mut().push_back(NAK);
+            return;
+        }
+        if self.cancel_block == Some(packet[1]) {
+            self.responses.borrow_mut().extend([CAN, CAN]);
+            return;
+        }
+        if self.reject_block == Some(packet[1]) {
+            self.reject_block = None;
+            self.responses.borrow_mut().push_back(NAK);
+            return;
+        }
+
+        if self.eot_count > 0 {
+            // empty header ends the batch
+            assert!(block.iter().all(|byte| *byte == 0));
+            self.finished = true;
+            self.responses.borrow_mut().push_back(ACK);
+        } else if packet[1] == 0 && self.file_name.is_none() {
+            let header: Vec<&[u8]> = block.split(|byte| *byte == 0).collect();
+            self.file_name = Some(String::from_utf8(header[0].to_vec()).unwrap());
+            self.file_size = String::from_utf8(header[1].to_vec()).unwrap().parse().unwrap();
+            self.next_block = 1;
+            self.responses.borrow_mut().extend([ACK, CRC_REQUEST]);
+        } else {
+            if self.stray_crc_block == Some(packet[1]) {
+                self.responses.borrow_mut().push_back(CRC_REQUEST);
+            }
+            self.data.extend_from_slice(block);
+            self.next_block = self.next_block.wrapping_add(1);
+            self.responses.borrow_mut().push_back(ACK);
+        }
+    }
+}
+
+impl Endpoint for YmodemReceiver {
+    fn open(&mut self) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn close(&mut self) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn write(&mut self, message: &str) -> Result<(), String> {
+        self.write_bytes(message.as_bytes())
+    }
+
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), String> {
+        if bytes == [EOT] {
+            // the first end of transmission is confirmed with NAK
+            self.eot_count += 1;
+            match self.eot_count {
+                1 => self.responses.borrow_mut().push_back(NAK),
+                _ => {
+                    self.next_block = 0;
+                    self.responses.borrow_mut().extend([ACK, CRC_REQUEST]);
+                }
+            }
+            return Ok(());
+        }
+        self.receive_packet(bytes);
+        Ok(())
+    }
+
+    fn read_new_messages(&self, _timeout: Duration) -> Vec<String> {
+        Vec::new()
+    }
+
+    fn set_raw_mode(&mut self, _enabled: bool) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn read_raw_bytes(&self, _timeout: Duration) -> Result<Vec<u8>, String> {
+        Ok(self.responses.borrow_mut().drain(..).collect())
+    }
+}
+
+fn get_test_file(size: usize) -> Vec<u8> {
+    (0..size).map(|index| b"G1 X10 Y10\n"[index % 11]).collect()
+}
+
+#[test]
+fn sender_transfers_file() {
+    let file = get_test_file(2500);
+    let mut receiver = YmodemReceiver::new();
+    let mut progress = Vec::new();
+    YmodemSender::new("job.nc", file.clone()).send(&mut receiver, |sent, total| progress.push((sent, total))).unwrap();
+
+    assert!(receiver.finished);
+    assert_eq!(Some("job.nc".to_string()), receiver.file_name);
+    assert_eq!(file, receiver.received_file());
+    assert_eq!(vec![(1024, 2500), (2048, 2500), (2500, 2500)], progress);
+}
+
+#[test]
+fn sender_resends_rejected_block() {
+    let file = get_test_file(1500);
+    let mut receiver = YmodemReceiver::new();
+    receiver.reject_block = Some(2);
+    YmodemSender::new("job.nc", file.clone()).send(&mut receiver, |_, _| ()).unwrap();
+
+    assert!(receiver.finished);
+    assert_eq!(file, receiver.received_file());
+}
+
+#[test]
+fn sender_ignores_crc_request_during_transfer() {
+    let file = get_test_file(2500);
+    let mut receiver = YmodemReceiver::new();
+    receiver.stray_crc_block = Some(2);
+    YmodemSender::new("job.nc", file.clone()).send(&mut receiver, |_, _| ()).unwrap();
+
+    assert!(receiver.finished);
+    assert_eq!(file, receiver.received_file());
+}
+
+#[test]
+fn sender_stops_on_cancel() {
+    let mut receiver = YmodemReceiver::new();
+    receiver.cancel_block = Some(1);
+    let result = YmodemSender::new("job.nc", get_test_file(100)).send(&mut receiver, |_, _| ());
+
+    assert_eq!(Err("Transfer was cancelled by the receiver".to_string()), result);
+    assert!(!receiver.finished);
+}
+
+#[test]
+fn header_block_contains_name_and_size() {
+    let header = get_header_block("/jobs/part.nc", 2500);
+    assert_eq!(128, header.len());
+    assert_eq!(b"/jobs/part.nc\x002500\0", &header[..19]);
+    assert!(header[19..].iter().all(|byte| *byte == 0));
+}
+
+#[test]
+fn crc16_matches_xmodem_check_value() {
+    assert_eq!(0x31C3, get_crc16(b"123456789"));
+    assert_eq!(0, get_crc16(&[]));
+}
@@ -0,0 +1,151 @@
+use grbli::{device::{response::setting::{DeviceSetting, DeviceSettings}, response::state::modes::ArcMode, util::{axis::Axis, coordinate::CoordinateSystem}}, job::analyzer::JobAnalyzer};
+
+
+
+fn assert_near(expected: f32, actual: f32) {
+    assert!((expected - actual).abs() < 0.01, "expected {} but was {}", expected, actual);
+}
+
+#[test]
+fn analyze_measures_rapid_and_cutting_distance() {
+    let analysis = JobAnalyzer::new().analyze("G0 X10\nG1 X10 Y10 F100\nG0 X0 Y0").unwrap();
+    assert_near(10.0 + 200f32.sqrt(), analysis.rapid_distance());
+    assert_near(10.0, analysis.cutting_distance());
+}
+
+#[test]
+fn analyze_returns_bounding_box() {
+    let analysis = JobAnalyzer::new().analyze("G0 X-5 Y2\nG1 Z-3 F100\nG1 X20").unwrap();
+    let bounds = analysis.bounds().unwrap();
+    assert_near(-5.0, bounds.min(&Axis::X));
+    assert_near(20.0, bounds.max(&Axis::X));
+    assert_near(-3.0, bounds.min(&Axis::Z));
+    assert_near(25.0, bounds.size(&Axis::X));
+}
+
+#[test]
+fn analyze_without_motion_has_no_bounds() {
+    let analysis = JobAnalyzer::new().analyze("G21 G90\nM5").unwrap();
+    assert!(analysis.bounds().is_none());
+}
+
+#[test]
+fn analyze_handles_incremental_and_inch_mode() {
+    let analysis = JobAnalyzer::new().analyze("G20 G91\nG0 X1\nG0 X1").unwrap();
+    assert_near(50.8, analysis.bounds().unwrap().max(&Axis::X));
+    assert_near(50.8, analysis.rapid_distance());
+}
+
+#[test]
+fn analyze_includes_arc_extents() {
+    // half circle from (0,0) to (20,0) around (10,0) bulging to y=10
+    let analysis = JobAnalyzer::new().analyze("G1 X0 Y0 F100\nG2 X20 Y0 I10 J0").unwrap();
+    let bounds = analysis.bounds().unwrap();
+    assert_near(10.0, bounds.max(&Axis::Y));
+    assert_near(10.0 * std::f32::consts::PI, analysis.cutting_distance());
+}
+
+#[test]
+fn analyze_includes_exact_quadrant_extents() {
+    // arc starting at 2.5 degrees passes the top of the circle between any 5 degree samples
+    let analysis = JobAnalyzer::new().analyze("G1 X99.9048 Y4.3619 F100\nG3 X-100 Y0 I-99.9048 J-4.3619").unwrap();
+    let bounds = analysis.bounds().unwrap();
+    assert_near(100.0, bounds.max(&Axis::Y));
+    assert_near(-100.0, bounds.min(&Axis::X));
+}
+
+#[test]
+fn analyze_reads_radius_arcs() {
+    let analysis = JobAnalyzer::new().analyze("G3 X20 Y0 R10 F100").unwrap();
+    let bounds = analysis.bounds().unwrap();
+    assert_near(-10.0, bounds.min(&Axis::Y));
+    assert_near(10.0 * std::f32::consts::PI, analysis.cutting_distance());
+}
+
+#[test]
+fn analyze_applies_work_offsets() {
+    let mut analyzer = JobAnalyzer::new();
+    analyzer.set_work_offset(&CoordinateSystem::G55, &[100.0, 0.0, 0.0]);
+    let analysis = analyzer.analyze("G0 X5\nG55 G0 X5").unwrap();
+    assert_near(105.0, analysis.bounds().unwrap().max(&Axis::X));
+}
+
+#[test]
+fn analyze_reports_machine_bounds() {
+    let mut analyzer = JobAnalyzer::new();
+    analyzer.set_work_offset(&CoordinateSystem::G54, &[-50.0, -20.0, 0.0]);
+    analyzer.set_work_offset(&CoordinateSystem::G55, &[-150.0, -20.0, 0.0]);
+    let analysis = analyzer.analyze("G0 X5\nG55 G0 X5").unwrap();
+    let machine_bounds = analysis.machine_bounds().unwrap();
+    assert_near(-145.0, machine_bounds.min(&Axis::X));
+    assert_near(-45.0, machine_bounds.max(&Axis::X));
+    assert_near(-95.0, analysis.bounds().unwrap().min(&Axis::X));
+}
+
+#[test]
+fn analyze_reads_lathe_diameter_mode() {
+    let mut analyzer = JobAnalyzer::new();
+    analyzer.set_arc_mode(ArcMode::Diameter);
+    let analysis = analyzer.analyze("G0 X20\nG8\nG0 X20").unwrap();
+    assert_near(20.0, analysis.bounds().unwrap().max(&Axis::X));
+    assert_near(10.0, analysis.bounds().unwrap().min(&Axis::X));
+}
+
+#[test]
+fn analyze_reports_lathe_features() {
+    let analysis = JobAnalyzer::new().analyze("G7\nG0 X20 Z2\nG33 Z-10 K1.5").unwrap();
+    assert!(analysis.uses_diameter_mode());
+    assert!(analysis.uses_spindle_sync());
+    assert!(!JobAnalyzer::new().analyze("G0 X20\nG1 Z-10 F100").unwrap().uses_spindle_sync());
+}
+
+#[test]
+fn analyze_includes_threading_cycle_extents() {
+    let analysis = JobAnalyzer::new().analyze("G0 X10 Z2\nG76 P1.5 Z-20 I-0.2 J0.1 K0.9\nG0 X12").unwrap();
+    let bounds = analysis.bounds().unwrap();
+    assert_near(8.9, bounds.min(&Axis::X));
+    assert_near(-20.0, bounds.min(&Axis::Z));
+    assert_near(22.0, analysis.cutting_distance());
+    assert!(analysis.uses_spindle_sync());
+}
+
+#[test]
+fn analyze_records_tool_changes() {
+    let analysis = JobAnalyzer::new().analyze("T2 M6\nT5\nM6\nM30\nT7 M6").unwrap();
+    assert_eq!(vec![2u32, 5u32], analysis.tool_changes());
+}
+
+#[test]
+fn analyze_estimates_runtime_from_settings() {
+    let mut settings = DeviceSettings::new();
+    settings.put_setting(DeviceSetting::new(110, String::from("6000")));
+    settings.put_setting(DeviceSetting::new(120, String::from("100")));
+    let analyzer = JobAnalyzer::from_settings(&settings);
+    assert_near(6000.0, analyzer.max_rate(&Axis::X));
+
+    // 100 mm/s reached after 1 s and 50 mm, cruise 100 mm for 1 s, decelerate 1 s
+    let analysis = analyzer.analyze("G0 X200").unwrap();
+    assert_near(3.0, analysis.estimated_runtime().as_secs_f32());
+
+    // dwell adds seconds
+    let analysis = analyzer.analyze("G4 P2.5").unwrap();
+    assert_near(2.5, analysis.estimated_runtime().as_secs_f32());
+
+    // P may precede G4 in the block
+    let analysis = analyzer.analyze("P1.5 G4").unwrap();
+    assert_near(1.5, analysis.estimated_runtime().as_secs_f32());
+}
+
+#[test]
+fn analyze_fails_on_unbounded_runtime() {
+    let mut settings = DeviceSettings::new();
+    settings.put_setting(DeviceSetting::new(110, String::from("1e-30")));
+    let error = JobAnalyzer::from_settings(&settings).analyze("G0 X200").err().unwrap();
+    assert!(error.starts_with("Cannot estimate runtime"));
+}
+
+#[test]
+fn analyze_fails_on_undefined_feed_rate() {
+    let error = JobAnalyzer::new().analyze("G0 X1\nG1 X2").err().unwrap();
+    assert_eq!("Line 2: Undefined feed rate", error);
+}
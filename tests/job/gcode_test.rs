@@ -0,0 +1,43 @@
+use grbli::job::gcode::{parse_gcode_line, strip_gcode_comments};
+
+
+
+#[test]
+fn parse_gcode_line_reads_all_words() {
+    let words = parse_gcode_line("N10 G1 X-1.5 y2 F300").unwrap();
+    assert_eq!(words.len(), 5);
+    assert!(words[1].is_command('G', 1.0));
+    assert_eq!('Y', words[3].letter());
+    assert_eq!(2f32, words[3].value());
+}
+
+#[test]
+fn parse_gcode_line_accepts_words_without_spaces() {
+    let words = parse_gcode_line("G38.2Z-10F50").unwrap();
+    assert!(words[0].is_command('G', 38.2));
+    assert_eq!(-10f32, words[1].value());
+    assert_eq!(50f32, words[2].value());
+}
+
+#[test]
+fn parse_gcode_line_ignores_comments() {
+    let words = parse_gcode_line("(header) G0 X1 ; rapid").unwrap();
+    assert_eq!(words.len(), 2);
+}
+
+#[test]
+fn parse_gcode_line_fails_on_missing_value() {
+    let error = parse_gcode_line("G1 X").err().unwrap();
+    assert_eq!("Cannot read value of word \"X\" in \"G1 X\"", error);
+}
+
+#[test]
+fn parse_gcode_line_fails_on_invalid_letter() {
+    let error = parse_gcode_line("G1 #1").err().unwrap();
+    assert_eq!("Invalid word letter \"#\" in \"G1 #1\"", error);
+}
+
+#[test]
+fn strip_gcode_comments_removes_program_demarcation() {
+    assert_eq!("", strip_gcode_comments("%"));
+}
@@ -0,0 +1,42 @@
+use grbli::{device::{response::read_response, DeviceInfo}, job::laser::{check_laser_job, format_laser_warnings, is_laser_mode, LaserPowerRange, LaserWarning}};
+
+
+
+#[test]
+fn is_laser_mode_reads_setting() {
+    let mut device_info = DeviceInfo::from("test").unwrap();
+    assert!(!is_laser_mode(device_info.settings()));
+    read_response("$32=1", &mut device_info).unwrap();
+    assert!(is_laser_mode(device_info.settings()));
+}
+
+#[test]
+fn power_range_maps_percentage_to_spindle_speed() {
+    let mut device_info = DeviceInfo::from("test").unwrap();
+    for message in ["$30=255", "$31=5"] {
+        read_response(message, &mut device_info).unwrap();
+    }
+    let range = LaserPowerRange::from_settings(device_info.settings());
+    assert_eq!(255.0, range.get_spindle_speed(150.0));
+    assert_eq!(130.0, range.get_spindle_speed(50.0));
+    assert_eq!(50.0, range.get_percentage(130.0));
+    assert_eq!(1000.0, LaserPowerRange::from_settings(DeviceInfo::from("test").unwrap().settings()).max());
+}
+
+#[test]
+fn check_laser_job_accepts_dynamic_power() {
+    let program = "G21 G90\nM4 S800\nG0 X0 Y0\nG1 X10 F1000\nG2 X20 Y0 I5 J0\nM5\nG0 X0";
+    assert!(check_laser_job(program).unwrap().is_empty());
+}
+
+#[test]
+fn check_laser_job_reports_each_cut_without_laser_once() {
+    let warnings = check_laser_job("G1 X10 F1000\nX20\nM4 S100\nX30\nM5\nG1 X0").unwrap();
+    assert_eq!(vec![LaserWarning::LaserOff(1), LaserWarning::LaserOff(6)], warnings);
+    assert_eq!("Line 1: cutting move without enabled laser, Line 6: cutting move without enabled laser", format_laser_warnings(&warnings));
+}
+
+#[test]
+fn check_laser_job_reports_line_of_parse_error() {
+    assert!(check_laser_job("M4 S100\nG1 X").unwrap_err().starts_with("Line 2"));
+}
@@ -0,0 +1,54 @@
+use grbli::{device::response::state::modes::ArcMode, job::lathe::ThreadingPlan};
+
+
+
+fn assert_near(expected: f32, actual: f32) {
+    assert!((expected - actual).abs() < 0.001, "expected {} but was {}", expected, actual);
+}
+
+#[test]
+fn new_rejects_invalid_dimensions() {
+    assert!(ThreadingPlan::new(0.0, 1.0, 0.2).is_err());
+    assert!(ThreadingPlan::new(1.5, -1.0, 0.2).is_err());
+    assert!(ThreadingPlan::new(1.5, 1.0, 0.2).unwrap().set_degression(0.5).is_err());
+    assert!(ThreadingPlan::new(1.5, 1.0, 0.2).unwrap().set_degression(1e9).is_err());
+    assert!(ThreadingPlan::new(1.5, 1.0, 0.2).unwrap().set_degression(f32::NAN).is_err());
+}
+
+#[test]
+fn pass_depths_decrease_until_full_depth() {
+    let mut plan = ThreadingPlan::new(1.5, 0.9, 0.3).unwrap();
+    plan.set_spring_passes(1);
+    let depths = plan.pass_depths().unwrap();
+    assert_eq!(10, depths.len());
+    assert_near(0.3, depths[0]);
+    assert_near(0.3 * 2f32.sqrt(), depths[1]);
+    assert_eq!(vec![0.9, 0.9], depths[8..].to_vec());
+}
+
+#[test]
+fn pass_depths_are_constant_without_degression() {
+    let mut plan = ThreadingPlan::new(1.0, 0.9, 0.3).unwrap();
+    plan.set_degression(1.0).unwrap();
+    let depths = plan.pass_depths().unwrap();
+    assert_eq!(3, depths.len());
+    assert_near(0.6, depths[1]);
+}
+
+#[test]
+fn pass_depths_fail_on_too_many_passes() {
+    let mut plan = ThreadingPlan::new(1.0, 10.0, 0.01).unwrap();
+    plan.set_degression(4.0).unwrap();
+    assert!(plan.pass_depths().is_err());
+
+    let mut plan = ThreadingPlan::new(1.0, 0.9, 0.3).unwrap();
+    plan.set_spring_passes(usize::MAX);
+    assert!(plan.get_program(10.0, 1.0, -15.0, true, ArcMode::Radius).is_err());
+}
+
+#[test]
+fn get_program_writes_diameter_for_internal_thread() {
+    let plan = ThreadingPlan::new(2.0, 0.5, 0.5).unwrap();
+    let program = plan.get_program(10.0, 1.0, -15.0, false, ArcMode::Diameter).unwrap();
+    assert_eq!("G0 X20.000 Z1.000\nG0 X21.000\nG33 Z-15.000 K2.000\nG0 X20.000\nG0 Z1.000\n", program);
+}
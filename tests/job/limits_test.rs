@@ -0,0 +1,84 @@
+use grbli::{device::{response::read_response, util::{axis::Axis, coordinate::CoordinateSystem}, DeviceInfo}, job::{analyzer::JobAnalyzer, limits::{format_soft_limit_violations, SoftLimitViolation, TravelLimits}}};
+
+
+
+fn create_device_info(report: &str) -> DeviceInfo {
+    let mut device_info = DeviceInfo::from("test").unwrap();
+    for message in ["$22=1", "$130=200", "$131=100", "$132=50", report] {
+        read_response(message, &mut device_info).unwrap();
+    }
+    device_info
+}
+
+#[test]
+fn from_device_info_uses_negative_machine_space() {
+    let limits = TravelLimits::from_device_info(&create_device_info("<Idle|MPos:0,0,0|H:1,7>"));
+    assert_eq!(Some(-200f32), limits.min(&Axis::X));
+    assert_eq!(Some(0f32), limits.max(&Axis::X));
+    assert_eq!(None, limits.min(&Axis::A));
+    assert!(limits.is_homed(&Axis::Z));
+}
+
+#[test]
+fn from_device_info_uses_positive_space_with_forced_origin() {
+    let mut device_info = create_device_info("<Idle|MPos:0,0,0|H:1,7>");
+    read_response("$23=1", &mut device_info).unwrap();
+    read_response("[OPT:VZ,15,128]", &mut device_info).unwrap();
+    let limits = TravelLimits::from_device_info(&device_info);
+    assert_eq!(Some(0f32), limits.min(&Axis::X));
+    assert_eq!(Some(200f32), limits.max(&Axis::X));
+    assert_eq!(Some(-100f32), limits.min(&Axis::Y));
+}
+
+#[test]
+fn check_bounds_reports_axis_and_distance() {
+    let limits = TravelLimits::from_device_info(&create_device_info("<Idle|MPos:0,0,0|H:1,7>"));
+    let mut analyzer = JobAnalyzer::new();
+
+    // work zero at machine (-5, -90, 35)
+    analyzer.set_work_offset(&CoordinateSystem::G54, &[-5.0, -90.0, 35.0]);
+    let analysis = analyzer.analyze("G0 X10 Y-20 Z-30").unwrap();
+    let violations = limits.check_bounds(analysis.machine_bounds().unwrap()).err().unwrap();
+    assert_eq!(3, violations.len());
+    assert!(matches!(violations[0], SoftLimitViolation::AboveMaximum(Axis::X, distance) if (distance - 5.0).abs() < 0.001));
+    assert!(matches!(violations[1], SoftLimitViolation::BelowMinimum(Axis::Y, distance) if (distance - 10.0).abs() < 0.001));
+    assert!(matches!(violations[2], SoftLimitViolation::AboveMaximum(Axis::Z, distance) if (distance - 5.0).abs() < 0.001));
+}
+
+#[test]
+fn check_bounds_accepts_job_inside_travel() {
+    let limits = TravelLimits::from_device_info(&create_device_info("<Idle|MPos:0,0,0|H:1,7>"));
+    let mut analyzer = JobAnalyzer::new();
+    analyzer.set_work_offset(&CoordinateSystem::G54, &[-100.0, -50.0, -10.0]);
+    let analysis = analyzer.analyze("G0 X10 Y10\nG1 Z-5 F100").unwrap();
+    assert!(limits.check_bounds(analysis.machine_bounds().unwrap()).is_ok());
+}
+
+#[test]
+fn check_target_refuses_unhomed_axes() {
+    let limits = TravelLimits::from_device_info(&create_device_info("<Idle|MPos:0,0,0|H:1,3>"));
+    let violations = limits.check_target(&[(Axis::X, -10.0), (Axis::Z, -10.0)]).err().unwrap();
+    assert_eq!(vec![SoftLimitViolation::NotHomed(Axis::Z)], violations);
+}
+
+#[test]
+fn check_target_expects_homed_axes_without_homing_state() {
+    let limits = TravelLimits::from_device_info(&create_device_info("<Idle|MPos:0,0,0>"));
+    assert!(!limits.is_homing_state_known());
+    assert!(limits.check_target(&[(Axis::Z, -10.0)]).is_ok());
+    assert_eq!(vec![SoftLimitViolation::AboveMaximum(Axis::X, 5.0)], limits.check_target(&[(Axis::X, 5.0)]).err().unwrap());
+}
+
+#[test]
+fn check_target_skips_axes_without_homing() {
+    let mut device_info = create_device_info("<Idle|MPos:0,0,0|H:0,0>");
+    read_response("$22=0", &mut device_info).unwrap();
+    let limits = TravelLimits::from_device_info(&device_info);
+    assert!(limits.check_target(&[(Axis::X, 50.0), (Axis::Z, -10.0)]).is_ok());
+}
+
+#[test]
+fn format_soft_limit_violations_lists_all_axes() {
+    let violations = vec![SoftLimitViolation::AboveMaximum(Axis::X, 2.0), SoftLimitViolation::NotHomed(Axis::Z)];
+    assert_eq!("X exceeds maximum travel by 2.000 mm, Z is not homed", format_soft_limit_violations(&violations));
+}
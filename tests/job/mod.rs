@@ -0,0 +1,6 @@
+pub mod analyzer_test;
+pub mod gcode_test;
+pub mod laser_test;
+pub mod lathe_test;
+pub mod limits_test;
+pub mod progress_test;
\ No newline at end of file
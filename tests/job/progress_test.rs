@@ -0,0 +1,62 @@
+use grbli::job::progress::{JobProgress, JobState, UploadProgress};
+
+
+
+#[test]
+fn progress_waits_for_acknowledgement() {
+    let mut progress = JobProgress::new(2);
+    assert!(progress.is_ready_for_next_line());
+    progress.put_line_sent();
+    assert!(!progress.is_ready_for_next_line());
+    progress.put_response_status(&Ok(()));
+    assert!(progress.is_ready_for_next_line());
+    progress.put_line_sent();
+    progress.put_response_status(&Ok(()));
+    assert_eq!(JobState::Completed, *progress.state());
+    assert_eq!(2, progress.acknowledged_lines());
+}
+
+#[test]
+fn progress_fails_on_error_status() {
+    let mut progress = JobProgress::new(3);
+    progress.put_line_sent();
+    progress.put_response_status(&Err(22));
+    assert_eq!(JobState::Failed(String::from("Line 1 failed with error:22")), *progress.state());
+    assert!(!progress.is_ready_for_next_line());
+}
+
+#[test]
+fn progress_ignores_unrequested_status() {
+    let mut progress = JobProgress::new(1);
+    progress.put_response_status(&Ok(()));
+    assert_eq!(0, progress.acknowledged_lines());
+    assert!(progress.is_running());
+}
+
+#[test]
+fn cancel_stops_running_job() {
+    let mut progress = JobProgress::new(1);
+    progress.cancel();
+    assert_eq!(JobState::Cancelled, *progress.state());
+    assert!(!progress.is_ready_for_next_line());
+}
+
+#[test]
+fn upload_progress_limits_sent_bytes() {
+    let mut progress = UploadProgress::new("job.nc", 2500);
+    progress.put_bytes_sent(1024);
+    assert_eq!(1024, progress.sent_bytes());
+    progress.put_bytes_sent(3072);
+    assert_eq!(2500, progress.sent_bytes());
+    progress.complete();
+    assert_eq!(JobState::Completed, *progress.state());
+}
+
+#[test]
+fn upload_progress_keeps_first_result() {
+    let mut progress = UploadProgress::new("job.nc", 100);
+    progress.fail("Transfer was cancelled by the receiver");
+    progress.complete();
+    assert_eq!(JobState::Failed(String::from("Transfer was cancelled by the receiver")), *progress.state());
+    assert!(!progress.is_running());
+}
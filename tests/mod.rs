@@ -1,2 +1,4 @@
 pub mod device;
+pub mod endpoint;
+pub mod job;
 pub mod simulator;
\ No newline at end of file